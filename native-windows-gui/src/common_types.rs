@@ -18,6 +18,26 @@ pub enum VTextAlign {
     Bottom
 }
 
+bitflags! {
+    /**
+        The keys a control wants to handle itself instead of letting the dialog manager process them.
+        See `ControlHandle::set_want_keys`.
+
+        * NONE:     Default behaviour of the control
+        * ARROWS:   The arrow keys are sent to the control (`DLGC_WANTARROWS`)
+        * TAB:      The tab key is sent to the control instead of moving the focus to the next control (`DLGC_WANTTAB`)
+        * CHARS:    All `WM_CHAR` messages are sent to the control (`DLGC_WANTCHARS`)
+        * ALL_KEYS: Every keyboard input is sent to the control, including Enter and Esc (`DLGC_WANTALLKEYS`)
+    */
+    pub struct WantKeys: u32 {
+        const NONE = 0;
+        const ARROWS = 0x0001;
+        const TAB = 0x0002;
+        const CHARS = 0x0080;
+        const ALL_KEYS = 0x0004;
+    }
+}
+
 pub mod keys {
     //! Windows virtual key code
     
@@ -15,6 +15,7 @@ lazy_static! {
     static ref THREAD_STATE: Arc<Mutex<AnimationThread>> = {
         let state = AnimationThread {
             timers: Vec::new(),
+            high_resolution_count: 0,
         };
 
         let state = Arc::new(Mutex::new(state));
@@ -35,11 +36,23 @@ lazy_static! {
                         None => { continue; }
                     };
 
-                    if timer.last_tick.elapsed() > timer.interval {
+                    let now = Instant::now();
+                    let tick = match timer.high_resolution {
+                        true => now >= timer.last_tick + timer.interval,
+                        false => timer.last_tick.elapsed() > timer.interval
+                    };
+
+                    if tick {
                         AnimationThread::timer_tick(id as u32, timer.hwnd);
-                        timer.last_tick = Instant::now();
                         timer.current_tick += 1;
 
+                        // High resolution timers are scheduled from the previous deadline, so the late ticks do not accumulate.
+                        // If the timer fell behind by more than one interval, the missed ticks are skipped.
+                        timer.last_tick = match timer.high_resolution {
+                            true if now < timer.last_tick + timer.interval * 2 => timer.last_tick + timer.interval,
+                            _ => now
+                        };
+
                         if Some(timer.current_tick) == timer.max_tick {
                             AnimationThread::timer_stop(id as u32, timer.hwnd);
                             timer.active = false;
@@ -75,11 +88,15 @@ struct InnerTimer {
     max_tick: Option<u64>,
     current_tick: u64,
     active: bool,
+    high_resolution: bool,
     hwnd: usize,
 }
 
 struct AnimationThread {
     timers: Vec<Option<InnerTimer>>,
+
+    /// Number of high resolution timers. The system timer resolution is raised while this is not 0
+    high_resolution_count: u32,
 }
 
 impl AnimationThread {
@@ -110,6 +127,11 @@ impl AnimationThread {
             t.active = true;
             t.birthtime = Instant::now();
             t.current_tick = 0;
+
+            // The ticks of a high resolution timer are aligned on the start time
+            if t.high_resolution {
+                t.last_tick = t.birthtime;
+            }
         }
     }
 
@@ -139,8 +161,57 @@ impl AnimationThread {
 
     fn remove_timer(id: u32) {
         let mut state = THREAD_STATE.lock().unwrap();
-        if let Some(t) = state.timers.get_mut(id as usize) {
-            *t = None;
+        let high_resolution = match state.timers.get_mut(id as usize) {
+            Some(t) => t.take().map(|t| t.high_resolution).unwrap_or(false),
+            None => false
+        };
+
+        if high_resolution {
+            state.release_high_resolution();
+        }
+    }
+
+    fn set_high_resolution(id: u32, high_resolution: bool) {
+        let mut state = THREAD_STATE.lock().unwrap();
+        let changed = match state.timers.get_mut(id as usize) {
+            Some(Some(t)) if t.high_resolution != high_resolution => {
+                t.high_resolution = high_resolution;
+                true
+            },
+            _ => false
+        };
+
+        if changed {
+            match high_resolution {
+                true => state.acquire_high_resolution(),
+                false => state.release_high_resolution()
+            }
+        }
+    }
+
+    fn high_resolution(id: u32) -> bool {
+        let state = THREAD_STATE.lock().unwrap();
+        match state.timers.get(id as usize) {
+            Some(Some(t)) => t.high_resolution,
+            _ => false
+        }
+    }
+
+    fn acquire_high_resolution(&mut self) {
+        use winapi::um::timeapi::timeBeginPeriod;
+
+        if self.high_resolution_count == 0 {
+            unsafe { timeBeginPeriod(1); }
+        }
+        self.high_resolution_count += 1;
+    }
+
+    fn release_high_resolution(&mut self) {
+        use winapi::um::timeapi::timeEndPeriod;
+
+        self.high_resolution_count -= 1;
+        if self.high_resolution_count == 0 {
+            unsafe { timeEndPeriod(1); }
         }
     }
 
@@ -175,6 +246,7 @@ AnimationTimer replaces the default winapi timer. Please, for the love of god, d
     * `lifetime`:   The timer should automatically stop after the selected Duration. Defaults to `None`.
     * `max_tick`:   The timer should automatically stop after sending X amount of OnTImerTick events. Defaults to `None`.
     * `active`:     If the timer should start right away. Default to `false`
+    * `high_resolution`: Use the drift corrected, high resolution mode. See `AnimationTimer::set_high_resolution`. Default to `false`
 
 **Control events:**
     * `OnTimerTick`: When the timer ticks
@@ -209,6 +281,7 @@ impl AnimationTimer {
             max_tick: None,
             lifetime: None,
             active: false,
+            high_resolution: false,
         }
     }
 
@@ -260,6 +333,35 @@ impl AnimationTimer {
         AnimationThread::update_timer(id, None, None, Some(max_tick));
     }
 
+    /**
+        Enables the drift corrected, high resolution mode of the timer.
+
+        By default, the next tick is scheduled one interval after the previous tick was sent, so the delays add up
+        and the timer drifts under load. The timer thread also wakes up at the resolution of the system timer (usually 15.6 ms),
+        so short intervals are rounded up. In high resolution mode:
+          * The ticks are scheduled on a fixed grid (`start + n * interval`). A late tick does not delay the next ones.
+            If the timer falls behind by more than one interval, the missed ticks are skipped instead of being sent in a burst.
+          * The system timer resolution is raised to 1 ms (`timeBeginPeriod`) while at least one high resolution timer exists.
+
+        Trade-offs: a higher timer resolution increases the power usage of the whole system, so only use this mode for
+        animations and metronome-like timers, and disable it (or drop the timer) when it is not needed. The minimum interval is still 1 ms
+        and the ticks still have about 1 ms of jitter.
+
+        The ticks are still raised as `OnTimerTick` on the UI thread. A busy UI thread delays them like any other event.
+    */
+    pub fn set_high_resolution(&self, high_resolution: bool) {
+        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+        let (_, id) = self.handle.timer().expect(BAD_HANDLE);
+        AnimationThread::set_high_resolution(id, high_resolution);
+    }
+
+    /// Returns `true` if the timer uses the high resolution mode. See `set_high_resolution`.
+    pub fn high_resolution(&self) -> bool {
+        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+        let (_, id) = self.handle.timer().expect(BAD_HANDLE);
+        AnimationThread::high_resolution(id)
+    }
+
 }
 
 impl Drop for AnimationTimer {
@@ -280,7 +382,8 @@ pub struct AnimationTimerBuilder {
     interval: Duration,
     max_tick: Option<u64>,
     lifetime: Option<Duration>,
-    active: bool
+    active: bool,
+    high_resolution: bool,
 }
 
 impl AnimationTimerBuilder {
@@ -310,6 +413,11 @@ impl AnimationTimerBuilder {
         self
     }
 
+    pub fn high_resolution(mut self, high_resolution: bool) -> AnimationTimerBuilder {
+        self.high_resolution = high_resolution;
+        self
+    }
+
     pub fn build(self, out: &mut AnimationTimer) -> Result<(), NwgError> {
         let parent = match self.parent {
             Some(p) => match p.hwnd() {
@@ -331,10 +439,14 @@ impl AnimationTimerBuilder {
             max_tick: self.max_tick,
             current_tick: 0,
             active: self.active,
+            high_resolution: false,
             hwnd: parent as usize,
         };
 
         let id = AnimationThread::add_timer(inner);
+        if self.high_resolution {
+            AnimationThread::set_high_resolution(id, true);
+        }
 
         *out = AnimationTimer {
             handle: ControlHandle::Timer(parent, id)
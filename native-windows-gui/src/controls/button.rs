@@ -1,10 +1,14 @@
 use winapi::um::winuser::{WS_DISABLED, BS_ICON, BS_BITMAP, BS_NOTIFY, WS_VISIBLE, WS_TABSTOP, WS_CHILD};
+use winapi::um::commctrl::{BS_SPLITBUTTON, BS_DEFSPLITBUTTON};
 use crate::win32::{
     base_helper::check_hwnd,  
     window_helper as wh,
     resources_helper as rh
 };
 use crate::{NwgError, Font, Bitmap, Icon};
+
+#[cfg(feature = "image-list")]
+use crate::ImageList;
 use super::{ControlBase, ControlHandle};
 
 const NOT_BOUND: &'static str = "Button is not yet bound to a winapi object";
@@ -22,6 +26,11 @@ bitflags! {
         * ICON:     The button will display a icon image with no text. Must have a icon or else it will only show text.
         * NOTIFY:   Enable the `OnButtonDoubleClick` event
         * TAB_STOP: The control can be selected using tab navigation
+        * SPLIT:    The button has a dropdown arrow on its right side. Clicking the arrow raises `OnButtonDropdown` instead of `OnButtonClick`.
+        * DEFAULT_SPLIT: Same as `SPLIT`, but the button is drawn as the default button of the window
+
+        Split buttons require the version 6 of comctl32 (an application manifest with the common controls dependency, see `enable_visual_styles`).
+        With an older version, the button is drawn as a regular push button and the arrow is never shown.
     */
     pub struct ButtonFlags: u32 {
         const NONE = 0;
@@ -31,6 +40,8 @@ bitflags! {
         const BITMAP = BS_BITMAP;
         const NOTIFY = BS_NOTIFY;
         const TAB_STOP = WS_TABSTOP;
+        const SPLIT = BS_SPLITBUTTON;
+        const DEFAULT_SPLIT = BS_DEFSPLITBUTTON;
     }
 }
 
@@ -54,8 +65,9 @@ Button is not behind any features.
   * `focus`:    The control receive focus after being created
 
 **Control events:**
-  * `OnButtonClick`: When the button is clicked once by the user
+  * `OnButtonClick`: When the button is clicked once by the user. `EventData::OnButtonClick` tells if it was activated by the mouse, the keyboard or `click`
   * `OnButtonDoubleClick`: When the button is clicked twice rapidly by the user
+  * `OnButtonDropdown`: When the dropdown arrow of a split button is clicked. `EventData::OnButtonDropdown` has the rectangle of the button to position a menu
   * `MousePress(_)`: Generic mouse press events on the button
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
@@ -96,7 +108,10 @@ impl Button {
         }
     }
 
-    /// Simulate a user click
+    /**
+        Simulate a user click (`BM_CLICK`). The button notifies its parent like a mouse click, so `OnButtonClick`
+        is raised (with `programmatic` set to `true`) before this method returns. Does nothing if the button is disabled.
+    */
     pub fn click(&self) {
         use winapi::um::winuser::BM_CLICK;
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
@@ -127,6 +142,54 @@ impl Button {
         wh::send_message(handle, BM_SETIMAGE, IMAGE_ICON as WPARAM, image_handle);
     }
 
+    /**
+        Sets the image list of the button. The image at index 0 is drawn left of the text of the button.
+        Pass None to remove the image list. The image list is not copied and must outlive the button.
+
+        If the image list has more than one image, the images are used for the button states:
+        normal, hot, pressed, disabled, defaulted and stylus hot.
+
+        This is only available is the feature "image-list" is enabled.
+    */
+    #[cfg(feature = "image-list")]
+    pub fn set_image_list(&self, list: Option<&ImageList>) {
+        use winapi::um::commctrl::{BCM_SETIMAGELIST, BUTTON_IMAGELIST, BUTTON_IMAGELIST_ALIGN_LEFT};
+        use winapi::shared::windef::RECT;
+        use std::ptr;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let mut info = BUTTON_IMAGELIST {
+            himl: list.map(|l| l.handle).unwrap_or(ptr::null_mut()),
+            margin: RECT { left: 0, top: 0, right: 0, bottom: 0 },
+            uAlign: BUTTON_IMAGELIST_ALIGN_LEFT,
+        };
+
+        wh::send_message(handle, BCM_SETIMAGELIST, 0, &mut info as *mut BUTTON_IMAGELIST as _);
+    }
+
+    /**
+        Returns the image list of the button or None if there is none.
+        The returned image list is not owned.
+
+        This is only available is the feature "image-list" is enabled.
+    */
+    #[cfg(feature = "image-list")]
+    pub fn image_list(&self) -> Option<ImageList> {
+        use winapi::um::commctrl::{BCM_GETIMAGELIST, BUTTON_IMAGELIST};
+        use std::mem;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let mut info: BUTTON_IMAGELIST = unsafe { mem::zeroed() };
+        wh::send_message(handle, BCM_GETIMAGELIST, 0, &mut info as *mut BUTTON_IMAGELIST as _);
+
+        match info.himl.is_null() {
+            true => None,
+            false => Some(ImageList { handle: info.himl, owned: false })
+        }
+    }
+
     /// Returns the current image in the button.
     /// If the button has a bitmap, the value will be returned in `bitmap`
     /// If the button has a icon, the value will be returned in `icon`
@@ -135,6 +198,7 @@ impl Button {
         use winapi::shared::minwindef::WPARAM;
         use winapi::shared::windef::HBITMAP;
         use winapi::um::winnt::HANDLE;
+        use std::ptr;
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
 
@@ -145,7 +209,7 @@ impl Button {
         *icon = None;
 
         if bitmap_handle != 0 && rh::is_bitmap(bitmap_handle as HBITMAP) {
-            *bitmap = Some(Bitmap { handle: bitmap_handle as HANDLE, owned: false });
+            *bitmap = Some(Bitmap { handle: bitmap_handle as HANDLE, owned: false, dc: ptr::null_mut() });
         } else if icon_handle != 0 {
             *icon = Some(Icon { handle: icon_handle as HANDLE, owned: false });
         }
@@ -242,6 +306,64 @@ impl Button {
         unsafe { wh::set_window_text(handle, v) }
     }
 
+    /// Resizes the button so that its label fits, using the button font. `padding` is added on each side, in logical pixels.
+    /// Use this after `set_text` for buttons with a dynamic label.
+    pub fn fit_to_content(&self, padding: u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::fit_window_to_text(handle, wh::push_button_text_extra(), padding); }
+    }
+
+    /**
+        Saves the input that activates the button (mouse, keyboard or `BM_CLICK`) in a window property, so that
+        `OnButtonClick` can report it. `BM_CLICK` simulates a mouse click, so the mouse messages it sends are ignored.
+        The pending flag is cleared once `BM_CLICK` returns, because a disabled button ignores the simulated click.
+    */
+    fn hook_click_source(&self) {
+        use crate::bind_raw_event_handler_inner;
+        use crate::win32::window_helper::{BUTTON_CLICK_HANDLER_ID, BUTTON_CLICK_SOURCE_PROP, BUTTON_CLICK_TIME_PROP,
+            CLICK_SOURCE_MOUSE, CLICK_SOURCE_KEYBOARD, CLICK_SOURCE_PROGRAMMATIC, CLICK_SOURCE_PENDING};
+        use winapi::um::winuser::{SetPropW, GetPropW, RemovePropW, GetMessageTime, BM_CLICK, WM_LBUTTONUP, WM_KEYUP, WM_DESTROY, VK_SPACE};
+        use winapi::um::commctrl::DefSubclassProc;
+        use crate::win32::base_helper::to_utf16;
+
+        let source_prop = to_utf16(BUTTON_CLICK_SOURCE_PROP);
+        let time_prop = to_utf16(BUTTON_CLICK_TIME_PROP);
+        let handler = bind_raw_event_handler_inner(&self.handle, BUTTON_CLICK_HANDLER_ID, move |hwnd, msg, w, l| {
+            unsafe {
+                let current = GetPropW(hwnd, source_prop.as_ptr()) as usize;
+                let source = match msg {
+                    BM_CLICK => Some(CLICK_SOURCE_PROGRAMMATIC | CLICK_SOURCE_PENDING),
+                    WM_LBUTTONUP if current & CLICK_SOURCE_PENDING != 0 => Some(CLICK_SOURCE_PROGRAMMATIC),
+                    WM_LBUTTONUP => Some(CLICK_SOURCE_MOUSE),
+                    WM_KEYUP if w == VK_SPACE as usize => Some(CLICK_SOURCE_KEYBOARD),
+                    WM_DESTROY => {
+                        RemovePropW(hwnd, source_prop.as_ptr());
+                        RemovePropW(hwnd, time_prop.as_ptr());
+                        None
+                    },
+                    _ => None
+                };
+
+                if let Some(source) = source {
+                    SetPropW(hwnd, source_prop.as_ptr(), source as _);
+                    SetPropW(hwnd, time_prop.as_ptr(), GetMessageTime() as u32 as usize as _);
+                }
+
+                if msg == BM_CLICK {
+                    let result = DefSubclassProc(hwnd, msg, w, l);
+                    let source = GetPropW(hwnd, source_prop.as_ptr()) as usize;
+                    SetPropW(hwnd, source_prop.as_ptr(), (source & !CLICK_SOURCE_PENDING) as _);
+                    return Some(result);
+                }
+            }
+
+            None
+        });
+
+        // The handler is freed when the button is dropped
+        drop(handler);
+    }
+
     /// Winapi class name used during control creation
     pub fn class_name(&self) -> &'static str {
         "BUTTON"
@@ -261,6 +383,13 @@ impl Button {
 
 impl Drop for Button {
     fn drop(&mut self) {
+        use crate::unbind_raw_event_handler_inner;
+        use crate::win32::window_helper::BUTTON_CLICK_HANDLER_ID;
+
+        if self.handle.hwnd().is_some() {
+            drop(unbind_raw_event_handler_inner(&self.handle, BUTTON_CLICK_HANDLER_ID));
+        }
+
         self.handle.destroy();
     }
 }
@@ -376,6 +505,8 @@ impl<'a> ButtonBuilder<'a> {
             out.set_focus();
         }
 
+        out.hook_click_source();
+
         Ok(())
     }
 
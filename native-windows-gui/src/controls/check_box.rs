@@ -250,6 +250,13 @@ impl CheckBox {
         unsafe { wh::set_window_text(handle, v) }
     }
 
+    /// Resizes the check box so that its glyph and its text fit, using the check box font.
+    /// `padding` is added on each side, in logical pixels.
+    pub fn fit_to_content(&self, padding: u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::fit_check_window_to_text(handle, padding) }
+    }
+
     /// Winapi class name used during control creation
     pub fn class_name(&self) -> &'static str {
         "BUTTON"
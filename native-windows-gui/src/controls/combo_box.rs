@@ -1,12 +1,13 @@
 use winapi::shared::windef::HWND;
 use winapi::shared::minwindef::{LPARAM, WPARAM};
-use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, WS_TABSTOP};
+use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, WS_TABSTOP, CBS_OWNERDRAWFIXED, CBS_HASSTRINGS};
 use crate::win32::base_helper::{check_hwnd, to_utf16, from_utf16};
 use crate::win32::window_helper as wh;
 use crate::{Font, NwgError, VTextAlign, RawEventHandler, unbind_raw_event_handler};
 use super::{ControlHandle, ControlBase};
 use std::cell::{Ref, RefMut, RefCell};
 use std::fmt::Display;
+use std::rc::Rc;
 use std::mem;
 
 const NOT_BOUND: &'static str = "Combobox is not yet bound to a winapi object";
@@ -21,12 +22,14 @@ bitflags! {
         * VISIBLE:  The combobox is immediatly visible after creation
         * DISABLED: The combobox cannot be interacted with by the user. It also has a grayed out look.
         * TAB_STOP: The control can be selected using tab navigation
+        * OWNER_DRAW: The items are painted by the application in the `OnComboBoxDraw` event
     */
     pub struct ComboBoxFlags: u32 {
         const NONE = 0;
         const VISIBLE = WS_VISIBLE;
         const DISABLED = WS_DISABLED;
         const TAB_STOP = WS_TABSTOP;
+        const OWNER_DRAW = CBS_OWNERDRAWFIXED | CBS_HASSTRINGS;
     }
 }
 
@@ -47,15 +50,24 @@ Requires the `combobox` feature.
   * `collection`:     The default collection of the combobox
   * `selected_index`: The default selected index. None means no values are selected.  
   * `focus`:          The control receive focus after being created
+  * `item_height`:    The height of the items in the dropdown list
+  * `editable`:       If the selection field is an edit control where the user can type a value. Default `false`
 
 **Control events:**
   * `OnComboBoxClosed`: When the combobox dropdown is closed
   * `OnComboBoxDropdown`: When the combobox dropdown is opened
   * `OnComboxBoxSelection`: When a new value in a combobox is choosen
+  * `OnComboBoxDraw`: When an item of an `OWNER_DRAW` combobox must be painted
   * `MousePress(_)`: Generic mouse press events on the checkbox
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
 
+**Owner drawing:**  
+With the `OWNER_DRAW` flag, the combobox sends `WM_DRAWITEM` to its parent for every item that must be painted.
+NWG raises `OnComboBoxDraw` on the combobox and paints the item text if the handler does not consume the event.
+`WM_MEASUREITEM` is sent once while the control is created, before NWG can hook the parent, so the system default height
+is used. Call `set_item_height` to change it. The selection field has its own height, see `set_selection_field_height`.
+
 
 ```rust
 use native_windows_gui as nwg;
@@ -91,6 +103,8 @@ impl<D: Display+Default> ComboBox<D> {
             font: None,
             collection: None,
             selected_index: None,
+            item_height: None,
+            editable: false,
             parent: None
         }
     }
@@ -154,25 +168,8 @@ impl<D: Display+Default> ComboBox<D> {
     /// Return the display value of the currenctly selected item
     /// Return `None` if no item is selected. This reads the visual value.
     pub fn selection_string(&self) -> Option<String> {
-        use winapi::um::winuser::{CB_GETCURSEL, CB_GETLBTEXTLEN, CB_GETLBTEXT, CB_ERR};
-        use winapi::shared::ntdef::WCHAR;
-
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-
-        let index = wh::send_message(handle, CB_GETCURSEL, 0, 0);
-
-        if index == CB_ERR { None }
-        else {
-            let index = index as usize;
-            let length = (wh::send_message(handle, CB_GETLBTEXTLEN, index, 0) as usize) + 1; // +1 for the null character
-            let mut buffer: Vec<WCHAR> = Vec::with_capacity(length);
-            unsafe { 
-                buffer.set_len(length); 
-                wh::send_message(handle, CB_GETLBTEXT, index, buffer.as_ptr() as LPARAM);
-            }
-
-            Some(from_utf16(&buffer))
-        }
+        selection_string_inner(handle)
     }
 
     /// Set the currently selected item in the combobox.
@@ -278,6 +275,146 @@ impl<D: Display+Default> ComboBox<D> {
         col
     }
 
+    /// Clears the control and free the underlying collection. The text of an editable combobox is also cleared.
+    pub fn clear(&self) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        self.clear_inner(handle);
+        unsafe { wh::set_window_text(handle, ""); }
+        self.collection.borrow_mut().clear();
+    }
+
+    /// Removes the selected value without changing the collection. Same as `set_selection(None)`
+    pub fn clear_selection(&self) {
+        self.set_selection(None);
+    }
+
+    /**
+        Repopulates `dependent` each time the selection of this combobox changes, to build cascading dropdowns (ex: country, state, city).
+        `f` receives the display value of the new selection (`None` if nothing is selected) and returns the new collection of `dependent`.
+        The selection of `dependent` is cleared.
+
+        The selection is read from the `CBN_SELCHANGE` notification, so `set_selection` does not repopulate `dependent`.
+        After the update, `dependent` sends its own `CBN_SELCHANGE` notification. It raises `OnComboxBoxSelection` on `dependent`
+        and repopulates the next combobox of the chain, so changing the country clears the cities. 
+
+        The handler is bound on the parent of this combobox and only keeps a weak reference to `dependent`: it does nothing
+        once `dependent` is dropped. It lives until the parent is destroyed. To break the chain earlier, pass the returned handler
+        to `unbind_raw_event_handler`.
+
+        ```rust
+        use native_windows_gui as nwg;
+        use std::rc::Rc;
+
+        fn cascade(country: &nwg::ComboBox<&'static str>, state: &Rc<nwg::ComboBox<&'static str>>) -> nwg::RawEventHandler {
+            country.on_change_repopulate(state, |country| match country {
+                Some("Canada") => vec!["Ontario", "Quebec"],
+                Some("France") => vec!["Bretagne", "Normandie"],
+                _ => Vec::new()
+            }).unwrap()
+        }
+        ```
+    */
+    pub fn on_change_repopulate<D2, F>(&self, dependent: &Rc<ComboBox<D2>>, f: F) -> Result<RawEventHandler, NwgError>
+        where D2: Display+Default+'static,
+              F: Fn(Option<&str>) -> Vec<D2> + 'static
+    {
+        use crate::bind_raw_event_handler_inner;
+        use winapi::um::winuser::{WM_COMMAND, CBN_SELCHANGE, GetDlgCtrlID};
+        use winapi::shared::minwindef::{HIWORD, MAKELONG};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        /// Keep generating ids so that several dependents can be bound to the same parent
+        static REPOPULATE_HANDLER_ID: AtomicUsize = AtomicUsize::new(0xAFFF);
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        check_hwnd(&dependent.handle, NOT_BOUND, BAD_HANDLE);
+
+        let parent = ControlHandle::Hwnd(wh::get_window_parent(handle));
+        let dependent = Rc::downgrade(dependent);
+        let handler_id = REPOPULATE_HANDLER_ID.fetch_add(1, Ordering::SeqCst);
+
+        bind_raw_event_handler_inner(&parent, handler_id, move |_hwnd, msg, w, l| {
+            if msg != WM_COMMAND || l as HWND != handle || HIWORD(w as u32) != CBN_SELCHANGE {
+                return None;
+            }
+
+            let dependent = match dependent.upgrade() {
+                Some(d) => d,
+                None => { return None; }
+            };
+
+            let selection = selection_string_inner(handle);
+            dependent.clear();
+            dependent.set_collection(f(selection.as_deref()));
+
+            // Cascades to the dependents of the dependent
+            let dependent_handle = dependent.handle.hwnd().unwrap();
+            let id = unsafe { GetDlgCtrlID(dependent_handle) };
+            let command = MAKELONG(id as u16, CBN_SELCHANGE);
+            wh::send_message(wh::get_window_parent(dependent_handle), WM_COMMAND, command as WPARAM, dependent_handle as LPARAM);
+
+            None
+        })
+    }
+
+    /// Return the height of the items in the dropdown list
+    pub fn item_height(&self) -> u32 {
+        use winapi::um::winuser::CB_GETITEMHEIGHT;
+        use crate::win32::high_dpi::physical_to_logical;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let height = wh::send_message(handle, CB_GETITEMHEIGHT, 0, 0) as i32;
+        let (_, height) = unsafe { physical_to_logical(0, height) };
+        height as u32
+    }
+
+    /// Set the height of the items in the dropdown list.
+    /// This does not change the height of the selection field. See `set_selection_field_height`.
+    pub fn set_item_height(&self, height: u32) {
+        self.set_item_height_inner(0, height);
+    }
+
+    /// Return the height of the selection field of the combobox
+    pub fn selection_field_height(&self) -> u32 {
+        use winapi::um::winuser::CB_GETITEMHEIGHT;
+        use crate::win32::high_dpi::physical_to_logical;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let height = wh::send_message(handle, CB_GETITEMHEIGHT, -1isize as WPARAM, 0) as i32;
+        let (_, height) = unsafe { physical_to_logical(0, height) };
+        height as u32
+    }
+
+    /// Set the height of the selection field of the combobox (index `-1` of `CB_SETITEMHEIGHT`).
+    /// The total height of the control is resized to match.
+    pub fn set_selection_field_height(&self, height: u32) {
+        self.set_item_height_inner(-1isize as WPARAM, height);
+    }
+
+    /**
+        Sets the placeholder text displayed in the selection field of an editable combobox (see the `editable` builder parameter)
+        when it is empty and does not have focus. Pass `None` to remove the placeholder. Does nothing if the combobox is not editable.
+
+        The cue banner is a feature of the edit control, so the handle of the inner edit control is fetched first with `CB_GETCOMBOBOXINFO`.
+    */
+    pub fn set_placeholder(&self, text: Option<&str>) {
+        use winapi::um::commctrl::EM_SETCUEBANNER;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        if let Some(edit) = unsafe { combo_edit_handle(handle) } {
+            let text = to_utf16(text.unwrap_or(""));
+            wh::send_message(edit, EM_SETCUEBANNER, 0, text.as_ptr() as LPARAM);
+        }
+    }
+
+    /// Returns `true` if the selection field of the combobox is editable
+    pub fn editable(&self) -> bool {
+        use winapi::um::winuser::{CBS_DROPDOWNLIST, CBS_DROPDOWN};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_style(handle) & CBS_DROPDOWNLIST == CBS_DROPDOWN
+    }
+
     /// Return the number of items in the control. NOT the inner rust collection
     pub fn len(&self) -> usize {
         use winapi::um::winuser::CB_GETCOUNT;
@@ -399,6 +536,15 @@ impl<D: Display+Default> ComboBox<D> {
         CBS_DROPDOWNLIST | WS_CHILD | WS_BORDER
     }
 
+    fn set_item_height_inner(&self, index: WPARAM, height: u32) {
+        use winapi::um::winuser::CB_SETITEMHEIGHT;
+        use crate::win32::high_dpi::logical_to_physical;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let (_, height) = unsafe { logical_to_physical(0, height as i32) };
+        wh::send_message(handle, CB_SETITEMHEIGHT, index, height as LPARAM);
+    }
+
     /// Remove all value displayed in the control without touching the rust collection
     fn clear_inner(&self, handle: HWND) {
         use winapi::um::winuser::CB_RESETCONTENT;
@@ -514,6 +660,23 @@ impl<D: Display+Default> ComboBox<D> {
 
 }
 
+fn selection_string_inner(handle: HWND) -> Option<String> {
+    use winapi::um::winuser::{CB_GETCURSEL, CB_GETLBTEXTLEN, CB_GETLBTEXT, CB_ERR};
+    use winapi::shared::ntdef::WCHAR;
+
+    let index = wh::send_message(handle, CB_GETCURSEL, 0, 0);
+
+    if index == CB_ERR { None }
+    else {
+        let index = index as usize;
+        let length = (wh::send_message(handle, CB_GETLBTEXTLEN, index, 0) as usize) + 1; // +1 for the null character
+        let mut buffer: Vec<WCHAR> = vec![0; length];
+        wh::send_message(handle, CB_GETLBTEXT, index, buffer.as_mut_ptr() as LPARAM);
+
+        Some(from_utf16(&buffer))
+    }
+}
+
 impl<D: Display+Default> Drop for ComboBox<D> {
     fn drop(&mut self) {
         let handler = self.handler0.borrow();
@@ -535,6 +698,8 @@ pub struct ComboBoxBuilder<'a, D: Display+Default> {
     font: Option<&'a Font>,
     collection: Option<Vec<D>>,
     selected_index: Option<usize>,
+    item_height: Option<u32>,
+    editable: bool,
     parent: Option<ControlHandle>
 }
 
@@ -590,12 +755,24 @@ impl<'a, D: Display+Default> ComboBoxBuilder<'a, D> {
         self
     }
 
+    pub fn item_height(mut self, height: u32) -> ComboBoxBuilder<'a, D> {
+        self.item_height = Some(height);
+        self
+    }
+
+    pub fn editable(mut self, editable: bool) -> ComboBoxBuilder<'a, D> {
+        self.editable = editable;
+        self
+    }
+
     pub fn v_align(self, _align: VTextAlign) -> ComboBoxBuilder<'a, D> {
         // Disabled for now because of a bug. Keep the method for backward compatibility
         self
     }
 
     pub fn build(self, out: &mut ComboBox<D>) -> Result<(), NwgError> {
+        use winapi::um::winuser::{CBS_DROPDOWNLIST, CBS_DROPDOWN};
+
         let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
 
         let parent = match self.parent {
@@ -606,9 +783,15 @@ impl<'a, D: Display+Default> ComboBoxBuilder<'a, D> {
         // Drop the old object
         *out = ComboBox::default();
 
+        // An editable combobox uses the `CBS_DROPDOWN` style instead of `CBS_DROPDOWNLIST`
+        let forced_flags = match self.editable {
+            true => (out.forced_flags() & !CBS_DROPDOWNLIST) | CBS_DROPDOWN,
+            false => out.forced_flags()
+        };
+
         out.handle = ControlBase::build_hwnd()
             .class_name(out.class_name())
-            .forced_flags(out.forced_flags())
+            .forced_flags(forced_flags)
             .flags(flags)
             .ex_flags(self.ex_flags)
             .size(self.size)
@@ -622,6 +805,10 @@ impl<'a, D: Display+Default> ComboBoxBuilder<'a, D> {
             out.set_font(Font::global_default().as_ref());
         }
 
+        if let Some(height) = self.item_height {
+            out.set_item_height(height);
+        }
+
         if self.collection.is_some() {
             out.set_collection(self.collection.unwrap());
         }
@@ -646,3 +833,21 @@ impl<D: Display+Default> PartialEq for ComboBox<D> {
         self.handle == other.handle
     }
 }
+
+/// Returns the handle of the edit control of an editable combobox
+unsafe fn combo_edit_handle(handle: HWND) -> Option<HWND> {
+    use winapi::um::winuser::{CB_GETCOMBOBOXINFO, COMBOBOXINFO};
+
+    let mut info: COMBOBOXINFO = mem::zeroed();
+    info.cbSize = mem::size_of::<COMBOBOXINFO>() as u32;
+
+    if wh::send_message(handle, CB_GETCOMBOBOXINFO, 0, &mut info as *mut COMBOBOXINFO as LPARAM) == 0 {
+        return None;
+    }
+
+    // For a non editable combobox, `hwndItem` is the combobox itself
+    match info.hwndItem.is_null() || info.hwndItem == handle {
+        true => None,
+        false => Some(info.hwndItem)
+    }
+}
@@ -1,5 +1,6 @@
 use winapi::shared::windef::{HWND, HMENU};
 use crate::win32::window_helper as wh;
+use crate::WantKeys;
 
 
 /**
@@ -102,6 +103,769 @@ impl ControlHandle {
         }
     }
 
+    /**
+        Sets the keys that the control wants to receive instead of the dialog manager.
+
+        NWG events loop (`dispatch_thread_events`) sends every message to `IsDialogMessage` before dispatching it.
+        `IsDialogMessage` asks the focused control which keys it wants by sending `WM_GETDLGCODE` and uses the other ones
+        for the keyboard navigation (ex: Tab moves the focus, Enter/Esc raise `OnKeyEnter`/`OnKeyEsc` on the window).
+        The keys returned here are added to the default answer of the control so they reach `OnKeyPress` and `OnChar`.
+
+        Calling this method again replaces the previous value. `WantKeys::NONE` restores the default behaviour.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn set_want_keys(&self, keys: WantKeys) {
+        use winapi::shared::minwindef::LRESULT;
+        use winapi::um::winuser::WM_GETDLGCODE;
+        use winapi::um::commctrl::DefSubclassProc;
+        use crate::win32::window_helper::WANT_KEYS_HANDLER_ID;
+        use crate::{bind_raw_event_handler_inner, unbind_raw_event_handler_inner};
+
+        self.hwnd().expect("Cannot set the wanted keys of a control that is not window-like");
+
+        drop(unbind_raw_event_handler_inner(self, WANT_KEYS_HANDLER_ID));
+        if keys.is_empty() {
+            return;
+        }
+
+        let bits = keys.bits() as LRESULT;
+        let handler = bind_raw_event_handler_inner(self, WANT_KEYS_HANDLER_ID, move |hwnd, msg, w, l| {
+            match msg {
+                WM_GETDLGCODE => unsafe { Some(DefSubclassProc(hwnd, msg, w, l) | bits) },
+                _ => None
+            }
+        });
+
+        // The handler is freed by `unbind_raw_event_handler_inner` the next time the keys are set
+        drop(handler);
+    }
+
+    /**
+        Returns the keys the control currently wants to handle itself (the answer to `WM_GETDLGCODE`).
+        This includes the default keys of the control (ex: an edit control always wants the chars and the arrows).
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn want_keys(&self) -> WantKeys {
+        use winapi::um::winuser::WM_GETDLGCODE;
+
+        let hwnd = self.hwnd().expect("Cannot get the wanted keys of a control that is not window-like");
+        let code = wh::send_message(hwnd, WM_GETDLGCODE, 0, 0);
+        WantKeys::from_bits_truncate(code as u32)
+    }
+
+    /**
+        Marks the whole client area of the control as invalid, so that it is repainted (with `OnPaint` for custom drawn controls)
+        the next time the events loop is idle. The background is erased before painting.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn invalidate(&self) {
+        use winapi::um::winuser::InvalidateRect;
+        use std::ptr;
+
+        let hwnd = self.hwnd().expect("Cannot invalidate a control that is not window-like");
+        unsafe { InvalidateRect(hwnd, ptr::null(), 1); }
+    }
+
+    /**
+        Marks a part of the control as invalid so that it is repainted the next time the events loop is idle.
+        `rect` is `[left, top, right, bottom]` in client coordinates, in physical pixels (the same space as `PaintData::update_rect`).
+        If `erase` is true, the background of the region is erased (`WM_ERASEBKGND`) before painting.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn invalidate_rect(&self, rect: [i32; 4], erase: bool) {
+        use winapi::um::winuser::InvalidateRect;
+        use winapi::shared::windef::RECT;
+
+        let hwnd = self.hwnd().expect("Cannot invalidate a control that is not window-like");
+        let [left, top, right, bottom] = rect;
+        let rect = RECT { left, top, right, bottom };
+
+        unsafe { InvalidateRect(hwnd, &rect, erase as _); }
+    }
+
+    /**
+        Repaints the invalid region of the control immediately (`UpdateWindow`), without waiting for the events loop.
+        Does nothing if the control has no invalid region.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn update(&self) {
+        use winapi::um::winuser::UpdateWindow;
+
+        let hwnd = self.hwnd().expect("Cannot update a control that is not window-like");
+        unsafe { UpdateWindow(hwnd); }
+    }
+
+    /**
+        Invalidates and repaints the control and all its children immediately (`RedrawWindow`).
+        If `erase` is true, the background is erased before painting.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn redraw(&self, erase: bool) {
+        use winapi::um::winuser::{RedrawWindow, RDW_INVALIDATE, RDW_UPDATENOW, RDW_ALLCHILDREN, RDW_ERASE};
+        use std::ptr;
+
+        let hwnd = self.hwnd().expect("Cannot redraw a control that is not window-like");
+        let mut flags = RDW_INVALIDATE | RDW_UPDATENOW | RDW_ALLCHILDREN;
+        if erase {
+            flags |= RDW_ERASE;
+        }
+
+        unsafe { RedrawWindow(hwnd, ptr::null(), ptr::null_mut(), flags); }
+    }
+
+    /**
+        Starts a drag and drop operation with the control as the source and `data` as the dragged content.
+        Other applications (ex: the explorer for `DragData::Files`, a text editor for `DragData::Text`) can receive the data.
+        This is usually called from a `MousePressLeftDown` or `OnMouseMove` handler.
+
+        The call blocks until the user drops the data or cancels the operation, but the events of the application are
+        still dispatched in the meantime (`DoDragDrop` runs its own modal loop). It must be called from the GUI thread
+        because drag and drop requires a single-threaded COM apartment. OLE is initialized on the thread by `nwg::init`.
+
+        Returns the effect selected by the drop target. Copy and move are allowed. If the result is `DropEffect::Move`,
+        the target took the data and the source should remove its own copy. `DropEffect::None` means that the operation was canceled.
+
+        Requires the `drag-drop` feature. Panics if the control is not a window-like control.
+    */
+    #[cfg(feature = "drag-drop")]
+    pub fn begin_drag_drop(&self, data: crate::DragData) -> Result<crate::DropEffect, crate::NwgError> {
+        use crate::win32::drag_drop::do_drag_drop;
+
+        self.hwnd().expect("Cannot start a drag and drop operation from a control that is not window-like");
+        unsafe { do_drag_drop(&data) }
+    }
+
+    /**
+        Registers (or unregisters if `enabled` is false) the control as an OLE drop target. While registered, the control
+        raises `OnDragEnter`, `OnDragOver`, `OnDragLeave` and `OnDragDrop` with a `DropTargetData`. The handlers can
+        inspect the dragged text or files before the drop and choose the effect (copy, move, or none to refuse the data).
+
+        A registered control does not receive `OnFileDrop` anymore. `OnFileDrop` (the `accept_files` flag of the window)
+        is still the simplest way to receive files if the data does not need to be checked before the drop.
+
+        The drop target is revoked automatically when the control is destroyed.
+        It must be called from the GUI thread. NWG initializes OLE on the thread the first time.
+
+        Requires the `drag-drop` feature. Panics if the control is not a window-like control.
+    */
+    #[cfg(feature = "drag-drop")]
+    pub fn set_drop_target(&self, enabled: bool) -> Result<(), crate::NwgError> {
+        use winapi::um::winuser::WM_DESTROY;
+        use crate::win32::drag_drop::{register_drop_target, revoke_drop_target};
+        use crate::win32::window_helper::DROP_TARGET_HANDLER_ID;
+        use crate::{bind_raw_event_handler_inner, unbind_raw_event_handler_inner};
+
+        let hwnd = self.hwnd().expect("Cannot register a drop target on a control that is not window-like");
+
+        drop(unbind_raw_event_handler_inner(self, DROP_TARGET_HANDLER_ID));
+        if !enabled {
+            unsafe { revoke_drop_target(hwnd); }
+            return Ok(());
+        }
+
+        unsafe { register_drop_target(hwnd)?; }
+
+        // OLE keeps a reference to the drop target until it is revoked, which must happen before the window is gone
+        let handler = bind_raw_event_handler_inner(self, DROP_TARGET_HANDLER_ID, move |hwnd, msg, _w, _l| {
+            if msg == WM_DESTROY {
+                unsafe { revoke_drop_target(hwnd); }
+            }
+            None
+        });
+
+        // The handler is freed by `unbind_raw_event_handler_inner` the next time this is called
+        drop(handler);
+
+        Ok(())
+    }
+
+    /**
+        Draws a colored frame around the control for `duration` to draw the attention of the user
+        (ex: a field that failed validation). The frame disappears automatically after the duration.
+
+        The frame is a transient overlay window placed on top of the control, so the control does not need to
+        handle custom drawing. The overlay ignores the mouse and follows the control if it moves. It is driven by
+        a timer, so it only updates while the events loop is running.
+
+        Calling this method again while the frame is visible replaces it. Use `cancel_highlight` to remove it early.
+
+        Returns an error if the overlay window cannot be created.
+        Panics if the control is not a window-like control.
+    */
+    pub fn highlight(&self, duration: ::std::time::Duration, color: [u8; 3]) -> Result<(), crate::NwgError> {
+        use crate::win32::highlight::show_highlight;
+
+        let hwnd = self.hwnd().expect("Cannot highlight a control that is not window-like");
+        unsafe { show_highlight(hwnd, duration, color) }
+    }
+
+    /**
+        Removes the frame drawn by `highlight` before its duration ends. Does nothing if the control is not highlighted.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn cancel_highlight(&self) {
+        use crate::win32::highlight::cancel_highlight;
+
+        let hwnd = self.hwnd().expect("Cannot cancel the highlight of a control that is not window-like");
+        unsafe { cancel_highlight(hwnd); }
+    }
+
+    /**
+        Returns `true` if the frame drawn by `highlight` is currently active on the control.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn is_highlighted(&self) -> bool {
+        use crate::win32::highlight::is_highlighted;
+
+        let hwnd = self.hwnd().expect("Cannot check the highlight of a control that is not window-like");
+        unsafe { is_highlighted(hwnd) }
+    }
+
+    /**
+        Sets a validator that checks the text of the control. The validator returns the error to display to the user if the text is invalid.
+        Setting a new validator replaces the previous one.
+
+        The validator runs when the control loses the keyboard focus, when `validate` is called,
+        and for every control of a window when `Window::validate_all` is called (before submitting a form, for example).
+
+        The errors are displayed with a balloon tooltip under the edit controls and with a red frame around the other controls.
+        Use `set_validation_display` to choose another display, or `ValidationDisplay::Silent` to display the errors yourself
+        with `validation_error`. The error is hidden when the text becomes valid.
+
+        The validator is removed when the control is destroyed. The text is read with `GetWindowText`, so the validator of a
+        control without a text (like a list box) receives an empty string.
+
+        ```rust
+        use native_windows_gui as nwg;
+
+        fn validate_age(input: &nwg::TextInput) {
+            input.handle.set_validator(|text| match text.parse::<u32>() {
+                Ok(age) if age < 150 => Ok(()),
+                _ => Err("Enter an age between 0 and 149".to_string())
+            });
+        }
+        ```
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn set_validator<F>(&self, validator: F)
+        where F: Fn(&str) -> Result<(), String> + 'static
+    {
+        use crate::win32::validation::{set_validator, Validator};
+
+        let hwnd = self.hwnd().expect("Cannot set the validator of a control that is not window-like");
+        let validator: Validator = ::std::rc::Rc::new(validator);
+        set_validator(hwnd, Some(validator));
+    }
+
+    /**
+        Removes the validator set with `set_validator`. The error of the last validation is not hidden.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn remove_validator(&self) {
+        use crate::win32::validation::set_validator;
+
+        let hwnd = self.hwnd().expect("Cannot remove the validator of a control that is not window-like");
+        set_validator(hwnd, None);
+    }
+
+    /**
+        Sets how the errors of the validator are displayed. See `ValidationDisplay`. Must be called after `set_validator`.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn set_validation_display(&self, display: crate::ValidationDisplay) {
+        use crate::win32::validation::set_validation_display;
+
+        let hwnd = self.hwnd().expect("Cannot set the validation display of a control that is not window-like");
+        set_validation_display(hwnd, display);
+    }
+
+    /**
+        Runs the validator of the control now and displays the error, if any.
+        Returns `true` if the text is valid or if the control has no validator.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn validate(&self) -> bool {
+        use crate::win32::validation::validate;
+
+        let hwnd = self.hwnd().expect("Cannot validate a control that is not window-like");
+        unsafe { validate(hwnd) }
+    }
+
+    /**
+        Returns the error of the last validation of the control, or `None` if the text was valid or was not validated yet.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn validation_error(&self) -> Option<String> {
+        use crate::win32::validation::validation_error;
+
+        let hwnd = self.hwnd().expect("Cannot get the validation error of a control that is not window-like");
+        validation_error(hwnd)
+    }
+
+    /**
+        Moves and resizes the control from its current geometry to `pos` and `size` over `duration`.
+        The values are in logical pixels, like `set_position` and `set_size`. `done` is called once the control reaches its final geometry.
+
+        The animation is driven by `WM_TIMER` messages, so it runs on the UI thread and only progresses while the events loop is running.
+        Each step raises the usual move and resize events of the control.
+
+        If the control is already animated, the new animation replaces the old one and starts from the current geometry.
+        The completion callback of the old animation is dropped without being called.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn animate_to<F: FnOnce() + 'static>(&self, pos: (i32, i32), size: (u32, u32), duration: ::std::time::Duration, easing: crate::Easing, done: F) {
+        use crate::win32::animation::animate_to;
+
+        let hwnd = self.hwnd().expect("Cannot animate a control that is not window-like");
+        unsafe { animate_to(hwnd, pos, size, duration, easing, Box::new(done)); }
+    }
+
+    /**
+        Stops the animation started by `animate_to`, leaving the control at its current geometry.
+        The completion callback is dropped without being called. Does nothing if the control is not animated.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn stop_animation(&self) {
+        use crate::win32::animation::stop_animation;
+
+        let hwnd = self.hwnd().expect("Cannot stop the animation of a control that is not window-like");
+        unsafe { stop_animation(hwnd); }
+    }
+
+    /**
+        Returns `true` if an animation started by `animate_to` is running on the control.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn is_animating(&self) -> bool {
+        use crate::win32::animation::is_animating;
+
+        let hwnd = self.hwnd().expect("Cannot check the animation of a control that is not window-like");
+        is_animating(hwnd)
+    }
+
+    /**
+        Sets the text of the tooltip displayed when the mouse hovers the control. `None` removes the tooltip.
+        This is the simple alternative to a `Tooltip` control: there is nothing to build or to keep alive.
+
+        The controls of a top level window share a single tooltip. It is created the first time one of them gets a tooltip,
+        and it is destroyed with the top level window (the window returned by `GetAncestor(GA_ROOT)` when the tooltip is set).
+        The tooltip of a control is removed automatically when the control is destroyed.
+
+        The text is copied in a buffer owned by NWG, so it is not limited to 79 characters like the text set in `OnTooltipText`.
+        Long texts are wrapped on multiple lines and `\r\n` starts a new line.
+
+        Panics if the control is not a window-like control.
+    */
+    #[cfg(feature = "tooltip")]
+    pub fn set_tooltip(&self, text: Option<&str>) {
+        use crate::win32::shared_tooltip::set_tooltip;
+
+        let hwnd = self.hwnd().expect("Cannot set the tooltip of a control that is not window-like");
+        unsafe { set_tooltip(hwnd, text); }
+    }
+
+    /**
+        Returns the text set with `set_tooltip`, or `None` if the control has no tooltip.
+
+        Panics if the control is not a window-like control.
+    */
+    #[cfg(feature = "tooltip")]
+    pub fn tooltip(&self) -> Option<String> {
+        use crate::win32::shared_tooltip::tooltip;
+
+        let hwnd = self.hwnd().expect("Cannot get the tooltip of a control that is not window-like");
+        tooltip(hwnd)
+    }
+
+    /**
+        Returns a builder that applies many property changes to the control at once. See `ControlUpdate`.
+        Not to be confused with `update`, which repaints the invalid region of the control.
+
+        ```rust
+        use native_windows_gui as nwg;
+        fn move_button(button: &nwg::Button) {
+            button.handle.begin_update()
+                .position((10, 10))
+                .size((120, 30))
+                .enabled(true)
+                .apply();
+        }
+        ```
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn begin_update(&self) -> ControlUpdate {
+        let hwnd = self.hwnd().expect("Cannot update a control that is not window-like");
+        ControlUpdate {
+            hwnd,
+            position: None,
+            size: None,
+            enabled: None,
+            visible: None,
+        }
+    }
+
+    /**
+        Moves the composition window of the input method editor (IME) to `(x, y)`, in logical pixels relative to the client area of the control.
+        Custom drawn text areas call this when the caret moves (usually in `OnImeStartComposition` and `OnImeComposition`)
+        so the composition and candidate windows appear next to the caret. Edit controls place them automatically.
+
+        Returns `false` if the control has no input context (ex: the IME is disabled).
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn set_ime_position(&self, x: i32, y: i32) -> bool {
+        use crate::win32::{ime, high_dpi::logical_to_physical};
+
+        let hwnd = self.hwnd().expect("Cannot set the IME position of a control that is not window-like");
+        unsafe {
+            let (x, y) = logical_to_physical(x, y);
+            ime::set_ime_position(hwnd, x, y)
+        }
+    }
+
+    /**
+        Posts a `WM_COMMAND` notification with the code `cmd` (ex: `BN_CLICKED`, `EN_CHANGE`, `CBN_SELCHANGE`) to the parent of the control,
+        as if the control had sent it. The events mapped to the notification (ex: `OnButtonClick`) are raised through the normal events path.
+
+        The message is posted: the events are raised the next time the events loop dispatches messages, never inside this call.
+        The state of the control is not changed. To click a button like a user would, use `Button::click`.
+
+        Returns `false` if the control has no parent or if the message could not be posted.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn post_command(&self, cmd: u16) -> bool {
+        use winapi::um::winuser::{GetParent, GetDlgCtrlID, PostMessageW, WM_COMMAND};
+        use winapi::shared::minwindef::MAKELONG;
+
+        let hwnd = self.hwnd().expect("Cannot post a command from a control that is not window-like");
+        unsafe {
+            let parent = GetParent(hwnd);
+            if parent.is_null() {
+                return false;
+            }
+
+            let id = GetDlgCtrlID(hwnd) as u16;
+            PostMessageW(parent, WM_COMMAND, MAKELONG(id, cmd) as u32 as _, hwnd as _) != 0
+        }
+    }
+
+    /**
+        Returns the bounds of the control (including the borders and the non client area) as `[left, top, right, bottom]`,
+        in screen coordinates (`GetWindowRect`). The values are in physical pixels and can be negative on a secondary monitor.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn rect_screen(&self) -> [i32; 4] {
+        use winapi::um::winuser::GetWindowRect;
+        use winapi::shared::windef::RECT;
+
+        let hwnd = self.hwnd().expect("Cannot get the rect of a control that is not window-like");
+        let mut r = RECT { left: 0, top: 0, right: 0, bottom: 0 };
+        unsafe { GetWindowRect(hwnd, &mut r); }
+
+        [r.left, r.top, r.right, r.bottom]
+    }
+
+    /**
+        Returns the bounds of the control (including the borders and the non client area) as `[left, top, right, bottom]`,
+        relative to the client area of its parent. This is the space used by `set_position`, but in physical pixels.
+        For a top level window, this is the same as `rect_screen`.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn rect_parent(&self) -> [i32; 4] {
+        use winapi::um::winuser::{GetParent, MapWindowPoints, WS_CHILD};
+        use winapi::shared::windef::POINT;
+
+        let hwnd = self.hwnd().expect("Cannot get the rect of a control that is not window-like");
+        let [left, top, right, bottom] = self.rect_screen();
+        let mut points = [POINT { x: left, y: top }, POINT { x: right, y: bottom }];
+
+        unsafe {
+            let parent = GetParent(hwnd);
+            if !parent.is_null() && wh::get_style(hwnd) & WS_CHILD != 0 {
+                MapWindowPoints(::std::ptr::null_mut(), parent, points.as_mut_ptr(), 2);
+            }
+        }
+
+        [points[0].x, points[0].y, points[1].x, points[1].y]
+    }
+
+    /**
+        Returns the client area of the control as `[left, top, right, bottom]`, in client coordinates (`GetClientRect`).
+        `left` and `top` are always 0. The values are in physical pixels (the same space as `PaintData::update_rect` and `invalidate_rect`).
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn rect_client(&self) -> [i32; 4] {
+        use winapi::um::winuser::GetClientRect;
+        use winapi::shared::windef::RECT;
+
+        let hwnd = self.hwnd().expect("Cannot get the rect of a control that is not window-like");
+        let mut r = RECT { left: 0, top: 0, right: 0, bottom: 0 };
+        unsafe { GetClientRect(hwnd, &mut r); }
+
+        [r.left, r.top, r.right, r.bottom]
+    }
+
+    /**
+        Returns the window style (`WS_*` and the control specific styles) of the control.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn style(&self) -> u32 {
+        let hwnd = self.hwnd().expect("Cannot get the style of a control that is not window-like");
+        wh::get_style(hwnd)
+    }
+
+    /**
+        Returns the extended window style (`WS_EX_*`) of the control.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn ex_style(&self) -> u32 {
+        let hwnd = self.hwnd().expect("Cannot get the extended style of a control that is not window-like");
+        wh::get_ex_style(hwnd)
+    }
+
+    /**
+        Adds the `add` bits and removes the `remove` bits from the window style of the control, then
+        applies the change immediately (`SetWindowPos` with `SWP_FRAMECHANGED`). Returns the previous style
+        so it can be restored later. `remove` is applied before `add`.
+
+        Not every style can be changed after the control is created. The control usually ignores the change or behaves erratically. Ex:
+          * `WS_CHILD` and `WS_POPUP`
+          * `ES_MULTILINE` on a text input, `CBS_DROPDOWNLIST` on a combobox
+          * `LBS_MULTICOLUMN`, `LBS_OWNERDRAWFIXED` on a listbox and `CBS_OWNERDRAWFIXED` on a combobox
+          * The button type (`BS_PUSHBUTTON`, `BS_CHECKBOX`, etc). Use `BM_SETSTYLE` instead.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn modify_style(&self, add: u32, remove: u32) -> u32 {
+        use winapi::um::winuser::GWL_STYLE;
+
+        let hwnd = self.hwnd().expect("Cannot set the style of a control that is not window-like");
+        unsafe { wh::modify_window_style(hwnd, GWL_STYLE, add, remove) }
+    }
+
+    /**
+        Adds the `add` bits and removes the `remove` bits from the extended window style of the control, then
+        applies the change immediately (`SetWindowPos` with `SWP_FRAMECHANGED`). Returns the previous extended style.
+        `remove` is applied before `add`.
+
+        `WS_EX_TOPMOST` cannot be changed this way, it requires `SetWindowPos` with `HWND_TOPMOST`/`HWND_NOTOPMOST`.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn modify_ex_style(&self, add: u32, remove: u32) -> u32 {
+        use winapi::um::winuser::GWL_EXSTYLE;
+
+        let hwnd = self.hwnd().expect("Cannot set the extended style of a control that is not window-like");
+        unsafe { wh::modify_window_style(hwnd, GWL_EXSTYLE, add, remove) }
+    }
+
+    /**
+        Returns the DPI of the control (96 is 100% scaling). With a per-monitor DPI aware process, this is the DPI of the monitor
+        displaying the top level window of the control, so it can differ between windows. Otherwise it is the system DPI.
+
+        This uses `GetDpiForWindow` on Windows 10 (1607) and later. Older systems do not have per-window DPI and
+        the DPI of the screen (`GetDeviceCaps(LOGPIXELSX)`) is returned instead.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn dpi(&self) -> u32 {
+        use crate::win32::high_dpi::window_dpi;
+
+        let hwnd = self.hwnd().expect("Cannot get the DPI of a control that is not window-like");
+        unsafe { window_dpi(hwnd) }
+    }
+
+    /**
+        Sets the right-to-left layout of the control (`WS_EX_LAYOUTRTL` and `WS_EX_RTLREADING`) for the languages written
+        from right to left, like Arabic and Hebrew. Use `nwg::system::is_rtl_locale` to detect them.
+
+        With a mirrored layout, the origin of the client area is the upper right corner and the x axis goes to the left:
+          * On a window or a frame, the children are positioned from the right edge. The children created after this call
+            (and the ones moved after it) are mirrored automatically, including the ones placed by the layouts of NWG, so the layouts
+            do not need to be flipped. Call `fit` on the layouts (or move the existing children again) after toggling the layout.
+          * The caption of a top level window is mirrored too (the title and the caption buttons swap sides).
+          * Text controls align and read their text from right to left.
+
+        The control is redrawn with its children after the change. Not every common control supports being mirrored after its creation.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn set_rtl(&self, rtl: bool) {
+        use winapi::um::winuser::{WS_EX_LAYOUTRTL, WS_EX_RTLREADING, RedrawWindow, RDW_ERASE, RDW_FRAME, RDW_INVALIDATE, RDW_ALLCHILDREN};
+
+        let hwnd = self.hwnd().expect("Cannot set the layout of a control that is not window-like");
+        let styles = WS_EX_LAYOUTRTL | WS_EX_RTLREADING;
+        let (add, remove) = match rtl {
+            true => (styles, 0),
+            false => (0, styles)
+        };
+
+        self.modify_ex_style(add, remove);
+
+        unsafe {
+            RedrawWindow(hwnd, ::std::ptr::null(), ::std::ptr::null_mut(), RDW_ERASE | RDW_FRAME | RDW_INVALIDATE | RDW_ALLCHILDREN);
+        }
+    }
+
+    /**
+        Returns `true` if the control has a right-to-left layout. See `set_rtl`.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn rtl(&self) -> bool {
+        use winapi::um::winuser::WS_EX_LAYOUTRTL;
+        self.ex_style() & WS_EX_LAYOUTRTL == WS_EX_LAYOUTRTL
+    }
+
+    /**
+        Returns the mnemonic of the control: the letter after the first single "&" of its text, in lower case.
+        "&&" is an ampersand and is not a mnemonic.
+
+        The mnemonics are handled by `IsDialogMessage` in the NWG events loop (`dispatch_thread_events`). When the user presses
+        Alt and the letter (or only the letter if the focused control does not want characters):
+          * A button (`Button`, `CheckBox`, `RadioButton`) is clicked and raises `OnButtonClick`
+          * A label moves the focus to the next control in the tab order. The label must not have the `LabelFlags::NO_PREFIX` flag
+          * Any other control receives the focus
+
+        The letter is underlined when the keyboard cues are visible, see `set_focus_visuals`.
+        Returns `None` if the text has no mnemonic.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn mnemonic(&self) -> Option<char> {
+        let hwnd = self.hwnd().expect("Cannot get the mnemonic of a control that is not window-like");
+        let text = unsafe { crate::win32::window_helper::get_window_text(hwnd) };
+
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c == '&' {
+                match chars.next() {
+                    Some('&') => {},
+                    Some(letter) => { return letter.to_lowercase().next(); },
+                    None => { return None; }
+                }
+            }
+        }
+
+        None
+    }
+
+    /**
+        Scrolls the nearest scrollable ancestor of the control so that the control is entirely visible.
+        Use it to reveal a control of a long form (ex: the control that failed the validation, or the control that received the focus).
+
+        The scrollable ancestor is the first parent, grand-parent, etc. (the top level window included) with a vertical
+        or a horizontal scroll bar (`WS_VSCROLL` or `WS_HSCROLL`). Only the axes that have a scroll bar are scrolled.
+        The scroll position is expected to be in pixels and the content to be moved with its children (`ScrollWindowEx` with
+        `SW_SCROLLCHILDREN`), which is the usual model of a scrollable panel. The scroll bar is updated and the children of
+        the ancestor are moved by the distance scrolled. The new position is clamped to the range of the scroll bar.
+
+        The control is scrolled by the smallest distance that makes it visible. If it is larger than the visible area, its top (or left)
+        edge is aligned with the top (or left) edge of the visible area.
+
+        Returns `true` if the ancestor was scrolled. Returns `false` if the control is already visible or if there is no scrollable ancestor.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn scroll_into_view(&self) -> bool {
+        use winapi::um::winuser::{GetParent, GetClientRect, MapWindowPoints, GetScrollInfo, SetScrollInfo, ScrollWindowEx,
+            SCROLLINFO, SIF_POS, SB_HORZ, SB_VERT, WS_VSCROLL, WS_HSCROLL, WS_CHILD, SW_SCROLLCHILDREN, SW_INVALIDATE, SW_ERASE};
+        use winapi::shared::windef::{RECT, POINT};
+        use std::{mem, ptr};
+
+        let hwnd = self.hwnd().expect("Cannot scroll to a control that is not window-like");
+
+        unsafe {
+            // The nearest ancestor with a scroll bar
+            let mut child = hwnd;
+            let mut ancestor = ptr::null_mut();
+            while wh::get_style(child) & WS_CHILD != 0 {
+                let parent = GetParent(child);
+                if parent.is_null() {
+                    break;
+                }
+
+                if wh::get_style(parent) & (WS_VSCROLL | WS_HSCROLL) != 0 {
+                    ancestor = parent;
+                    break;
+                }
+
+                child = parent;
+            }
+
+            if ancestor.is_null() {
+                return false;
+            }
+
+            let [left, top, right, bottom] = self.rect_screen();
+            let mut points = [POINT { x: left, y: top }, POINT { x: right, y: bottom }];
+            MapWindowPoints(ptr::null_mut(), ancestor, points.as_mut_ptr(), 2);
+
+            let mut client = RECT { left: 0, top: 0, right: 0, bottom: 0 };
+            GetClientRect(ancestor, &mut client);
+
+            // Distance to scroll on one axis: the smallest one, or the one that aligns the start if the control is larger than the view
+            let distance = |start: i32, end: i32, view: i32| {
+                if start < 0 || end - start > view { start }
+                else if end > view { end - view }
+                else { 0 }
+            };
+
+            let style = wh::get_style(ancestor);
+            let scroll = |bar: i32, style_bit: u32, delta: i32| -> i32 {
+                if style & style_bit == 0 || delta == 0 {
+                    return 0;
+                }
+
+                let mut si: SCROLLINFO = mem::zeroed();
+                si.cbSize = mem::size_of::<SCROLLINFO>() as u32;
+                si.fMask = SIF_POS;
+                GetScrollInfo(ancestor, bar, &mut si);
+                let old = si.nPos;
+
+                // The system clamps the position to the range
+                si.nPos = old + delta;
+                SetScrollInfo(ancestor, bar, &si, 1);
+                GetScrollInfo(ancestor, bar, &mut si);
+
+                si.nPos - old
+            };
+
+            let dx = scroll(SB_HORZ as i32, WS_HSCROLL, distance(points[0].x, points[1].x, client.right));
+            let dy = scroll(SB_VERT as i32, WS_VSCROLL, distance(points[0].y, points[1].y, client.bottom));
+            if dx == 0 && dy == 0 {
+                return false;
+            }
+
+            ScrollWindowEx(ancestor, -dx, -dy, ptr::null(), ptr::null(), ptr::null_mut(), ptr::null_mut(), SW_SCROLLCHILDREN | SW_INVALIDATE | SW_ERASE);
+
+            true
+        }
+    }
+
 }
 
 
@@ -165,3 +929,116 @@ impl Eq for ControlHandle {}
 impl From<&ControlHandle> for ControlHandle {
     fn from(control: &ControlHandle) -> Self { *control }
 }
+
+
+/**
+    Applies many property changes to a control in a single pass. Created with `ControlHandle::begin_update`.
+
+    The position, the size and the visibility are applied with a single `SetWindowPos` call and the control is repainted once,
+    instead of once per setter. Only the properties that were set are changed, the other properties are left unchanged.
+    If no property was set, `apply` does nothing.
+
+    The position and the size are in logical pixels, like `set_position` and `set_size`. Like `Window::set_size`,
+    the size of a top level window is the size of its client area.
+*/
+pub struct ControlUpdate {
+    hwnd: HWND,
+    position: Option<(i32, i32)>,
+    size: Option<(u32, u32)>,
+    enabled: Option<bool>,
+    visible: Option<bool>,
+}
+
+impl ControlUpdate {
+
+    pub fn position(mut self, position: (i32, i32)) -> ControlUpdate {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn size(mut self, size: (u32, u32)) -> ControlUpdate {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> ControlUpdate {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    pub fn visible(mut self, visible: bool) -> ControlUpdate {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Applies the changes to the control
+    pub fn apply(self) {
+        use winapi::um::winuser::{SetWindowPos, AdjustWindowRectEx, InvalidateRect, GWL_STYLE, WS_CHILD, WS_DISABLED};
+        use winapi::um::winuser::{SWP_NOZORDER, SWP_NOMOVE, SWP_NOSIZE, SWP_NOACTIVATE, SWP_NOOWNERZORDER, SWP_SHOWWINDOW, SWP_HIDEWINDOW};
+        use winapi::shared::windef::RECT;
+        use crate::win32::high_dpi::logical_to_physical;
+        use std::ptr;
+
+        let hwnd = self.hwnd;
+
+        unsafe {
+            let mut enabled_changed = false;
+            if let Some(enabled) = self.enabled {
+                let style = wh::get_style(hwnd);
+                let new_style = match enabled {
+                    true => style & !WS_DISABLED,
+                    false => style | WS_DISABLED
+                };
+
+                if new_style != style {
+                    wh::set_window_long(hwnd, GWL_STYLE, new_style as usize);
+                    enabled_changed = true;
+                }
+            }
+
+            let mut flags = SWP_NOZORDER | SWP_NOACTIVATE | SWP_NOOWNERZORDER;
+            let (mut x, mut y, mut w, mut h) = (0, 0, 0, 0);
+
+            match self.position {
+                Some((px, py)) => {
+                    let (px, py) = logical_to_physical(px, py);
+                    x = px;
+                    y = py;
+                },
+                None => { flags |= SWP_NOMOVE; }
+            }
+
+            match self.size {
+                Some((sw, sh)) => {
+                    let (sw, sh) = logical_to_physical(sw as i32, sh as i32);
+                    w = sw;
+                    h = sh;
+
+                    let style = wh::get_style(hwnd);
+                    if style & WS_CHILD == 0 {
+                        let mut rect = RECT { left: 0, top: 0, right: w, bottom: h };
+                        AdjustWindowRectEx(&mut rect, style, 0, wh::get_ex_style(hwnd));
+                        w = rect.right - rect.left;
+                        h = rect.bottom - rect.top;
+                    }
+                },
+                None => { flags |= SWP_NOSIZE; }
+            }
+
+            match self.visible {
+                Some(true) => { flags |= SWP_SHOWWINDOW; },
+                Some(false) => { flags |= SWP_HIDEWINDOW; },
+                None => {}
+            }
+
+            if self.position.is_some() || self.size.is_some() || self.visible.is_some() {
+                SetWindowPos(hwnd, ptr::null_mut(), x, y, w, h, flags);
+            }
+
+            if enabled_changed {
+                InvalidateRect(hwnd, ptr::null(), 1);
+            }
+        }
+    }
+
+}
@@ -0,0 +1,297 @@
+use super::{ListView, ListViewBuilder, ListViewStyle, ListViewFlags, ListViewExFlags, ControlHandle};
+use crate::NwgError;
+use std::cell::{Ref, RefMut, RefCell};
+
+
+/**
+    A type that can be displayed as a row of a `DataListView`.
+    `columns` returns the text of each column of the row, starting with the first column.
+*/
+pub trait ListViewRow {
+    fn columns(&self) -> Vec<String>;
+}
+
+/**
+A report mode list view bound to a collection of `D`. This is the `ListBox` data-binding approach applied to the `ListView`:
+every row of the control maps to an item of the inner collection, so the selection can be mapped back to `D`.
+
+The inner list view is a regular `ListView` in the `Detailed` style. The columns are not managed by the collection,
+set them with the `columns` parameter of the builder or add them later with `list.insert_column`.
+
+Requires the `list-view` feature.
+
+Builder parameters:
+  * `parent`:           **Required.** The data list view parent container.
+  * `size`:             The data list view size.
+  * `position`:         The data list view position.
+  * `columns`:          The text of the columns. The headers are hidden if there are no columns.
+  * `collection`:       The default collection of the data list view.
+  * `background_color`: The data list view background color in RGB format
+  * `double_buffer`:    If the data list view should be double buffered (defaults to true)
+  * `text_color`:       The data list view text color in RGB format
+  * `flags`:            A combination of the ListViewFlags values.
+  * `ex_flags`:         A combination of the ListViewExFlags values. Not to be confused with `ex_window_flags` 
+  * `ex_window_flags`:  A combination of win32 window extended flags. This is the equivalent to `ex_flags` in the other controls
+  * `focus`:            The control receive focus after being created
+
+```rust
+use native_windows_gui as nwg;
+
+struct Product { name: String, price: f32 }
+
+impl nwg::ListViewRow for Product {
+    fn columns(&self) -> Vec<String> {
+        vec![self.name.clone(), format!("{:.2}", self.price)]
+    }
+}
+
+fn build_products(products: &mut nwg::DataListView<Product>, window: &nwg::Window) {
+    nwg::DataListView::builder()
+        .columns(&["Name", "Price"])
+        .collection(vec![Product { name: "Banana".to_string(), price: 10.0 }])
+        .parent(window)
+        .build(products)
+        .unwrap();
+}
+
+fn total_selected(products: &nwg::DataListView<Product>) -> f32 {
+    products.map_selection(|selected| selected.iter().map(|p| p.price).sum())
+}
+```
+*/
+pub struct DataListView<D: ListViewRow> {
+    pub list: ListView,
+    collection: RefCell<Vec<D>>,
+}
+
+impl<D: ListViewRow> DataListView<D> {
+
+    pub fn builder() -> DataListViewBuilder<D> {
+        DataListViewBuilder {
+            list: ListView::builder().list_style(ListViewStyle::Detailed),
+            columns: Vec::new(),
+            collection: None,
+        }
+    }
+
+    /// Adds a row at the end of the collection and the control
+    pub fn push(&self, item: D) {
+        let row = self.collection.borrow().len();
+        self.insert(row, item);
+    }
+
+    /// Inserts a row in the collection and the control.
+    /// Panics if `index > len`
+    pub fn insert(&self, index: usize, item: D) {
+        let columns = item.columns();
+        self.collection.borrow_mut().insert(index, item);
+        self.list.insert_items_row(Some(index as i32), &columns);
+    }
+
+    /// Replaces the row at `index` and returns the old value. Every column of the row is updated.
+    /// Panics if the index is out of bounds
+    pub fn update(&self, index: usize, item: D) -> D {
+        let columns = item.columns();
+        let old = ::std::mem::replace(&mut self.collection.borrow_mut()[index], item);
+
+        for (column, text) in columns.iter().enumerate() {
+            self.list.set_subitem(index, column, text);
+        }
+
+        old
+    }
+
+    /// Removes the row at `index` and returns it.
+    /// Panics if the index is out of bounds
+    pub fn remove(&self, index: usize) -> D {
+        let item = self.collection.borrow_mut().remove(index);
+        self.list.remove_item(index);
+        item
+    }
+
+    /// Removes every row of the collection and the control
+    pub fn clear(&self) {
+        self.collection.borrow_mut().clear();
+        self.list.clear();
+    }
+
+    /// Returns the number of rows in the collection
+    pub fn len(&self) -> usize {
+        self.collection.borrow().len()
+    }
+
+    /// Returns `true` if the collection has no rows
+    pub fn is_empty(&self) -> bool {
+        self.collection.borrow().is_empty()
+    }
+
+    /// Returns the indices of the selected rows
+    pub fn selected_rows(&self) -> Vec<usize> {
+        self.list.selected_items()
+    }
+
+    /// Returns the index of the first selected row
+    pub fn selection(&self) -> Option<usize> {
+        self.list.selected_item()
+    }
+
+    /// Calls `f` with the items of the selected rows and returns its result.
+    /// The inner collection is borrowed during the call.
+    pub fn map_selection<R, F: FnOnce(Vec<&D>) -> R>(&self, f: F) -> R {
+        let selected = self.selected_rows();
+        let col = self.collection.borrow();
+        let items = selected.iter().filter_map(|&i| col.get(i)).collect();
+        f(items)
+    }
+
+    /// Update the visual of the control with the inner collection.
+    /// This rebuild every row in the list view and can take some time on big collections.
+    pub fn sync(&self) {
+        self.list.set_redraw(false);
+        self.list.clear();
+        self.list.set_item_count(self.collection.borrow().len() as u32);
+
+        for item in self.collection.borrow().iter() {
+            self.list.insert_items_row(None, &item.columns());
+        }
+
+        self.list.set_redraw(true);
+    }
+
+    /// Sets the collection of the list view and rebuild the rows. Returns the old collection
+    pub fn set_collection(&self, col: Vec<D>) -> Vec<D> {
+        let old = self.collection.replace(col);
+        self.sync();
+        old
+    }
+
+    /// Get read-only access to the inner collection of the list view
+    /// This call refcell.borrow under the hood. Be sure to drop the value before
+    /// calling other list view methods
+    pub fn collection(&self) -> Ref<'_, Vec<D>> {
+        self.collection.borrow()
+    }
+
+    /// Get mutable access to the inner collection of the list view. Does not update the visual
+    /// control. Call `sync` to update the view. This call refcell.borrow_mut under the hood.
+    /// Be sure to drop the value before calling other list view methods
+    pub fn collection_mut(&self) -> RefMut<'_, Vec<D>> {
+        self.collection.borrow_mut()
+    }
+
+}
+
+/// The builder of a `DataListView`. See `DataListView` for the parameters.
+pub struct DataListViewBuilder<D: ListViewRow> {
+    list: ListViewBuilder,
+    columns: Vec<String>,
+    collection: Option<Vec<D>>,
+}
+
+impl<D: ListViewRow> DataListViewBuilder<D> {
+
+    pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> DataListViewBuilder<D> {
+        self.list = self.list.parent(p);
+        self
+    }
+
+    pub fn flags(mut self, flags: ListViewFlags) -> DataListViewBuilder<D> {
+        self.list = self.list.flags(flags);
+        self
+    }
+
+    pub fn ex_flags(mut self, flags: ListViewExFlags) -> DataListViewBuilder<D> {
+        self.list = self.list.ex_flags(flags);
+        self
+    }
+
+    pub fn ex_window_flags(mut self, flags: u32) -> DataListViewBuilder<D> {
+        self.list = self.list.ex_window_flags(flags);
+        self
+    }
+
+    pub fn size(mut self, size: (i32, i32)) -> DataListViewBuilder<D> {
+        self.list = self.list.size(size);
+        self
+    }
+
+    pub fn position(mut self, position: (i32, i32)) -> DataListViewBuilder<D> {
+        self.list = self.list.position(position);
+        self
+    }
+
+    pub fn double_buffer(mut self, buffer: bool) -> DataListViewBuilder<D> {
+        self.list = self.list.double_buffer(buffer);
+        self
+    }
+
+    pub fn background_color(mut self, color: [u8; 3]) -> DataListViewBuilder<D> {
+        self.list = self.list.background_color(color);
+        self
+    }
+
+    pub fn text_color(mut self, color: [u8; 3]) -> DataListViewBuilder<D> {
+        self.list = self.list.text_color(color);
+        self
+    }
+
+    pub fn focus(mut self, focus: bool) -> DataListViewBuilder<D> {
+        self.list = self.list.focus(focus);
+        self
+    }
+
+    pub fn columns(mut self, columns: &[&str]) -> DataListViewBuilder<D> {
+        self.columns = columns.iter().map(|c| c.to_string()).collect();
+        self
+    }
+
+    pub fn collection(mut self, collection: Vec<D>) -> DataListViewBuilder<D> {
+        self.collection = Some(collection);
+        self
+    }
+
+    pub fn build(self, out: &mut DataListView<D>) -> Result<(), NwgError> {
+        self.list.build(&mut out.list)?;
+
+        for column in self.columns.iter() {
+            out.list.insert_column(column.as_str());
+        }
+        out.list.set_headers_enabled(!self.columns.is_empty());
+
+        out.set_collection(self.collection.unwrap_or_default());
+
+        Ok(())
+    }
+
+}
+
+impl<D: ListViewRow> Default for DataListView<D> {
+    fn default() -> DataListView<D> {
+        DataListView {
+            list: Default::default(),
+            collection: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl<D: ListViewRow> PartialEq for DataListView<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.list.handle == other.list.handle
+    }
+}
+
+impl<D: ListViewRow> From<&DataListView<D>> for ControlHandle {
+    fn from(control: &DataListView<D>) -> Self { control.list.handle }
+}
+
+impl<D: ListViewRow> PartialEq<ControlHandle> for DataListView<D> {
+    fn eq(&self, other: &ControlHandle) -> bool {
+        self.list.handle == *other
+    }
+}
+
+impl<D: ListViewRow> PartialEq<DataListView<D>> for ControlHandle {
+    fn eq(&self, other: &DataListView<D>) -> bool {
+        *self == other.list.handle
+    }
+}
@@ -42,6 +42,14 @@ Requires the `frame` feature.
   * `MousePress(_)`: Generic mouse press events on the button
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
+
+**Keyboard navigation:**
+A frame is a control parent (`WS_EX_CONTROLPARENT`): the dialog manager walks into it, so Tab and Shift+Tab move the focus
+through the children of the frame as if they were the children of the window. A frame also closes the radio button group
+of its children, like a `GroupBox` in WinForms: put each group of radio buttons in its own frame and the arrow keys stay in the frame.
+See `RadioButton` for the groups.
+
+With `set_control_parent(false)`, Tab skips the children of the frame.
 */
 #[derive(Default, PartialEq, Eq)]
 pub struct Frame {
@@ -73,6 +81,80 @@ impl Frame {
         unsafe { wh::set_focus(handle); }
     }
 
+    /// Moves the keyboard focus to the next child of the frame that accepts the focus (`WS_TABSTOP`).
+    /// Disabled and hidden children are skipped. The order is the creation order of the children and it wraps around at the end.
+    /// If no child of the frame has the focus, the first one receives it.
+    ///
+    /// This can be used to implement keyboard navigation in windows that do not rely on the dialog manager.
+    pub fn focus_next(&self) {
+        self.move_focus(false);
+    }
+
+    /// Moves the keyboard focus to the previous child of the frame that accepts the focus. See `focus_next`.
+    /// If no child of the frame has the focus, the last one receives it.
+    pub fn focus_prev(&self) {
+        self.move_focus(true);
+    }
+
+    fn move_focus(&self, backward: bool) {
+        use winapi::um::winuser::{GetWindow, GetFocus, GetParent, IsChild, IsWindowVisible, IsWindowEnabled, SetFocus, GW_CHILD, GW_HWNDNEXT, WS_TABSTOP};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        unsafe {
+            let mut children = Vec::new();
+            let mut child = GetWindow(handle, GW_CHILD);
+            while !child.is_null() {
+                if wh::get_style(child) & WS_TABSTOP != 0 && IsWindowVisible(child) != 0 && IsWindowEnabled(child) != 0 {
+                    children.push(child);
+                }
+                child = GetWindow(child, GW_HWNDNEXT);
+            }
+
+            if children.is_empty() {
+                return;
+            }
+
+            // The focus can be on a descendant of a child (ex: the edit control of a combobox)
+            let mut focus = GetFocus();
+            if IsChild(handle, focus) != 0 {
+                while GetParent(focus) != handle {
+                    focus = GetParent(focus);
+                }
+            }
+
+            let count = children.len();
+            let next = match (children.iter().position(|&c| c == focus), backward) {
+                (Some(i), false) => (i + 1) % count,
+                (Some(i), true) => (i + count - 1) % count,
+                (None, false) => 0,
+                (None, true) => count - 1,
+            };
+
+            SetFocus(children[next]);
+        }
+    }
+
+    /// Returns true if the keyboard navigation of the dialog manager walks into the children of the frame. See `Frame`.
+    pub fn control_parent(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_ex_style(handle) & WS_EX_CONTROLPARENT != 0
+    }
+
+    /// Sets if the keyboard navigation of the dialog manager walks into the children of the frame (`WS_EX_CONTROLPARENT`).
+    /// Frames are created as control parents.
+    pub fn set_control_parent(&self, v: bool) {
+        use winapi::um::winuser::GWL_EXSTYLE;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let (add, remove) = match v {
+            true => (WS_EX_CONTROLPARENT, 0),
+            false => (0, WS_EX_CONTROLPARENT)
+        };
+
+        unsafe { wh::modify_window_style(handle, GWL_EXSTYLE, add, remove); }
+    }
+
     /// Returns true if the control user can interact with the control, return false otherwise
     pub fn enabled(&self) -> bool {
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
@@ -1,4 +1,5 @@
 use super::{ControlHandle, Window, Button, CheckBox, RadioButton, TextInput, Label, ImageFrame};
+use winapi::shared::windef::HWND;
 use std::convert::From;
 
 #[allow(unused)]
@@ -6,6 +7,15 @@ use std::fmt::Display;
 
 macro_rules! handles {
     ($control:ty) => {
+        #[allow(deprecated)]
+        impl $control {
+            /**
+                Returns the raw window handle of the control, or `None` if the control is not window-like (ex: a menu) or not built.
+                The handle is owned by the control: do not destroy it, and do not use it after the control is dropped.
+            */
+            pub fn hwnd(&self) -> Option<HWND> { self.handle.hwnd() }
+        }
+
         #[allow(deprecated)]
         impl From<&$control> for ControlHandle {
             fn from(control: &$control) -> Self { control.handle }
@@ -32,6 +42,20 @@ macro_rules! handles {
     };
 }
 
+/// Forwards `set_tooltip` and `tooltip` to the `ControlHandle` of the controls that can be hovered. See `ControlHandle::set_tooltip`
+#[cfg(feature = "tooltip")]
+macro_rules! tooltips {
+    ($control:ty) => {
+        impl $control {
+            /// Sets the text of the tooltip of the control. `None` removes the tooltip. See `ControlHandle::set_tooltip`
+            pub fn set_tooltip(&self, text: Option<&str>) { self.handle.set_tooltip(text) }
+
+            /// Returns the text of the tooltip set with `set_tooltip`
+            pub fn tooltip(&self) -> Option<String> { self.handle.tooltip() }
+        }
+    };
+}
+
 /**
 Automatically implements the functionnalities required to process an external struct as a NWG control
 
@@ -121,6 +145,12 @@ handles!(MenuSeparator);
 #[cfg(feature = "combobox")]
 use super::ComboBox;
 
+#[cfg(feature = "combobox")]
+impl<D: Display+Default> ComboBox<D> {
+    /// Returns the raw window handle of the control, or `None` if the control is not built. See `Window::hwnd`.
+    pub fn hwnd(&self) -> Option<HWND> { self.handle.hwnd() }
+}
+
 #[cfg(feature = "combobox")]
 impl<D: Display+Default> From<&ComboBox<D>> for ControlHandle {
     fn from(control: &ComboBox<D>) -> Self { control.handle }
@@ -143,6 +173,12 @@ impl<D: Display+Default> PartialEq<ComboBox<D>> for ControlHandle {
 #[cfg(feature = "listbox")]
 use super::ListBox;
 
+#[cfg(feature = "listbox")]
+impl<D: Display+Default> ListBox<D> {
+    /// Returns the raw window handle of the control, or `None` if the control is not built. See `Window::hwnd`.
+    pub fn hwnd(&self) -> Option<HWND> { self.handle.hwnd() }
+}
+
 #[cfg(feature = "listbox")]
 impl<D: Display+Default> From<&ListBox<D>> for ControlHandle {
     fn from(control: &ListBox<D>) -> Self { control.handle }
@@ -241,6 +277,13 @@ use super::Frame;
 handles!(Frame);
 
 
+#[cfg(feature = "splitter")]
+use super::Splitter;
+
+#[cfg(feature = "splitter")]
+handles!(Splitter);
+
+
 #[cfg(feature = "rich-textbox")]
 use super::RichTextBox;
 
@@ -270,3 +313,57 @@ use super::Plotters;
 
 #[cfg(feature = "plotting")]
 handles!(Plotters);
+
+
+#[cfg(feature = "tooltip")]
+tooltips!(Button);
+#[cfg(feature = "tooltip")]
+tooltips!(ImageFrame);
+#[cfg(feature = "tooltip")]
+tooltips!(Label);
+#[cfg(feature = "tooltip")]
+tooltips!(CheckBox);
+#[cfg(feature = "tooltip")]
+tooltips!(RadioButton);
+#[cfg(feature = "tooltip")]
+tooltips!(TextInput);
+#[cfg(all(feature = "tooltip", feature = "textbox"))]
+tooltips!(TextBox);
+#[cfg(all(feature = "tooltip", feature = "trackbar"))]
+tooltips!(TrackBar);
+#[cfg(all(feature = "tooltip", feature = "datetime-picker"))]
+tooltips!(DatePicker);
+#[cfg(all(feature = "tooltip", feature = "progress-bar"))]
+tooltips!(ProgressBar);
+#[cfg(all(feature = "tooltip", feature = "tree-view"))]
+tooltips!(TreeView);
+#[cfg(all(feature = "tooltip", feature = "list-view"))]
+tooltips!(ListView);
+#[cfg(all(feature = "tooltip", feature = "frame"))]
+tooltips!(Frame);
+#[cfg(all(feature = "tooltip", feature = "rich-textbox"))]
+tooltips!(RichTextBox);
+#[cfg(all(feature = "tooltip", feature = "rich-textbox"))]
+tooltips!(RichLabel);
+#[cfg(all(feature = "tooltip", feature = "scroll-bar"))]
+tooltips!(ScrollBar);
+#[cfg(all(feature = "tooltip", feature = "number-select"))]
+tooltips!(NumberSelect);
+
+#[cfg(all(feature = "tooltip", feature = "combobox"))]
+impl<D: Display+Default> ComboBox<D> {
+    /// Sets the text of the tooltip of the control. `None` removes the tooltip. See `ControlHandle::set_tooltip`
+    pub fn set_tooltip(&self, text: Option<&str>) { self.handle.set_tooltip(text) }
+
+    /// Returns the text of the tooltip set with `set_tooltip`
+    pub fn tooltip(&self) -> Option<String> { self.handle.tooltip() }
+}
+
+#[cfg(all(feature = "tooltip", feature = "listbox"))]
+impl<D: Display+Default> ListBox<D> {
+    /// Sets the text of the tooltip of the control. `None` removes the tooltip. See `ControlHandle::set_tooltip`
+    pub fn set_tooltip(&self, text: Option<&str>) { self.handle.set_tooltip(text) }
+
+    /// Returns the text of the tooltip set with `set_tooltip`
+    pub fn tooltip(&self) -> Option<String> { self.handle.tooltip() }
+}
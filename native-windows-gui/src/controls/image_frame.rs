@@ -112,6 +112,7 @@ impl ImageFrame {
         use winapi::shared::minwindef::WPARAM;
         use winapi::shared::windef::HBITMAP;
         use winapi::um::winnt::HANDLE;
+        use std::ptr;
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
         let bitmap_handle = wh::send_message(handle, STM_GETIMAGE, IMAGE_BITMAP as WPARAM, 0);
@@ -121,7 +122,7 @@ impl ImageFrame {
         *icon = None;
 
         if bitmap_handle != 0 && rh::is_bitmap(bitmap_handle as HBITMAP) {
-            *bitmap = Some(Bitmap { handle: bitmap_handle as HANDLE, owned: false });
+            *bitmap = Some(Bitmap { handle: bitmap_handle as HANDLE, owned: false, dc: ptr::null_mut() });
         } else if icon_handle != 0 {
             *icon = Some(Icon { handle: icon_handle as HANDLE, owned: false });
         }
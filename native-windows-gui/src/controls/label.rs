@@ -1,5 +1,5 @@
 use winapi::um::{
-    winuser::{WS_VISIBLE, WS_DISABLED, SS_WORDELLIPSIS},
+    winuser::{WS_VISIBLE, WS_DISABLED, SS_WORDELLIPSIS, SS_NOPREFIX},
     wingdi::DeleteObject
 };
 
@@ -20,6 +20,7 @@ bitflags! {
         * NONE:     No flags. Equivalent to a invisible blank label.
         * VISIBLE:  The label is immediatly visible after creation
         * DISABLED: The label cannot be interacted with by the user. It also has a grayed out look.
+        * NO_PREFIX: The "&" characters of the text are displayed instead of underlining the next letter. Part of the default flags.
     */
     pub struct LabelFlags: u32 {
         const NONE = 0;
@@ -28,6 +29,8 @@ bitflags! {
 
         /// Truncate the label if the text is too long. A label with this style CANNOT have multiple lines.
         const ELIPSIS = SS_WORDELLIPSIS;
+
+        const NO_PREFIX = SS_NOPREFIX;
     }
 }
 
@@ -55,6 +58,12 @@ Label is not behind any features.
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
 
+**Mnemonics:**
+A label built without `LabelFlags::NO_PREFIX` underlines the letter after "&" (use "&&" for an ampersand).
+Pressing Alt and this letter moves the focus to the next control in the tab order, which is the creation order.
+Create the label just before the control it describes, for example "&Name:" before the text input of the name.
+See `ControlHandle::mnemonic`.
+
 
 ** Example **
 
@@ -186,6 +195,13 @@ impl Label {
         unsafe { wh::set_window_text(handle, v) }
     }
 
+    /// Resize the label so that its text fits, using the label font. Multiline text is supported.
+    /// `padding` is added on each side, in logical pixels.
+    pub fn fit_to_content(&self, padding: u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::fit_window_to_text(handle, (0, 0), padding) }
+    }
+
     /// Winapi class name used during control creation
     pub fn class_name(&self) -> &'static str {
         "STATIC"
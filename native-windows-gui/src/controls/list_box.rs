@@ -1,18 +1,23 @@
 use winapi::shared::windef::HWND;
-use winapi::shared::minwindef::{WPARAM, LPARAM};
-use winapi::um::winuser::{LBS_MULTIPLESEL, LBS_NOSEL, WS_VISIBLE, WS_DISABLED, WS_TABSTOP};
+use winapi::shared::minwindef::{WPARAM, LPARAM, LRESULT};
+use winapi::um::winuser::{LBS_MULTIPLESEL, LBS_NOSEL, LBS_MULTICOLUMN, LBS_OWNERDRAWFIXED, LBS_NODATA, LB_GETITEMDATA, LB_SETITEMDATA, WS_VISIBLE, WS_DISABLED, WS_TABSTOP, WS_HSCROLL};
 use crate::win32::window_helper as wh;
 use crate::win32::base_helper::{to_utf16, from_utf16, check_hwnd};
-use crate::{Font, NwgError};
+use crate::{Font, NwgError, RawEventHandler};
 use super::{ControlBase, ControlHandle};
 use std::cell::{Ref, RefMut, RefCell};
 use std::fmt::Display;
+use std::rc::Rc;
+use std::cmp::Ordering;
 use std::ops::Range;
-use std::mem;
+use std::{mem, ptr};
 
 const NOT_BOUND: &'static str = "ListBox is not yet bound to a winapi object";
 const BAD_HANDLE: &'static str = "INTERNAL ERROR: ListBox handle is not HWND!";
 
+/// Maps the text of an inline edit to a new item. See `ListBox::enable_inline_edit`
+type InlineEditCommit<D> = Rc<dyn Fn(usize, &str) -> Option<D>>;
+
 
 bitflags! {
     /**
@@ -24,6 +29,8 @@ bitflags! {
         * MULTI_SELECT: It is possible for the user to select more than 1 item at a time
         * NO_SELECT: It is impossible for the user to select the listbox items
         * TAB_STOP: The control can be selected using tab navigation
+        * MULTI_COLUMN: Items flow into multiple columns that scroll horizontally. Vertical scrolling is disabled in this mode.
+        * VIRTUAL: The listbox does not store its items. See the **Virtual list box** section of `ListBox`
     */
     pub struct ListBoxFlags: u32 {
         const NONE = 0;
@@ -32,6 +39,8 @@ bitflags! {
         const MULTI_SELECT = LBS_MULTIPLESEL;
         const NO_SELECT = LBS_NOSEL;
         const TAB_STOP = WS_TABSTOP;
+        const MULTI_COLUMN = LBS_MULTICOLUMN | WS_HSCROLL;
+        const VIRTUAL = LBS_OWNERDRAWFIXED | LBS_NODATA;
     }
 }
 
@@ -52,10 +61,26 @@ Requires the `list-box` feature.
   * `collection`:      The default collections of the listbox
   * `selected_index`:  The default selected index in the listbox collection
   * `multi_selection`: The collections of indices to set as selected in a multi selection listbox 
+  * `column_width`:    The width of the columns of a `MULTI_COLUMN` listbox
+  * `item_count`:      The number of items of a `VIRTUAL` listbox
+
+**Virtual list box:**
+
+With the `VIRTUAL` flag, the listbox does not store any item. It only knows the number of items (`set_item_count`)
+and raises `OnListBoxGetText` each time a visible row must be painted. The handler gives the text of the row with `EventData::OnListBoxGetText`.
+This keeps the memory usage and the creation time constant for lists with hundreds of thousands of rows.
+
+Trade-offs:
+  * The rust `collection` is not used. `push`, `insert`, `remove`, `set_collection`, `sync` and `sort` must not be used on a virtual listbox.
+  * The text is not known by the control, so `selection_string`, `set_selection_string` and the keyboard search do not work.
+    The selection works by index only (`selection`, `multi_selection`, `set_selection`, ...).
+  * The text is requested again every time a row is repainted, so the handler should be fast.
 
 **Control events:**
-  * `OnListBoxSelect`: When the current listbox selection is changed
+  * `OnListBoxSelect`: When the current listbox selection is changed. The old and new selection are in `EventData::OnListBoxSelect`
   * `OnListBoxDoubleClick`: When a listbox item is clicked twice rapidly
+  * `OnListBoxFocus`: When the listbox has acquired the input focus
+  * `OnListBoxFocusLost`: When the listbox has lost the input focus (ex: to commit an in-place editor)
   * `MousePress(_)`: Generic mouse press events on the listbox
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
@@ -90,14 +115,53 @@ impl<D: Display+Default> ListBox<D> {
             focus: false,
             flags: None,
             ex_flags: 0,
+            column_width: None,
             font: None,
             collection: None,
             selected_index: None,
             multi_selection: Vec::new(),
+            item_count: 0,
             parent: None
         }
     }
 
+    /**
+        Wraps a list box that was created outside of NWG (ex: by hand with `CreateWindowExW`, or by another GUI library).
+        Returns an error if `handle` is not a window of the `ListBox` class.
+
+        **The items already in the control are removed**, because the `ListBox` keeps its items in a typed collection that cannot
+        be rebuilt from the text of the control. Use `set_collection` to fill it again.
+        The events of the list box are raised if its parent is bound to a NWG events handler.
+
+        # Safety
+        - `handle` must be a valid list box created on the current thread with `LBS_HASSTRINGS` and `LBS_NOTIFY`.
+        - The `ListBox` takes ownership of the window: it is destroyed when the `ListBox` is dropped. The window must not
+          be destroyed by the caller, and must not be wrapped by another `ListBox`.
+    */
+    pub unsafe fn from_handle(handle: HWND) -> Result<ListBox<D>, NwgError> {
+        use winapi::um::winuser::{IsWindow, GetClassNameW};
+
+        if handle.is_null() || IsWindow(handle) == 0 {
+            return Err(NwgError::control_create("ListBox::from_handle: the handle is not a valid window"));
+        }
+
+        let mut class_name = [0u16; 64];
+        let count = GetClassNameW(handle, class_name.as_mut_ptr(), class_name.len() as _) as usize;
+        if !from_utf16(&class_name[..count]).eq_ignore_ascii_case("ListBox") {
+            return Err(NwgError::control_create("ListBox::from_handle: the window is not a list box"));
+        }
+
+        let list_box = ListBox {
+            handle: ControlHandle::Hwnd(handle),
+            collection: RefCell::new(Vec::new()),
+        };
+
+        list_box.clear_inner(handle);
+        list_box.hook_selection();
+
+        Ok(list_box)
+    }
+
     /// Add a new item to the listbox. Sort the collection if the listbox is sorted.
     pub fn push(&self, item: D) {
         use winapi::um::winuser::LB_ADDSTRING;
@@ -333,6 +397,182 @@ impl<D: Display+Default> ListBox<D> {
         }
     }
 
+    /// Same as `sync`, but the scroll position and the selection of the list box are restored after the rebuild.
+    /// Indices that are out of range in the new collection are dropped and the top index is clamped to the new length.
+    ///
+    /// The selection is restored by index, not by value. This only makes sense when the data is mostly stable
+    /// (ex: a list refreshed on a timer where the items are updated in place or appended at the end).
+    pub fn sync_preserving_view(&self) {
+        use winapi::um::winuser::LBS_EXTENDEDSEL;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let multi = wh::get_style(handle) & (LBS_MULTIPLESEL | LBS_EXTENDEDSEL) != 0;
+
+        let top = self.top_index();
+        let selection = match multi {
+            true => self.multi_selection(),
+            false => self.selection().into_iter().collect()
+        };
+
+        self.resync(handle, multi, selection, top);
+    }
+
+    /**
+        Sort the inner collection with a comparator function and rebuild the list box, so that the order follows
+        the data model instead of the string collation of the system. The sort is stable.
+
+        The selected items stay selected at their new position and the scroll position is kept.
+        The item keys (see `set_item_key`) follow their item. The list box is not redrawn until the rebuild is done.
+    */
+    pub fn sort_by<F: FnMut(&D, &D) -> Ordering>(&self, mut f: F) {
+        use winapi::um::winuser::LBS_EXTENDEDSEL;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let multi = wh::get_style(handle) & (LBS_MULTIPLESEL | LBS_EXTENDEDSEL) != 0;
+
+        let top = self.top_index();
+        let selection = match multi {
+            true => self.multi_selection(),
+            false => self.selection().into_iter().collect::<Vec<_>>()
+        };
+
+        let keys: Vec<LPARAM> = (0..self.len())
+            .map(|i| wh::send_message(handle, LB_GETITEMDATA, i as WPARAM, 0) as LPARAM)
+            .collect();
+
+        // Sort the indices first to know where each item goes
+        let new_positions = {
+            let mut col = self.collection.borrow_mut();
+            let mut order: Vec<usize> = (0..col.len()).collect();
+            order.sort_by(|&a, &b| f(&col[a], &col[b]));
+
+            let mut items: Vec<Option<D>> = col.drain(..).map(Some).collect();
+            col.extend(order.iter().map(|&i| items[i].take().unwrap()));
+
+            let mut new_positions = vec![0; order.len()];
+            for (new, &old) in order.iter().enumerate() {
+                new_positions[old] = new;
+            }
+
+            new_positions
+        };
+
+        let selection = selection.into_iter()
+            .filter_map(|i| new_positions.get(i).cloned())
+            .collect();
+
+        self.resync(handle, multi, selection, top);
+
+        for (old, key) in keys.into_iter().enumerate() {
+            if let Some(&new) = new_positions.get(old) {
+                wh::send_message(handle, LB_SETITEMDATA, new as WPARAM, key);
+            }
+        }
+    }
+
+    /**
+        Stores `key` with the item at `index` (`LB_SETITEMDATA`). A key identifies a row independently of its position,
+        so the row can be found again with `index_of_key` after the list box is sorted or after items are inserted before it.
+
+        The keys follow their item in `sort_by` and `sort`, and when other items are inserted or removed. They are cleared by the methods
+        that rebuild the control (`sync`, `sync_preserving_view` and `set_collection`). The key of a new item is 0.
+        On 32 bits systems, only the lower 32 bits of the key are stored. Virtual list boxes do not store keys.
+
+        Returns `false` if the index is out of bounds.
+    */
+    pub fn set_item_key(&self, index: usize, key: u64) -> bool {
+        use winapi::um::winuser::LB_ERR;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, LB_SETITEMDATA, index as WPARAM, key as LPARAM) != LB_ERR
+    }
+
+    /// Returns the key of the item at `index`, or `None` if the index is out of bounds. See `set_item_key`.
+    pub fn item_key(&self, index: usize) -> Option<u64> {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        if index >= self.len() {
+            return None;
+        }
+
+        Some(wh::send_message(handle, LB_GETITEMDATA, index as WPARAM, 0) as usize as u64)
+    }
+
+    /**
+        Returns the index of the first item that has the key `key`. See `set_item_key`.
+
+        This is a linear scan that sends one message per item, which is fine for lists of a few thousand items.
+        For bigger lists, keep a map from the keys to the items in the application instead.
+    */
+    pub fn index_of_key(&self, key: u64) -> Option<usize> {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let key = key as usize;
+
+        (0..self.len()).find(|&i| wh::send_message(handle, LB_GETITEMDATA, i as WPARAM, 0) as usize == key)
+    }
+
+    /// Rebuild the list box without redrawing it, then restore the selection and the top index
+    fn resync(&self, handle: HWND, multi: bool, selection: Vec<usize>, top: usize) {
+        use winapi::um::winuser::{WM_SETREDRAW, InvalidateRect};
+
+        wh::send_message(handle, WM_SETREDRAW, 0, 0);
+
+        self.sync();
+
+        let len = self.len();
+        for index in selection.into_iter().filter(|&i| i < len) {
+            match multi {
+                true => self.multi_add_selection(index),
+                false => self.set_selection(Some(index))
+            }
+        }
+
+        if len > 0 {
+            self.set_top_index(top.min(len - 1));
+        }
+
+        wh::send_message(handle, WM_SETREDRAW, 1, 0);
+        unsafe { InvalidateRect(handle, ptr::null(), 1); }
+    }
+
+    /// Return the index of the first visible item in the list box
+    pub fn top_index(&self) -> usize {
+        use winapi::um::winuser::{LB_GETTOPINDEX, LB_ERR};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        match wh::send_message(handle, LB_GETTOPINDEX, 0, 0) {
+            LB_ERR => 0,
+            index => index as usize
+        }
+    }
+
+    /// Scroll the list box so that the item at `index` is the first visible item (or as close as possible at the end of the list).
+    /// Does nothing if the index is out of bound
+    pub fn set_top_index(&self, index: usize) {
+        use winapi::um::winuser::LB_SETTOPINDEX;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, LB_SETTOPINDEX, index as WPARAM, 0);
+    }
+
+    /**
+        Returns the bounds of the item at `index` as `[left, top, right, bottom]`, in the client coordinates of the list box (`LB_GETITEMRECT`).
+        The values are in physical pixels (the same space as `ControlHandle::rect_client`). The rect of an item that is scrolled out
+        is outside of the client area.
+
+        Returns `None` if the index is out of bounds.
+    */
+    pub fn item_rect(&self, index: usize) -> Option<[i32; 4]> {
+        use winapi::um::winuser::{LB_GETITEMRECT, LB_ERR};
+        use winapi::shared::windef::RECT;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let mut r = RECT { left: 0, top: 0, right: 0, bottom: 0 };
+        match wh::send_message(handle, LB_GETITEMRECT, index as WPARAM, &mut r as *mut RECT as LPARAM) {
+            LB_ERR => None,
+            _ => Some([r.left, r.top, r.right, r.bottom])
+        }
+    }
+
     /// Set the item collection of the list box. Return the old collection
     pub fn set_collection(&self, mut col: Vec<D>) -> Vec<D> {
         use winapi::um::winuser::LB_ADDSTRING;
@@ -361,6 +601,23 @@ impl<D: Display+Default> ListBox<D> {
         self.set_collection(Vec::new());
     }
 
+    /**
+        Sets the number of items of a `VIRTUAL` listbox. The rows are repainted and request their text with `OnListBoxGetText`.
+        Returns `false` if the listbox is not virtual or if the count could not be set.
+    */
+    pub fn set_item_count(&self, count: usize) -> bool {
+        use winapi::um::winuser::{LB_SETCOUNT, LB_ERR, LB_ERRSPACE};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        !matches!(wh::send_message(handle, LB_SETCOUNT, count as WPARAM, 0), LB_ERR | LB_ERRSPACE)
+    }
+
+    /// Returns `true` if the listbox was created with the `VIRTUAL` flag
+    pub fn is_virtual(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_style(handle) & LBS_NODATA == LBS_NODATA
+    }
+
     /// Return the number of items in the control. NOT the inner rust collection
     pub fn len(&self) -> usize {
         use winapi::um::winuser::LB_GETCOUNT;
@@ -452,6 +709,26 @@ impl<D: Display+Default> ListBox<D> {
         unsafe { wh::set_window_position(handle, x, y) }
     }
 
+    /// Return true if the listbox was created with the `MULTI_COLUMN` flag.
+    pub fn multi_column(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_style(handle) & LBS_MULTICOLUMN == LBS_MULTICOLUMN
+    }
+
+    /// Set the width of every column of a `MULTI_COLUMN` listbox. Items that do not fit in
+    /// the height of the control flow into the next column and the user scrolls horizontally.
+    /// Vertical scrolling is disabled in this mode.
+    ///
+    /// This has no effect on a listbox without the `MULTI_COLUMN` flag.
+    pub fn set_column_width(&self, width: u32) {
+        use winapi::um::winuser::LB_SETCOLUMNWIDTH;
+        use crate::win32::high_dpi::logical_to_physical;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let (width, _) = unsafe { logical_to_physical(width as i32, 0) };
+        wh::send_message(handle, LB_SETCOLUMNWIDTH, width as WPARAM, 0);
+    }
+
     /// Get read-only access to the inner collection of the list box
     /// This call refcell.borrow under the hood. Be sure to drop the value before
     /// calling other list box methods
@@ -466,6 +743,328 @@ impl<D: Display+Default> ListBox<D> {
         self.collection.borrow_mut()
     }
 
+    /// Iterate over the items of the inner collection. Each item is returned with its index and its selected state.
+    /// The selected state is read once from the control when this method is called.
+    ///
+    /// This call refcell.borrow under the hood and the borrow is held for the lifetime of the iterator (and the returned references). 
+    /// Be sure to drop the iterator and the items before calling `collection_mut` or the other list box methods that modify the collection.
+    pub fn iter_with_state<'a>(&'a self) -> impl Iterator<Item = (usize, Ref<'a, D>, bool)> + 'a {
+        use winapi::um::winuser::{LBS_EXTENDEDSEL};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let multi = wh::get_style(handle) & (LBS_MULTIPLESEL | LBS_EXTENDEDSEL) != 0;
+
+        let col = self.collection.borrow();
+        let mut selected = vec![false; col.len()];
+        let selection = match multi {
+            true => self.multi_selection(),
+            false => self.selection().into_iter().collect()
+        };
+
+        for index in selection {
+            if index < selected.len() {
+                selected[index] = true;
+            }
+        }
+
+        (0..col.len()).map(move |i| {
+            let item = Ref::map(Ref::clone(&col), |c| &c[i]);
+            (i, item, selected[i])
+        })
+    }
+
+    /// Returns the `Display` string of every item of the inner collection, in order.
+    /// The strings are formatted from the collection, not read from the control.
+    pub fn to_string_list(&self) -> Vec<String> {
+        self.collection.borrow().iter().map(|item| item.to_string()).collect()
+    }
+
+    /**
+        Exports the items of the inner collection as delimited text, one row per item with a single field (the `Display` string of the item).
+        Use `,` for CSV and `\t` for TSV. See `export_with` for the quoting rules and for the items with many fields.
+    */
+    pub fn export(&self, sep: char) -> String {
+        self.export_with(sep, |item| vec![item.to_string()])
+    }
+
+    /**
+        Exports the items of the inner collection as delimited text. `fields` returns the fields of the row of an item.
+
+        The rows are separated by `\r\n` and the fields by `sep`. The quoting rules are the rules of RFC 4180 (CSV),
+        applied to any separator: a field that contains `sep`, a double quote or a line break is enclosed in double quotes,
+        and its double quotes are doubled. The other fields are not quoted.
+
+        The result can be copied to the clipboard (`Clipboard::set_data_text`) or written to the file chosen with a `FileDialog`.
+        A virtual list box has no inner collection, so its export is empty.
+
+        ```rust
+        use native_windows_gui as nwg;
+
+        struct Contact { name: String, email: String }
+
+        impl std::fmt::Display for Contact {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result { write!(f, "{}", self.name) }
+        }
+
+        impl Default for Contact {
+            fn default() -> Contact { Contact { name: String::new(), email: String::new() } }
+        }
+
+        fn save(list: &nwg::ListBox<Contact>, path: &str) -> std::io::Result<()> {
+            let csv = list.export_with(',', |c| vec![c.name.clone(), c.email.clone()]);
+            std::fs::write(path, csv)
+        }
+        ```
+    */
+    pub fn export_with<F>(&self, sep: char, fields: F) -> String
+        where F: Fn(&D) -> Vec<String>
+    {
+        let rows: Vec<String> = self.collection.borrow().iter()
+            .map(|item| {
+                let row: Vec<String> = fields(item).iter().map(|field| quote_field(field, sep)).collect();
+                row.join(&sep.to_string())
+            })
+            .collect();
+
+        rows.join("\r\n")
+    }
+
+    /**
+        Copies the `Display` string of the selected items to the clipboard, one item per line (separated by `\r\n`), in the list order.
+        Works with single and multi selection list boxes.
+
+        Returns `false` and leaves the clipboard untouched if no item is selected.
+    */
+    #[cfg(feature = "clipboard")]
+    pub fn copy_selection_to_clipboard(&self) -> bool {
+        use crate::Clipboard;
+
+        let selected: Vec<String> = self.iter_with_state()
+            .filter(|(_, _, selected)| *selected)
+            .map(|(_, item, _)| item.to_string())
+            .collect();
+
+        if selected.is_empty() {
+            return false;
+        }
+
+        Clipboard::set_data_text(self.handle, &selected.join("\r\n"));
+
+        true
+    }
+
+    /**
+        Edits the items in place. Double-clicking a row (`OnListBoxDoubleClick`) opens a text input over the row (see `item_rect`),
+        filled with the display value of the item and with all the text selected.
+          * Enter, or moving the focus away from the input (ex: clicking another row), commits the edit
+          * Esc cancels the edit and keeps the original item
+
+        On commit, `f` receives the index of the item and the edited text, and returns the new item. This is where the text
+        is mapped back to `D`: `Some(text.to_string())` for a `ListBox<String>`, `text.parse().ok()` for a number, or a copy of
+        the old item (from `collection`) with one field changed. Returning `None` rejects the text and keeps the original item.
+        The new item replaces the old one in `collection` and in the control. The selection and the key of the row are kept.
+
+        The input is a temporary child of the list box. It is created when the edit begins and destroyed when the edit ends,
+        so there is at most one input at a time and it never raises NWG events. The edit is committed to the index where it began:
+        do not insert or remove items while the input is open.
+
+        The handler is bound on the parent of the list box and only keeps a weak reference to the list box: it does nothing once
+        the list box is dropped. It lives until the parent is destroyed. To disable the edit earlier, pass the returned handler
+        to `unbind_raw_event_handler`. Virtual list boxes do not store their items and return an error.
+
+        ```rust
+        use native_windows_gui as nwg;
+        use std::rc::Rc;
+
+        fn rename(names: &Rc<nwg::ListBox<String>>) -> nwg::RawEventHandler {
+            names.enable_inline_edit(|_index, text| match text.trim() {
+                "" => None,
+                name => Some(name.to_string())
+            }).unwrap()
+        }
+        ```
+    */
+    pub fn enable_inline_edit<F>(self: &Rc<Self>, f: F) -> Result<RawEventHandler, NwgError>
+        where D: 'static,
+              F: Fn(usize, &str) -> Option<D> + 'static
+    {
+        use crate::bind_raw_event_handler_inner;
+        use winapi::um::winuser::{WM_COMMAND, LBN_DBLCLK};
+        use winapi::shared::minwindef::HIWORD;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        /// Keep generating ids so that several list boxes can be edited in the same parent
+        static INLINE_EDIT_HANDLER_ID: AtomicUsize = AtomicUsize::new(0xBFFF);
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        if self.is_virtual() {
+            return Err(NwgError::control_create("A virtual ListBox cannot be edited in place"));
+        }
+
+        let parent = ControlHandle::Hwnd(wh::get_window_parent(handle));
+        let list_box = Rc::downgrade(self);
+        let commit: InlineEditCommit<D> = Rc::new(f);
+        let handler_id = INLINE_EDIT_HANDLER_ID.fetch_add(1, Ordering::SeqCst);
+
+        bind_raw_event_handler_inner(&parent, handler_id, move |_hwnd, msg, w, l| {
+            if msg != WM_COMMAND || l as HWND != handle || HIWORD(w as u32) != LBN_DBLCLK {
+                return None;
+            }
+
+            let list_box = match list_box.upgrade() {
+                Some(l) => l,
+                None => { return None; }
+            };
+
+            if let Some(index) = unsafe { wh::list_box_current_item(handle) } {
+                list_box.open_inline_editor(index, commit.clone());
+            }
+
+            None
+        })
+    }
+
+    /// Opens the text input of `enable_inline_edit` over the item at `index`
+    fn open_inline_editor(self: &Rc<Self>, index: usize, commit: InlineEditCommit<D>) where D: 'static {
+        use crate::bind_raw_event_handler_until_destroyed;
+        use crate::win32::window_helper::LIST_BOX_INLINE_EDIT_HANDLER_ID;
+        use winapi::um::winuser::{WM_GETDLGCODE, WM_KEYDOWN, WM_CHAR, WM_KILLFOCUS, WM_CLOSE, WM_GETFONT, WM_SETFONT, EM_SETSEL,
+            VK_RETURN, VK_ESCAPE, DLGC_WANTALLKEYS, WS_CHILD, WS_BORDER, ES_AUTOHSCROLL, HWND_TOP, SWP_SHOWWINDOW, SetWindowPos, SetFocus};
+        use winapi::um::commctrl::DefSubclassProc;
+        use std::cell::Cell;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let text = match self.collection.borrow().get(index) {
+            Some(item) => format!("{}", item),
+            None => { return; }
+        };
+
+        let [left, top, right, bottom] = match self.item_rect(index) {
+            Some(rect) => rect,
+            None => { return; }
+        };
+
+        let input = ControlBase::build_hwnd()
+            .class_name("Edit")
+            .flags(0)
+            .forced_flags(WS_CHILD | WS_BORDER | ES_AUTOHSCROLL)
+            .size((0, 0))
+            .position((0, 0))
+            .text(&text)
+            .parent(Some(self.handle))
+            .build();
+
+        let input = match input.ok().and_then(|i| i.hwnd()) {
+            Some(i) => i,
+            None => { return; }
+        };
+
+        let list_box = Rc::downgrade(self);
+        let done = Cell::new(false);
+        let handler = move |hwnd, msg, w, l| {
+            let accept = match msg {
+                WM_GETDLGCODE => { return unsafe { Some(DefSubclassProc(hwnd, msg, w, l) | DLGC_WANTALLKEYS as LRESULT) }; },
+                WM_KEYDOWN if w == VK_RETURN as WPARAM => true,
+                WM_KEYDOWN if w == VK_ESCAPE as WPARAM => false,
+                // The edit control beeps on the chars of Enter and Esc
+                WM_CHAR if w == VK_RETURN as WPARAM || w == VK_ESCAPE as WPARAM => { return Some(0); },
+                WM_KILLFOCUS => true,
+                _ => { return None; }
+            };
+
+            // Giving the focus back to the list box sends `WM_KILLFOCUS` again
+            if !done.replace(true) {
+                let list_box = list_box.upgrade();
+                if let (true, Some(list_box)) = (accept, list_box.as_ref()) {
+                    let text = unsafe { wh::get_window_text(hwnd) };
+                    if let Some(item) = commit(index, &text) {
+                        list_box.replace_inner(handle, index, item);
+                    }
+                }
+
+                if msg != WM_KILLFOCUS {
+                    unsafe { SetFocus(handle); }
+                }
+
+                // The input cannot be destroyed while it processes its own message
+                wh::post_message(hwnd, WM_CLOSE, 0, 0);
+            }
+
+            match msg {
+                WM_KILLFOCUS => None,
+                _ => Some(0)
+            }
+        };
+
+        unsafe {
+            bind_raw_event_handler_until_destroyed(input, LIST_BOX_INLINE_EDIT_HANDLER_ID, handler);
+
+            let font = wh::send_message(handle, WM_GETFONT, 0, 0);
+            wh::send_message(input, WM_SETFONT, font as WPARAM, 0);
+            SetWindowPos(input, HWND_TOP, left, top, right - left, bottom - top, SWP_SHOWWINDOW);
+            wh::send_message(input, EM_SETSEL as u32, 0, -1);
+            SetFocus(input);
+        }
+    }
+
+    /// Replaces the item at `index` in the collection and in the control, keeping its selection and its key
+    fn replace_inner(&self, handle: HWND, index: usize, item: D) {
+        use winapi::um::winuser::{LB_DELETESTRING, LB_INSERTSTRING, LB_SETSEL, LBS_EXTENDEDSEL};
+
+        if index >= self.collection.borrow().len() {
+            return;
+        }
+
+        let multi = wh::get_style(handle) & (LBS_MULTIPLESEL | LBS_EXTENDEDSEL) != 0;
+        let selected = self.selected(index);
+        let key = self.item_key(index);
+        let display_os = to_utf16(&format!("{}", item));
+
+        self.collection.borrow_mut()[index] = item;
+
+        wh::send_message(handle, LB_DELETESTRING, index as WPARAM, 0);
+        wh::send_message(handle, LB_INSERTSTRING, index as WPARAM, display_os.as_ptr() as LPARAM);
+
+        if let Some(key) = key {
+            self.set_item_key(index, key);
+        }
+
+        match (selected, multi) {
+            (false, _) => {},
+            (true, true) => { wh::send_message(handle, LB_SETSEL, 1, index as LPARAM); },
+            (true, false) => self.set_selection(Some(index)),
+        }
+    }
+
+    /// Saves the selection before every user input that can change it, so that `OnListBoxSelect` can report the old selection.
+    /// The list box sends `LBN_SELCHANGE` while it processes the input, after the selection was saved.
+    fn hook_selection(&self) {
+        use crate::bind_raw_event_handler_inner;
+        use crate::win32::window_helper::{LIST_BOX_SELECTION_HANDLER_ID, LIST_BOX_SELECTION_PROP};
+        use winapi::um::winuser::{SetPropW, RemovePropW, WM_LBUTTONDOWN, WM_LBUTTONDBLCLK, WM_MOUSEMOVE, WM_KEYDOWN, WM_CHAR, WM_DESTROY};
+
+        let prop_name = to_utf16(LIST_BOX_SELECTION_PROP);
+        let handler = bind_raw_event_handler_inner(&self.handle, LIST_BOX_SELECTION_HANDLER_ID, move |hwnd, msg, _w, _l| {
+            unsafe {
+                match msg {
+                    WM_LBUTTONDOWN | WM_LBUTTONDBLCLK | WM_MOUSEMOVE | WM_KEYDOWN | WM_CHAR => {
+                        let value = wh::list_box_current_item(hwnd).map(|i| i + 1).unwrap_or(0);
+                        SetPropW(hwnd, prop_name.as_ptr(), value as _);
+                    },
+                    WM_DESTROY => {
+                        RemovePropW(hwnd, prop_name.as_ptr());
+                    },
+                    _ => {}
+                }
+            }
+
+            None
+        });
+
+        // The handler is freed when the list box is dropped
+        drop(handler);
+    }
+
     /// Winapi class name used during control creation
     pub fn class_name(&self) -> &'static str {
         "ListBox"
@@ -491,8 +1090,24 @@ impl<D: Display+Default> ListBox<D> {
 
 }
 
+impl<D: Display+Default+Ord> ListBox<D> {
+
+    /// Sort the inner collection in ascending order and rebuild the list box. See `sort_by`.
+    pub fn sort(&self) {
+        self.sort_by(|a, b| a.cmp(b));
+    }
+
+}
+
 impl<D: Display+Default> Drop for ListBox<D> {
     fn drop(&mut self) {
+        use crate::unbind_raw_event_handler_inner;
+        use crate::win32::window_helper::LIST_BOX_SELECTION_HANDLER_ID;
+
+        if self.handle.hwnd().is_some() {
+            drop(unbind_raw_event_handler_inner(&self.handle, LIST_BOX_SELECTION_HANDLER_ID));
+        }
+
         self.handle.destroy();
     }
 }
@@ -504,10 +1119,12 @@ pub struct ListBoxBuilder<'a, D: Display+Default> {
     focus: bool,
     flags: Option<ListBoxFlags>,
     ex_flags: u32,
+    column_width: Option<u32>,
     font: Option<&'a Font>,
     collection: Option<Vec<D>>,
     selected_index: Option<usize>,
     multi_selection: Vec<usize>,
+    item_count: usize,
     parent: Option<ControlHandle>
 }
 
@@ -568,8 +1185,27 @@ impl<'a, D: Display+Default> ListBoxBuilder<'a, D> {
         self
     }
 
+    pub fn column_width(mut self, width: u32) -> ListBoxBuilder<'a, D> {
+        self.column_width = Some(width);
+        self
+    }
+
+    pub fn item_count(mut self, count: usize) -> ListBoxBuilder<'a, D> {
+        self.item_count = count;
+        self
+    }
+
     pub fn build(self, out: &mut ListBox<D>) -> Result<(), NwgError> {
+        use winapi::um::winuser::LBS_HASSTRINGS;
+
         let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+        let virtual_list = flags & LBS_NODATA == LBS_NODATA;
+
+        // A list box without data cannot store strings
+        let forced_flags = match virtual_list {
+            true => out.forced_flags() & !LBS_HASSTRINGS,
+            false => out.forced_flags()
+        };
 
         let parent = match self.parent {
             Some(p) => Ok(p),
@@ -580,7 +1216,7 @@ impl<'a, D: Display+Default> ListBoxBuilder<'a, D> {
 
         out.handle = ControlBase::build_hwnd()
             .class_name(out.class_name())
-            .forced_flags(out.forced_flags())
+            .forced_flags(forced_flags)
             .flags(flags)
             .ex_flags(self.ex_flags)
             .size(self.size)
@@ -594,7 +1230,13 @@ impl<'a, D: Display+Default> ListBoxBuilder<'a, D> {
             out.set_font(Font::global_default().as_ref());
         }
 
-        if let Some(col) = self.collection {
+        if let Some(width) = self.column_width {
+            out.set_column_width(width);
+        }
+
+        if virtual_list {
+            out.set_item_count(self.item_count);
+        } else if let Some(col) = self.collection {
             out.set_collection(col);
         }
 
@@ -614,11 +1256,22 @@ impl<'a, D: Display+Default> ListBoxBuilder<'a, D> {
             out.set_enabled(self.enabled);
         }
 
+        out.hook_selection();
+
         Ok(())
     }
 
 }
 
+/// Quotes a field of a delimited row. See `ListBox::export_with`
+fn quote_field(field: &str, sep: char) -> String {
+    let quote = field.chars().any(|c| c == sep || c == '"' || c == '\r' || c == '\n');
+    match quote {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string()
+    }
+}
+
 impl<D: Display+Default> PartialEq for ListBox<D> {
     fn eq(&self, other: &Self) -> bool {
         self.handle == other.handle
@@ -791,6 +791,19 @@ impl ListView {
         wh::send_message(handle, LVM_SETITEMW , 0, &mut item as *mut LVITEMW as _);
     }
 
+    /// Sets the text of the item at `row_index` and `column_index`.
+    /// Does nothing if there is no row at `row_index`
+    pub fn set_subitem(&self, row_index: usize, column_index: usize, text: &str) {
+        self.update_item(row_index, InsertListViewItem {
+            index: Some(row_index as _),
+            column_index: column_index as _,
+            text: Some(text.to_string()),
+
+            #[cfg(feature="image-list")]
+            image: None,
+        });
+    }
+
     /// Remove all items on the seleted row. Returns `true` if an item was removed or false otherwise.
     /// To "remove" an item without deleting the row, use `update_item` and set the text to "".
     pub fn remove_item(&self, row_index: usize) -> bool {
@@ -1,5 +1,6 @@
+use winapi::shared::windef::HBITMAP;
 use crate::win32::menu as mh;
-use crate::NwgError;
+use crate::{NwgError, Bitmap};
 use super::{ControlBase, ControlHandle};
 use std::ptr;
 
@@ -307,6 +308,55 @@ impl MenuItem {
         unsafe { mh::menu_item_checked(parent_handle, id) }
     }
 
+    /**
+        Shows `bitmap` in the icon column of the menu item, next to the text (`MIIM_BITMAP`). `None` removes the image.
+        Use a 32 bits bitmap with an alpha channel for transparency (ex: a bitmap loaded from a png).
+
+        The menu does not copy the bitmap: it must be kept alive as long as the menu item shows it.
+        Returns `false` if the image could not be set.
+
+        Images and custom drawing:
+        - `set_bitmap` shows an image next to the text, like an icon (ex: the recent files of an application or a color swatch).
+          The menu grows to fit the image, so the bitmap should have the size of a small icon (16x16 at 96 dpi).
+        - `set_check_bitmaps` replaces the check mark. The bitmaps are drawn in the check mark column, at the size of
+          a check mark (`SM_CXMENUCHECK`), and a checked item without image shows no check mark at all.
+        - For anything else (custom fonts, colored text, full custom rendering), build the item with `MFT_OWNERDRAW` using the
+          winapi and handle `WM_MEASUREITEM`/`WM_DRAWITEM` (with `ODT_MENU`) in a raw event handler on the window that owns the menu.
+          NWG does not raise an event for owner drawn menu items.
+    */
+    pub fn set_bitmap(&self, bitmap: Option<&Bitmap>) -> bool {
+        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+        let (parent_handle, id) = self.handle.hmenu_item().expect(BAD_HANDLE);
+        let bitmap = bitmap.map(|b| b.handle as HBITMAP).unwrap_or(ptr::null_mut());
+
+        unsafe { mh::set_menu_item_bitmap(parent_handle, id, bitmap) }
+    }
+
+    /// Returns `true` if the menu item shows an image set with `set_bitmap`
+    pub fn has_bitmap(&self) -> bool {
+        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+        let (parent_handle, id) = self.handle.hmenu_item().expect(BAD_HANDLE);
+
+        unsafe { !mh::menu_item_bitmap(parent_handle, id).is_null() }
+    }
+
+    /**
+        Replaces the check mark of the menu item with `checked` when the item is checked, and shows `unchecked` when it is not (`SetMenuItemBitmaps`).
+        `None` shows nothing in that state. Passing `None` for both restores the default check mark.
+
+        The bitmaps are drawn at the size of a check mark (`SM_CXMENUCHECK`). They must be kept alive as long as the menu item uses them.
+        See `set_bitmap` for the other ways to customize the menu items.
+        Returns `false` if the images could not be set.
+    */
+    pub fn set_check_bitmaps(&self, unchecked: Option<&Bitmap>, checked: Option<&Bitmap>) -> bool {
+        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+        let (parent_handle, id) = self.handle.hmenu_item().expect(BAD_HANDLE);
+        let unchecked = unchecked.map(|b| b.handle as HBITMAP).unwrap_or(ptr::null_mut());
+        let checked = checked.map(|b| b.handle as HBITMAP).unwrap_or(ptr::null_mut());
+
+        unsafe { mh::set_menu_item_check_bitmaps(parent_handle, id, unchecked, checked) }
+    }
+
 }
 
 impl Drop for MenuItem {
@@ -7,6 +7,7 @@ mod radio_button;
 mod text_input;
 mod label;
 mod image_frame;
+mod system_menu;
 
 #[cfg(feature = "textbox")]
 mod text_box;
@@ -68,6 +69,9 @@ mod message_window;
 #[cfg(feature = "list-view")]
 mod list_view;
 
+#[cfg(feature = "list-view")]
+mod data_list_view;
+
 #[cfg(feature = "number-select")]
 mod number_select;
 
@@ -77,6 +81,9 @@ mod extern_canvas;
 #[cfg(feature = "frame")]
 mod frame;
 
+#[cfg(feature = "splitter")]
+mod splitter;
+
 #[cfg(feature = "scroll-bar")]
 mod scroll_bar;
 
@@ -85,15 +92,18 @@ mod plotters;
 
 mod handle_from_control;
 
-pub use control_handle::ControlHandle;
+mod preferred_size;
+
+pub use control_handle::{ControlHandle, ControlUpdate};
 pub use control_base::{ControlBase, HwndBuilder, TimerBuilder as BaseTimerBuilder, OtherBuilder};
-pub use window::{Window, WindowBuilder, WindowFlags};
+pub use window::{Window, WindowBuilder, WindowFlags, SnapEdge, CornerPreference, BackdropType, DisplayAffinity, ResizeMode};
 pub use button::{Button, ButtonBuilder, ButtonFlags};
 pub use check_box::{CheckBox, CheckBoxBuilder, CheckBoxState, CheckBoxFlags};
 pub use radio_button::{RadioButton, RadioButtonBuilder, RadioButtonState, RadioButtonFlags};
-pub use text_input::{TextInput, TextInputBuilder, TextInputFlags};
+pub use text_input::{TextInput, TextInputBuilder, TextInputFlags, TextInputCase};
 pub use label::{Label, LabelBuilder, LabelFlags};
 pub use image_frame::{ImageFrame, ImageFrameBuilder, ImageFrameFlags};
+pub use system_menu::{SystemMenu, SystemMenuItem};
 
 #[cfg(feature = "textbox")]
 pub use text_box::{TextBox, TextBoxBuilder, TextBoxFlags};
@@ -160,6 +170,9 @@ pub use message_window::{MessageWindow, MessageWindowBuilder};
 #[cfg(feature = "list-view")]
 pub use list_view::{ListView, ListViewStyle, ListViewBuilder, ListViewFlags, ListViewExFlags, InsertListViewItem, ListViewItem, InsertListViewColumn, ListViewColumn, ListViewColumnSortArrow, ListViewColumnFlags};
 
+#[cfg(feature = "list-view")]
+pub use data_list_view::{DataListView, DataListViewBuilder, ListViewRow};
+
 #[cfg(all(feature="list-view", feature="image-list"))]
 pub use list_view::ListViewImageListType;
 
@@ -172,6 +185,9 @@ pub use extern_canvas::{ExternCanvas, ExternCanvasBuilder, ExternCanvasFlags};
 #[cfg(feature = "frame")]
 pub use frame::{Frame, FrameBuilder, FrameFlags};
 
+#[cfg(feature = "splitter")]
+pub use splitter::{Splitter, SplitterBuilder, SplitterFlags, SplitterOrientation};
+
 #[cfg(feature = "scroll-bar")]
 pub use scroll_bar::{ScrollBar, ScrollBarBuilder, ScrollBarFlags};
 
@@ -179,3 +195,5 @@ pub use scroll_bar::{ScrollBar, ScrollBarBuilder, ScrollBarFlags};
 pub use self::plotters::{Plotters, PlottersBuilder, PlottersDrawingArea, PlottersBackend, PlottersError};
 
 pub use handle_from_control::*;
+
+pub use preferred_size::PreferredSize;
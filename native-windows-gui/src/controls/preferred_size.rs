@@ -0,0 +1,142 @@
+use crate::win32::window_helper as wh;
+use crate::win32::base_helper::check_hwnd;
+use super::{ControlHandle, Button, CheckBox, RadioButton, TextInput, Label};
+
+#[cfg(feature = "listbox")]
+use super::ListBox;
+
+#[cfg(feature = "listbox")]
+use std::fmt::Display;
+
+const NOT_BOUND: &str = "Control is not yet bound to a winapi object";
+const BAD_HANDLE: &str = "INTERNAL ERROR: Control handle is not HWND!";
+
+/// Padding added on each side of the text of a button, in logical pixels. Same as the usual `fit_to_content` padding.
+const BUTTON_PADDING: u32 = 5;
+
+/// Minimum width of a text input, in logical pixels. The text of an input is not its intrinsic size.
+const TEXT_INPUT_MIN_WIDTH: u32 = 100;
+
+/// Number of rows in the preferred height of a list box
+#[cfg(feature = "listbox")]
+const LIST_BOX_MIN_ROWS: usize = 3;
+#[cfg(feature = "listbox")]
+const LIST_BOX_MAX_ROWS: usize = 8;
+
+
+/**
+    The size a control needs to display its content, in logical pixels. Layouts and dialogs that fit their content use it
+    to size the controls instead of hardcoding a size.
+
+    * `Button`: the text of the button, its borders and a padding of 5 pixels on each side (`fit_to_content(5)`)
+    * `Label`: the text of the label, multiline text included (`fit_to_content(0)`)
+    * `CheckBox`, `RadioButton`: the glyph and the text (`fit_to_content(0)`)
+    * `TextInput`: the height of one line of text. The width is the width of the current text, but at least 100 pixels
+    * `ListBox`: the longest item and between 3 and 8 rows, plus the vertical scroll bar
+    * `ControlHandle`: the current size of the control. This is the default for the controls without an intrinsic size
+
+    The value is measured with the current text and font of the control, so it must be queried again after they change.
+
+    The layouts do not query it on their own. Use it to fill the constraints of a layout item. The explicit constraints always win:
+
+    ```rust
+    use native_windows_gui as nwg;
+    use nwg::PreferredSize;
+    use nwg::stretch::{geometry::Size, style::Dimension as D};
+
+    fn layout(window: &nwg::Window, button: &nwg::Button, layout: &nwg::FlexboxLayout) {
+        let (w, h) = button.preferred_size();
+        nwg::FlexboxLayout::builder()
+            .parent(window)
+            .child(button)
+                .child_min_size(Size { width: D::Points(w as f32), height: D::Points(h as f32) })
+            .build(layout)
+            .unwrap();
+    }
+    ```
+
+    With `child_min_size`, the item grows with the layout but never gets smaller than its content.
+    A `child_size` or a `child_max_size` set on the same item still applies, so the content can be cut if it is smaller than the preferred size.
+
+    Panics if the control is not bound.
+*/
+pub trait PreferredSize {
+    fn preferred_size(&self) -> (u32, u32);
+}
+
+impl PreferredSize for ControlHandle {
+    fn preferred_size(&self) -> (u32, u32) {
+        let handle = check_hwnd(self, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_size(handle) }
+    }
+}
+
+impl PreferredSize for Button {
+    fn preferred_size(&self) -> (u32, u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::window_text_fit_size(handle, wh::push_button_text_extra(), BUTTON_PADDING) }
+    }
+}
+
+impl PreferredSize for Label {
+    fn preferred_size(&self) -> (u32, u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::window_text_fit_size(handle, (0, 0), 0) }
+    }
+}
+
+impl PreferredSize for CheckBox {
+    fn preferred_size(&self) -> (u32, u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::window_text_fit_size(handle, wh::check_text_extra(handle), 0) }
+    }
+}
+
+impl PreferredSize for RadioButton {
+    fn preferred_size(&self) -> (u32, u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::window_text_fit_size(handle, wh::check_text_extra(handle), 0) }
+    }
+}
+
+impl PreferredSize for TextInput {
+    fn preferred_size(&self) -> (u32, u32) {
+        use winapi::um::winuser::{GetSystemMetrics, SM_CXEDGE, SM_CYEDGE};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe {
+            // The client edge and the inner margins of the edit control
+            let extra = ((GetSystemMetrics(SM_CXEDGE) * 4) as u32, (GetSystemMetrics(SM_CYEDGE) * 4) as u32);
+            let (w, h) = wh::window_text_fit_size(handle, extra, 0);
+            (w.max(TEXT_INPUT_MIN_WIDTH), h)
+        }
+    }
+}
+
+#[cfg(feature = "listbox")]
+impl<D: Display+Default> PreferredSize for ListBox<D> {
+    fn preferred_size(&self) -> (u32, u32) {
+        use winapi::um::winuser::{GetSystemMetrics, SM_CXEDGE, SM_CYEDGE, SM_CXVSCROLL, LB_GETITEMHEIGHT};
+        use crate::win32::high_dpi::physical_to_logical;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let items = self.to_string_list();
+
+        unsafe {
+            let text_width = items.iter()
+                .map(|item| wh::measure_text(handle, item).0)
+                .max()
+                .unwrap_or(0);
+
+            let item_height = wh::send_message(handle, LB_GETITEMHEIGHT, 0, 0).max(0) as u32;
+            let rows = items.len().clamp(LIST_BOX_MIN_ROWS, LIST_BOX_MAX_ROWS) as u32;
+
+            let border = (GetSystemMetrics(SM_CXEDGE) * 2, GetSystemMetrics(SM_CYEDGE) * 2);
+            let width = text_width as i32 + GetSystemMetrics(SM_CXVSCROLL) + border.0 * 2;
+            let height = (item_height * rows) as i32 + border.1;
+
+            let (w, h) = physical_to_logical(width, height);
+            (w as u32, h as u32)
+        }
+    }
+}
@@ -4,7 +4,7 @@ that indicates what the button does when the user selects it.
 */
 
 use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED};
-use winapi::um::commctrl::{PBS_MARQUEE, PBS_VERTICAL};
+use winapi::um::commctrl::{PBS_MARQUEE, PBS_VERTICAL, PBS_SMOOTH, PBS_SMOOTHREVERSE};
 use crate::win32::window_helper as wh;
 use crate::win32::base_helper::check_hwnd;
 use crate::NwgError;
@@ -16,11 +16,23 @@ const BAD_HANDLE: &'static str = "INTERNAL ERROR: Progress bar handle is not HWN
 
 
 bitflags! {
+    /**
+        The progress bar flags
+
+        * VISIBLE:  The progress bar is immediatly visible after creation
+        * DISABLED: The progress bar is disabled
+        * VERTICAL: The progress bar fills from the bottom to the top
+        * MARQUEE:  The progress bar shows a moving block instead of the position. See `set_marquee`
+        * SMOOTH:   Draws a continuous bar instead of blocks. Only used without visual styles, the themed bar is always continuous
+        * SMOOTH_REVERSE: The bar is animated when the position decreases (comctl32 v6). See `set_pos_smooth`
+    */
     pub struct ProgressBarFlags: u32 {
         const VISIBLE = WS_VISIBLE;
         const DISABLED = WS_DISABLED;
         const VERTICAL = PBS_VERTICAL;
         const MARQUEE = PBS_MARQUEE;
+        const SMOOTH = PBS_SMOOTH;
+        const SMOOTH_REVERSE = PBS_SMOOTHREVERSE;
     }
 }
 
@@ -174,6 +186,55 @@ impl ProgressBar {
         wh::send_message(handle, PBM_SETPOS, p as WPARAM, 0);
     }
 
+    /**
+        Set the position of the progress bar and animate the bar from the old position to the new one.
+        `pos` returns the new position immediately, only the drawing is animated.
+
+        The animation is done by the themed progress bar (comctl32 v6): it fills the bar towards a higher position,
+        but it jumps to a lower position unless the bar has the `SMOOTH_REVERSE` flag. This method adds the flag before moving the bar backward.
+        Without visual styles, the bar is always drawn immediately.
+
+        `set_pos` is animated the same way when the position increases. Use `set_pos_immediate` to skip the animation.
+    */
+    pub fn set_pos_smooth(&self, p: u32) {
+        use winapi::um::commctrl::PBM_SETPOS;
+        use winapi::shared::minwindef::WPARAM;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let style = wh::get_style(handle);
+        if p < self.pos() && style & PBS_SMOOTHREVERSE == 0 {
+            wh::set_style(handle, style | PBS_SMOOTHREVERSE);
+        }
+
+        wh::send_message(handle, PBM_SETPOS, p as WPARAM, 0);
+    }
+
+    /**
+        Set the position of the progress bar without the fill animation of the themed progress bar.
+
+        The themed bar draws a lower position immediately, so the position is set one step too far and then moved back.
+        At the end of the range, the range is extended by one during the move.
+    */
+    pub fn set_pos_immediate(&self, p: u32) {
+        use winapi::um::commctrl::{PBM_SETPOS, PBM_SETRANGE32};
+        use winapi::shared::minwindef::{WPARAM, LPARAM};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let range = self.range();
+        let p = p.max(range.start).min(range.end);
+
+        if p == range.end {
+            wh::send_message(handle, PBM_SETRANGE32, range.start as WPARAM, (range.end + 1) as LPARAM);
+            wh::send_message(handle, PBM_SETPOS, (p + 1) as WPARAM, 0);
+            wh::send_message(handle, PBM_SETPOS, p as WPARAM, 0);
+            wh::send_message(handle, PBM_SETRANGE32, range.start as WPARAM, range.end as LPARAM);
+        } else {
+            wh::send_message(handle, PBM_SETPOS, (p + 1) as WPARAM, 0);
+            wh::send_message(handle, PBM_SETPOS, p as WPARAM, 0);
+        }
+    }
+
     /// Get the range of the progress bar
     pub fn range(&self) -> Range<u32> {
         use winapi::um::commctrl::PBM_GETRANGE;
@@ -63,6 +63,17 @@ Note: Internally, radio buttons are `Button` and as such, they trigger the same
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
 
+**Groups and keyboard navigation:**
+A group starts at a radio button with the `GROUP` flag and contains the next controls of the same parent, in the creation order,
+until the next control with the `GROUP` flag or the end of the parent. Checking a radio button unchecks the other radio buttons of its group.
+
+The dialog manager of the events loop (`IsDialogMessage`) gives the groups the behavior of WinForms:
+  * The arrow keys move the focus to the next or previous radio button of the group, wrapping at the ends, and check it
+  * The checked radio button of a group is its tab stop: Tab enters the group on the checked radio button and leaves the group with the next Tab.
+    Give the `TAB_STOP` flag to the first radio button of the group so that Tab reaches a group where nothing is checked yet
+
+A control created after a group (ex: a button) belongs to the group unless it starts a new one. The simplest way to close a group
+is to put it in its own `Frame`, which is how a `GroupBox` works in WinForms. `set_group` moves the start of a group after the creation.
 
 ```rust
 use native_windows_gui as nwg;
@@ -190,6 +201,27 @@ impl RadioButton {
         unsafe { wh::set_focus(handle); }
     }
 
+    /// Return true if the radio button starts a new group. See `RadioButton`.
+    pub fn group(&self) -> bool {
+        use winapi::um::winuser::WS_GROUP;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_style(handle) & WS_GROUP != 0
+    }
+
+    /// Set if the radio button starts a new group (`WS_GROUP`). Without it, the radio button joins the group of the previous controls.
+    pub fn set_group(&self, v: bool) {
+        use winapi::um::winuser::{WS_GROUP, GWL_STYLE};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let (add, remove) = match v {
+            true => (WS_GROUP, 0),
+            false => (0, WS_GROUP)
+        };
+
+        unsafe { wh::modify_window_style(handle, GWL_STYLE, add, remove); }
+    }
+
     /// Return true if the control user can interact with the control, return false otherwise
     pub fn enabled(&self) -> bool {
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
@@ -251,6 +283,13 @@ impl RadioButton {
         unsafe { wh::set_window_text(handle, v) }
     }
 
+    /// Resizes the radio button so that its glyph and its text fit, using the radio button font.
+    /// `padding` is added on each side, in logical pixels.
+    pub fn fit_to_content(&self, padding: u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::fit_check_window_to_text(handle, padding) }
+    }
+
     /// Winapi class name used during control creation
     pub fn class_name(&self) -> &'static str {
         "BUTTON"
@@ -0,0 +1,478 @@
+use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, WS_CHILD};
+use crate::win32::window_helper as wh;
+use crate::win32::base_helper::check_hwnd;
+use crate::{NwgError, RawEventHandler, unbind_raw_event_handler};
+use super::{ControlBase, ControlHandle};
+use std::cell::Cell;
+use std::rc::Rc;
+
+const NOT_BOUND: &str = "Splitter is not yet bound to a winapi object";
+const BAD_HANDLE: &str = "INTERNAL ERROR: Splitter handle is not HWND!";
+
+
+bitflags! {
+    /**
+        The splitter flags
+
+        * NONE:     No flags. Equivalent to a invisible splitter.
+        * VISIBLE:  The splitter is immediatly visible after creation
+        * DISABLED: The splitter cannot be dragged by the user
+    */
+    pub struct SplitterFlags: u32 {
+        const NONE = 0;
+        const VISIBLE = WS_VISIBLE;
+        const DISABLED = WS_DISABLED;
+    }
+}
+
+/**
+    The orientation of a splitter bar
+
+    * Vertical: A vertical bar between a left and a right panel. The splitter moves horizontally.
+    * Horizontal: A horizontal bar between a top and a bottom panel. The splitter moves vertically.
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SplitterOrientation {
+    Vertical,
+    Horizontal,
+}
+
+/// The limits of the panels around a splitter, shared with the drag handler
+struct SplitterState {
+    orientation: SplitterOrientation,
+    min_first: Cell<u32>,
+    max_first: Cell<Option<u32>>,
+    min_second: Cell<u32>,
+    max_second: Cell<Option<u32>>,
+    drag: Cell<Option<(i32, i32)>>,
+}
+
+impl SplitterState {
+
+    /// Returns the closest position of the splitter that respects the panel limits.
+    /// `extent` is the size of the parent along the splitter axis and `thickness` the size of the splitter bar.
+    /// A panel smaller than its minimum size snaps to its minimum size.
+    fn clamp(&self, pos: i32, extent: i32, thickness: i32) -> i32 {
+        let mut min = self.min_first.get() as i32;
+        let mut max = extent - thickness - (self.min_second.get() as i32);
+
+        if let Some(max_first) = self.max_first.get() {
+            max = max.min(max_first as i32);
+        }
+
+        if let Some(max_second) = self.max_second.get() {
+            min = min.max(extent - thickness - (max_second as i32));
+        }
+
+        if max < min {
+            return min;
+        }
+
+        pos.max(min).min(max)
+    }
+
+}
+
+/**
+A splitter is a thin bar between two panels that can be dragged by the user to resize them. Splitter is implemented as a custom control.
+
+The splitter only moves itself. When it is dragged, it raises `OnSplitterMove` with the new position and the delta, and the application
+(or a layout) resizes the panels around it. The first panel goes from the parent origin to the splitter, the second panel goes from the
+end of the splitter to the end of the parent.
+
+Requires the `splitter` feature.
+
+**Builder parameters:**
+  * `parent`:      **Required.** The splitter parent container.
+  * `size`:        The splitter size. The thickness of the bar is the width of a vertical splitter or the height of a horizontal one.
+  * `position`:    The splitter position.
+  * `orientation`: The splitter orientation. Defaults to `SplitterOrientation::Vertical`
+  * `min_first`:   The minimum size of the first panel (left or top)
+  * `max_first`:   The maximum size of the first panel (left or top)
+  * `min_second`:  The minimum size of the second panel (right or bottom)
+  * `max_second`:  The maximum size of the second panel (right or bottom)
+  * `flags`:       A combination of the SplitterFlags values.
+  * `ex_flags`:    A combination of win32 window extended flags. Unlike `flags`, ex_flags must be used straight from winapi
+
+**Control events:**
+  * `OnSplitterMove`: When the splitter is dragged by the user. See `EventData::OnSplitterMove`
+
+```rust
+use native_windows_gui as nwg;
+
+fn build_splitter(splitter: &mut nwg::Splitter, window: &nwg::Window) {
+    nwg::Splitter::builder()
+        .orientation(nwg::SplitterOrientation::Vertical)
+        .position((200, 0))
+        .size((5, 400))
+        .min_first(100)
+        .min_second(150)
+        .parent(window)
+        .build(splitter);
+}
+
+fn resize_panels(left: &nwg::Frame, right: &nwg::Frame, data: &nwg::EventData) {
+    let (position, _delta) = data.on_splitter_move();
+    let (_, height) = left.size();
+    left.set_size(position as u32, height);
+    right.set_position(position + 5, 0);
+}
+```
+*/
+#[derive(Default)]
+pub struct Splitter {
+    pub handle: ControlHandle,
+    state: Option<Rc<SplitterState>>,
+    handler0: Option<RawEventHandler>,
+}
+
+impl Splitter {
+
+    pub fn builder() -> SplitterBuilder {
+        SplitterBuilder {
+            size: (5, 100),
+            position: (0, 0),
+            orientation: SplitterOrientation::Vertical,
+            min_first: 0,
+            max_first: None,
+            min_second: 0,
+            max_second: None,
+            flags: None,
+            ex_flags: 0,
+            parent: None,
+        }
+    }
+
+    /// Returns the orientation of the splitter
+    pub fn orientation(&self) -> SplitterOrientation {
+        self.state().orientation
+    }
+
+    /// Returns the position of the splitter along its axis. This is also the size of the first panel.
+    pub fn split_position(&self) -> i32 {
+        let (x, y) = self.position();
+        match self.state().orientation {
+            SplitterOrientation::Vertical => x,
+            SplitterOrientation::Horizontal => y,
+        }
+    }
+
+    /// Moves the splitter along its axis. The position is clamped to the panel limits.
+    /// This does not raise `OnSplitterMove`.
+    pub fn set_split_position(&self, pos: i32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { move_splitter(handle, self.state(), pos); }
+    }
+
+    /// Sets the minimum size of the first (left or top) and of the second (right or bottom) panel
+    pub fn set_min_sizes(&self, first: u32, second: u32) {
+        let state = self.state();
+        state.min_first.set(first);
+        state.min_second.set(second);
+    }
+
+    /// Returns the minimum size of the first and of the second panel
+    pub fn min_sizes(&self) -> (u32, u32) {
+        let state = self.state();
+        (state.min_first.get(), state.min_second.get())
+    }
+
+    /// Sets the maximum size of the first (left or top) and of the second (right or bottom) panel. `None` removes the limit.
+    pub fn set_max_sizes(&self, first: Option<u32>, second: Option<u32>) {
+        let state = self.state();
+        state.max_first.set(first);
+        state.max_second.set(second);
+    }
+
+    /// Returns the maximum size of the first and of the second panel
+    pub fn max_sizes(&self) -> (Option<u32>, Option<u32>) {
+        let state = self.state();
+        (state.max_first.get(), state.max_second.get())
+    }
+
+    /// Returns true if the control user can interact with the control, return false otherwise
+    pub fn enabled(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_enabled(handle) }
+    }
+
+    /// Enable or disable the control
+    pub fn set_enabled(&self, v: bool) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_enabled(handle, v) }
+    }
+
+    /// Returns true if the control is visible to the user. Will return true even if the
+    /// control is outside of the parent client view (ex: at the position (10000, 10000))
+    pub fn visible(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_visibility(handle) }
+    }
+
+    /// Show or hide the control to the user
+    pub fn set_visible(&self, v: bool) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_visibility(handle, v) }
+    }
+
+    /// Returns the size of the splitter in the parent window
+    pub fn size(&self) -> (u32, u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_size(handle) }
+    }
+
+    /// Sets the size of the splitter in the parent window
+    pub fn set_size(&self, x: u32, y: u32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_size(handle, x, y, false) }
+    }
+
+    /// Returns the position of the splitter in the parent window
+    pub fn position(&self) -> (i32, i32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_position(handle) }
+    }
+
+    /// Sets the position of the splitter in the parent window
+    pub fn set_position(&self, x: i32, y: i32) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_position(handle, x, y) }
+    }
+
+    /// Winapi class name used during control creation
+    pub fn class_name(&self) -> &'static str {
+        "NWG_SPLITTER"
+    }
+
+    /// Winapi base flags used during window creation
+    pub fn flags(&self) -> u32 {
+        WS_VISIBLE
+    }
+
+    /// Winapi flags required by the control
+    pub fn forced_flags(&self) -> u32 {
+        WS_CHILD
+    }
+
+    fn state(&self) -> &SplitterState {
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        self.state.as_ref().expect(NOT_BOUND)
+    }
+
+    /// Handles the cursor and the mouse drag
+    fn hook_drag(&mut self, state: Rc<SplitterState>) {
+        use crate::bind_raw_event_handler_inner;
+        use crate::win32::high_dpi::physical_to_logical;
+        use winapi::shared::windef::{HWND, POINT};
+        use winapi::um::winuser::{WM_SETCURSOR, WM_LBUTTONDOWN, WM_MOUSEMOVE, WM_LBUTTONUP, WM_CAPTURECHANGED, IDC_SIZEWE, IDC_SIZENS};
+        use winapi::um::winuser::{SetCursor, LoadCursorW, SetCapture, ReleaseCapture, GetCapture, GetCursorPos};
+        use std::ptr;
+
+        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+
+        unsafe fn cursor_pos(orientation: SplitterOrientation) -> i32 {
+            let mut p = POINT { x: 0, y: 0 };
+            GetCursorPos(&mut p);
+            let (x, y) = physical_to_logical(p.x, p.y);
+            match orientation {
+                SplitterOrientation::Vertical => x,
+                SplitterOrientation::Horizontal => y,
+            }
+        }
+
+        let state_handler = state.clone();
+        let handler = bind_raw_event_handler_inner(&self.handle, 0, move |hwnd: HWND, msg, _w, _l| {
+            let state = &state_handler;
+            unsafe {
+                match msg {
+                    WM_SETCURSOR => {
+                        let cursor = match state.orientation {
+                            SplitterOrientation::Vertical => IDC_SIZEWE,
+                            SplitterOrientation::Horizontal => IDC_SIZENS,
+                        };
+                        SetCursor(LoadCursorW(ptr::null_mut(), cursor));
+                        Some(1)
+                    },
+                    WM_LBUTTONDOWN => {
+                        let (x, y) = wh::get_window_position(hwnd);
+                        let pos = match state.orientation {
+                            SplitterOrientation::Vertical => x,
+                            SplitterOrientation::Horizontal => y,
+                        };
+
+                        state.drag.set(Some((cursor_pos(state.orientation), pos)));
+                        SetCapture(hwnd);
+                        Some(0)
+                    },
+                    WM_MOUSEMOVE => {
+                        if let Some((cursor_start, pos_start)) = state.drag.get() {
+                            if GetCapture() == hwnd {
+                                let target = pos_start + (cursor_pos(state.orientation) - cursor_start);
+                                let (old, new) = move_splitter(hwnd, state, target);
+                                if old != new {
+                                    wh::send_message(hwnd, wh::NWG_SPLITTER_MOVE, (new - old) as isize as _, new as isize as _);
+                                }
+                            }
+                        }
+                        Some(0)
+                    },
+                    WM_LBUTTONUP => {
+                        state.drag.set(None);
+                        ReleaseCapture();
+                        Some(0)
+                    },
+                    WM_CAPTURECHANGED => {
+                        state.drag.set(None);
+                        None
+                    },
+                    _ => None
+                }
+            }
+        });
+
+        self.handler0 = Some(handler.unwrap());
+        self.state = Some(state);
+
+        // Snap the initial position to the panel limits
+        unsafe { move_splitter(handle, self.state(), self.split_position()); }
+    }
+
+}
+
+/// Moves a splitter along its axis, respecting the panel limits. Returns the old and the new position.
+unsafe fn move_splitter(handle: winapi::shared::windef::HWND, state: &SplitterState, target: i32) -> (i32, i32) {
+    let parent = wh::get_window_parent(handle);
+    let (parent_width, parent_height) = wh::get_window_size(parent);
+    let (width, height) = wh::get_window_size(handle);
+    let (x, y) = wh::get_window_position(handle);
+
+    match state.orientation {
+        SplitterOrientation::Vertical => {
+            let new = state.clamp(target, parent_width as i32, width as i32);
+            if new != x { wh::set_window_position(handle, new, y); }
+            (x, new)
+        },
+        SplitterOrientation::Horizontal => {
+            let new = state.clamp(target, parent_height as i32, height as i32);
+            if new != y { wh::set_window_position(handle, x, new); }
+            (y, new)
+        }
+    }
+}
+
+impl PartialEq for Splitter {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+impl Drop for Splitter {
+    fn drop(&mut self) {
+        if let Some(h) = self.handler0.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
+        self.handle.destroy();
+    }
+}
+
+pub struct SplitterBuilder {
+    size: (i32, i32),
+    position: (i32, i32),
+    orientation: SplitterOrientation,
+    min_first: u32,
+    max_first: Option<u32>,
+    min_second: u32,
+    max_second: Option<u32>,
+    flags: Option<SplitterFlags>,
+    ex_flags: u32,
+    parent: Option<ControlHandle>
+}
+
+impl SplitterBuilder {
+
+    pub fn flags(mut self, flags: SplitterFlags) -> SplitterBuilder {
+        self.flags = Some(flags);
+        self
+    }
+
+    pub fn ex_flags(mut self, flags: u32) -> SplitterBuilder {
+        self.ex_flags = flags;
+        self
+    }
+
+    pub fn size(mut self, size: (i32, i32)) -> SplitterBuilder {
+        self.size = size;
+        self
+    }
+
+    pub fn position(mut self, pos: (i32, i32)) -> SplitterBuilder {
+        self.position = pos;
+        self
+    }
+
+    pub fn orientation(mut self, orientation: SplitterOrientation) -> SplitterBuilder {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn min_first(mut self, size: u32) -> SplitterBuilder {
+        self.min_first = size;
+        self
+    }
+
+    pub fn max_first(mut self, size: Option<u32>) -> SplitterBuilder {
+        self.max_first = size;
+        self
+    }
+
+    pub fn min_second(mut self, size: u32) -> SplitterBuilder {
+        self.min_second = size;
+        self
+    }
+
+    pub fn max_second(mut self, size: Option<u32>) -> SplitterBuilder {
+        self.max_second = size;
+        self
+    }
+
+    pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> SplitterBuilder {
+        self.parent = Some(p.into());
+        self
+    }
+
+    pub fn build(self, out: &mut Splitter) -> Result<(), NwgError> {
+        let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+
+        let parent = match self.parent {
+            Some(p) => Ok(p),
+            None => Err(NwgError::no_parent("Splitter"))
+        }?;
+
+        *out = Default::default();
+
+        out.handle = ControlBase::build_hwnd()
+            .class_name(out.class_name())
+            .forced_flags(out.forced_flags())
+            .flags(flags)
+            .ex_flags(self.ex_flags)
+            .size(self.size)
+            .position(self.position)
+            .parent(Some(parent))
+            .build()?;
+
+        let state = SplitterState {
+            orientation: self.orientation,
+            min_first: Cell::new(self.min_first),
+            max_first: Cell::new(self.max_first),
+            min_second: Cell::new(self.min_second),
+            max_second: Cell::new(self.max_second),
+            drag: Cell::new(None),
+        };
+
+        out.hook_drag(Rc::new(state));
+
+        Ok(())
+    }
+
+}
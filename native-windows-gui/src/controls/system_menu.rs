@@ -0,0 +1,161 @@
+use winapi::shared::windef::{HWND, HMENU};
+use winapi::um::winuser::{SC_CLOSE, SC_MINIMIZE, SC_MAXIMIZE, SC_RESTORE, SC_MOVE, SC_SIZE};
+use crate::win32::base_helper::to_utf16;
+use super::ControlHandle;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Ids of the custom items. The four low bits of a `WM_SYSCOMMAND` id are reserved by the system,
+/// and the ids must be lower than the system commands (`0xF000`) and than the menu items ids (`CUSTOM_ID_BEGIN`).
+static SYSTEM_MENU_ITEMS_ID: AtomicU32 = AtomicU32::new(0x1000);
+const SYSTEM_MENU_ITEMS_ID_STEP: u32 = 0x10;
+
+
+/// The standard items of the system menu. See `SystemMenu`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SystemMenuItem {
+    Close,
+    Minimize,
+    Maximize,
+    Restore,
+    Move,
+    Size,
+}
+
+impl SystemMenuItem {
+
+    fn command(&self) -> u32 {
+        let command = match self {
+            SystemMenuItem::Close => SC_CLOSE,
+            SystemMenuItem::Minimize => SC_MINIMIZE,
+            SystemMenuItem::Maximize => SC_MAXIMIZE,
+            SystemMenuItem::Restore => SC_RESTORE,
+            SystemMenuItem::Move => SC_MOVE,
+            SystemMenuItem::Size => SC_SIZE,
+        };
+
+        command as u32
+    }
+
+}
+
+/**
+The system menu of a window: the menu opened with Alt+Space or by clicking the icon of the title bar. Returned by `Window::system_menu`.
+The window must have the `WindowFlags::SYS_MENU` flag.
+
+The system menu is copied from the default menu of the system the first time it is modified. `reset` restores the default menu.
+
+The standard items can be disabled or removed:
+  * Disabling or removing `Close` also disables the close button (X) of the title bar, and Alt+F4
+  * Removing `Minimize`, `Maximize`, `Move` or `Size` only removes the menu item. The buttons and the borders of the window still work.
+    Use the window flags (ex: `WindowFlags::MINIMIZE_BOX`) to remove them
+
+The items added with `append` raise `OnMenuItemSelected` on the window when they are selected. The source of the event is the handle returned by `append`.
+
+```rust
+use native_windows_gui as nwg;
+
+fn add_about(window: &nwg::Window) -> nwg::ControlHandle {
+    let menu = window.system_menu();
+    menu.append_separator();
+    menu.append("&About...")
+}
+```
+*/
+pub struct SystemMenu {
+    window: HWND,
+    handle: HMENU,
+}
+
+impl SystemMenu {
+
+    pub(crate) fn new(window: HWND) -> SystemMenu {
+        use winapi::um::winuser::GetSystemMenu;
+
+        let handle = unsafe { GetSystemMenu(window, 0) };
+        SystemMenu { window, handle }
+    }
+
+    /// Returns the system menu handle
+    pub fn handle(&self) -> HMENU {
+        self.handle
+    }
+
+    /// Returns `true` if the system menu contains `item`
+    pub fn contains(&self, item: SystemMenuItem) -> bool {
+        use winapi::um::winuser::{GetMenuState, MF_BYCOMMAND};
+
+        unsafe { GetMenuState(self.handle, item.command(), MF_BYCOMMAND) != 0xFFFFFFFF }
+    }
+
+    /// Returns `true` if `item` is in the system menu and is enabled
+    pub fn enabled(&self, item: SystemMenuItem) -> bool {
+        use winapi::um::winuser::{GetMenuState, MF_BYCOMMAND, MF_GRAYED, MF_DISABLED};
+
+        let state = unsafe { GetMenuState(self.handle, item.command(), MF_BYCOMMAND) };
+        state != 0xFFFFFFFF && state & (MF_GRAYED | MF_DISABLED) == 0
+    }
+
+    /// Enables or disables `item`. See `SystemMenu` for the effect on the title bar buttons.
+    pub fn set_enabled(&self, item: SystemMenuItem, enabled: bool) {
+        use winapi::um::winuser::{EnableMenuItem, MF_BYCOMMAND, MF_ENABLED, MF_GRAYED};
+
+        let flags = match enabled {
+            true => MF_BYCOMMAND | MF_ENABLED,
+            false => MF_BYCOMMAND | MF_GRAYED
+        };
+
+        unsafe { EnableMenuItem(self.handle, item.command(), flags); }
+        self.redraw_frame();
+    }
+
+    /// Removes `item` from the system menu. See `SystemMenu` for the effect on the title bar buttons.
+    pub fn remove(&self, item: SystemMenuItem) {
+        use winapi::um::winuser::{RemoveMenu, MF_BYCOMMAND};
+
+        unsafe { RemoveMenu(self.handle, item.command(), MF_BYCOMMAND); }
+        self.redraw_frame();
+    }
+
+    /**
+        Appends a custom item at the end of the system menu. A "&" before a letter sets the mnemonic of the item.
+        Returns the handle of the item. It is the source of the `OnMenuItemSelected` event raised on the window when the item is selected.
+    */
+    pub fn append(&self, text: &str) -> ControlHandle {
+        use winapi::um::winuser::{AppendMenuW, MF_STRING};
+
+        let id = SYSTEM_MENU_ITEMS_ID.fetch_add(SYSTEM_MENU_ITEMS_ID_STEP, Ordering::SeqCst);
+        let text = to_utf16(text);
+        unsafe { AppendMenuW(self.handle, MF_STRING, id as _, text.as_ptr()); }
+
+        ControlHandle::MenuItem(self.handle, id)
+    }
+
+    /// Appends a separator at the end of the system menu
+    pub fn append_separator(&self) {
+        use winapi::um::winuser::{AppendMenuW, MF_SEPARATOR};
+        use std::ptr;
+
+        unsafe { AppendMenuW(self.handle, MF_SEPARATOR, 0, ptr::null()); }
+    }
+
+    /// Restores the default system menu. The custom items are removed and the standard items are enabled again.
+    pub fn reset(&mut self) {
+        use winapi::um::winuser::GetSystemMenu;
+
+        unsafe {
+            GetSystemMenu(self.window, 1);
+            self.handle = GetSystemMenu(self.window, 0);
+        }
+
+        self.redraw_frame();
+    }
+
+    /// The state of the close button is read from the system menu when the frame is drawn
+    fn redraw_frame(&self) {
+        use winapi::um::winuser::{SetWindowPos, SWP_FRAMECHANGED, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER, SWP_NOACTIVATE};
+        use std::ptr;
+
+        unsafe { SetWindowPos(self.window, ptr::null_mut(), 0, 0, 0, 0, SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE); }
+    }
+
+}
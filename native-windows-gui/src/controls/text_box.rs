@@ -188,6 +188,31 @@ impl TextBox {
         wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
     }
 
+    /**
+        Selects the characters between `start` and `end` and scrolls the text box so that the selection is visible.
+        This is the "find and reveal" action of a search in a log or an output view, and works on read-only text boxes.
+        The positions are character indices in the text of the control, where "\r\n" counts as two characters.
+
+        A text box only shows its selection when it has the focus, unless it was created with `ES_NOHIDESEL`.
+    */
+    pub fn highlight_range(&self, start: u32, end: u32) {
+        use winapi::um::winuser::{EM_SETSEL, EM_SCROLLCARET};
+
+        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+        wh::send_message(handle, EM_SETSEL as u32, start as usize, end as isize);
+        wh::send_message(handle, EM_SCROLLCARET as u32, 0, 0);
+    }
+
+    /// Removes the selection set by `highlight_range` (or by the user). The caret stays where the selection started.
+    pub fn clear_highlight(&self) {
+        use winapi::um::winuser::EM_SETSEL;
+
+        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+        wh::send_message(handle, EM_SETSEL as u32, usize::MAX, 0);
+    }
+
     /// Return the length of the user input in the control. Performs a newline conversion first since
     /// Windows treats "\r\n" as a single character
     pub fn len(&self) -> u32 {
@@ -325,18 +350,18 @@ impl TextBox {
         unsafe { wh::get_window_text(handle) }
     }
 
-    /// Set the text displayed in the TextInput
+    /// Set the text displayed in the TextInput. The `OnTextInput` event raised by this call reports `programmatic` as `true`.
     pub fn set_text<'a>(&self, v: &'a str) {
         if self.handle.blank() { panic!("{}", NOT_BOUND); }
         let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::set_window_text(handle, v) }
+        unsafe { wh::set_edit_text(handle, v) }
     }
 
     /// Set the text in the current control, converting unix-style newlines in the input to "\r\n"
     pub fn set_text_unix2dos<'a>(&self, v: &'a str) {
         if self.handle.blank() { panic!("{}", NOT_BOUND); }
         let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::set_window_text(handle,  &unix2dos(&v).to_string()) }
+        unsafe { wh::set_edit_text(handle, &unix2dos(&v)) }
     }
 
     /// Append text to the current control
@@ -3,7 +3,7 @@ use winapi::shared::{
     minwindef::{UINT, WPARAM, LPARAM}
 };
 use winapi::um::{
-    winuser::{WS_VISIBLE, WS_DISABLED, ES_NUMBER, ES_LEFT, ES_CENTER, ES_RIGHT, WS_TABSTOP, ES_AUTOHSCROLL},
+    winuser::{WS_VISIBLE, WS_DISABLED, ES_NUMBER, ES_LEFT, ES_CENTER, ES_RIGHT, WS_TABSTOP, ES_AUTOHSCROLL, ES_UPPERCASE, ES_LOWERCASE},
     wingdi::DeleteObject,
 };
 use crate::win32::window_helper as wh; 
@@ -28,6 +28,8 @@ bitflags! {
         * AUTO_SCROLL: The text input automatically scrolls text to the right by 10 characters when the user types a character 
                        at the end of the line. When the user presses the ENTER key, the control scrolls all text back to position zero.
         * TAB_STOP:    The text input can be selected using tab navigation
+        * UPPERCASE:   The characters typed in the text input are converted to uppercase
+        * LOWERCASE:   The characters typed in the text input are converted to lowercase
     */
     pub struct TextInputFlags: u32 {
         const VISIBLE = WS_VISIBLE;
@@ -35,9 +37,22 @@ bitflags! {
         const NUMBER = ES_NUMBER;
         const AUTO_SCROLL = ES_AUTOHSCROLL;
         const TAB_STOP = WS_TABSTOP;
+        const UPPERCASE = ES_UPPERCASE;
+        const LOWERCASE = ES_LOWERCASE;
     }
 }
 
+/// The conversion applied to the characters typed in a `TextInput`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TextInputCase {
+    /// The characters are not converted
+    Normal,
+    /// The characters are converted to uppercase (`ES_UPPERCASE`)
+    Upper,
+    /// The characters are converted to lowercase (`ES_LOWERCASE`)
+    Lower,
+}
+
 /** 
 An edit control is a rectangular control window to permit the user to enter and edit text by typing on the keyboard
 This control only allow a single line input. For block of text, use `TextBox`.
@@ -56,6 +71,8 @@ TextInput is not behind any features.
   * `limit`:            The maximum number of character that can be inserted in the control
   * `readonly`:         If the text input should allow user input or not
   * `password`:         The password character. If set to None, the textinput is a regular control.
+  * `number`:           If the text input only accepts digits
+  * `case`:             The conversion applied to the typed characters. See `TextInputCase`
   * `align`:            The alignment of the text in the text input
   * `background_color`: The color of the textinput top and bottom padding. This is not the white background under the text.
   * `focus`:            The control receive focus after being created
@@ -96,6 +113,8 @@ impl TextInput {
             ex_flags: 0,
             limit: 0,
             password: None,
+            number: false,
+            case: TextInputCase::Normal,
             align: HTextAlign::Left,
             readonly: false,
             focus: false,
@@ -136,7 +155,8 @@ impl TextInput {
     }
 
     /// Set or Remove the password character displayed by the text input.
-    /// If the input is not a password all character are re-rendered with the new character
+    /// If the input is not a password all character are re-rendered with the new character.
+    /// Passing `None` reveals the text. Unlike the other input modes, this does not require a style change.
     pub fn set_password_char(&self, c: Option<char>) {
         use winapi::um::winuser::{InvalidateRect, EM_SETPASSWORDCHAR};
 
@@ -147,6 +167,49 @@ impl TextInput {
         unsafe { InvalidateRect(handle, ::std::ptr::null(), 1); }
     }
 
+    /// Return true if the text input only accepts digits
+    pub fn number(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_style(handle) & ES_NUMBER == ES_NUMBER
+    }
+
+    /// Allow only digits to be typed in the text input. Pasted text is not filtered by the system.
+    /// The style is updated on the live control, so the current text is left as is.
+    pub fn set_number(&self, number: bool) {
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        match number {
+            true => self.handle.modify_style(ES_NUMBER, 0),
+            false => self.handle.modify_style(0, ES_NUMBER)
+        };
+    }
+
+    /// Return the conversion applied to the characters typed in the text input
+    pub fn case(&self) -> TextInputCase {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let style = wh::get_style(handle);
+        if style & ES_UPPERCASE == ES_UPPERCASE {
+            TextInputCase::Upper
+        } else if style & ES_LOWERCASE == ES_LOWERCASE {
+            TextInputCase::Lower
+        } else {
+            TextInputCase::Normal
+        }
+    }
+
+    /// Set the conversion applied to the characters typed in the text input.
+    /// The style is changed on the live control and only affects the characters typed after the call:
+    /// the current text is not converted.
+    pub fn set_case(&self, case: TextInputCase) {
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let add = match case {
+            TextInputCase::Normal => 0,
+            TextInputCase::Upper => ES_UPPERCASE,
+            TextInputCase::Lower => ES_LOWERCASE,
+        };
+
+        self.handle.modify_style(add, ES_UPPERCASE | ES_LOWERCASE);
+    }
+
     /// Return the number of maximum character allowed in this text input
     pub fn limit(&self) -> u32 {
         use winapi::um::winuser::EM_GETLIMITTEXT;
@@ -234,6 +297,29 @@ impl TextInput {
         wh::send_message(handle, EM_SETREADONLY as u32, r as WPARAM, 0);
     }
 
+    /**
+        Enables or disables the input method editor (IME) of the text input. With the IME disabled, the keys are entered as is,
+        without composition. Use it for the fields that only accept ASCII, like numeric codes, ids or passwords.
+
+        The system switches the input context when the focus moves, so the IME of the other controls is not affected
+        and works again as soon as the focus leaves the text input. Enabling the IME again restores the previous input context.
+        The input context is also restored when the control is destroyed.
+
+        The IME is disabled by removing the input context of the control (`ImmAssociateContext`). The default input context
+        is shared by all the windows of the thread, so it must not be destroyed or changed with the `Imm*` functions while
+        the IME of a control is disabled. The user can still use the IME if the application sets another input context on the control.
+    */
+    pub fn set_ime_enabled(&self, enabled: bool) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { crate::win32::ime::set_ime_enabled(handle, enabled); }
+    }
+
+    /// Returns `false` if the IME of the text input was disabled with `set_ime_enabled`
+    pub fn ime_enabled(&self) -> bool {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { crate::win32::ime::ime_enabled(handle) }
+    }
+
     /// Return true if the control currently has the keyboard focus
     pub fn focus(&self) -> bool {
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
@@ -301,10 +387,10 @@ impl TextInput {
         unsafe { wh::get_window_text(handle) }
     }
 
-    /// Set the text displayed in the TextInput
+    /// Set the text displayed in the TextInput. The `OnTextInput` event raised by this call reports `programmatic` as `true`.
     pub fn set_text<'a>(&self, v: &'a str) {
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_text(handle, v) }
+        unsafe { wh::set_edit_text(handle, v) }
     }
 
     /// Return the placeholder text displayed in the TextInput
@@ -486,6 +572,8 @@ pub struct TextInputBuilder<'a> {
     ex_flags: u32,
     limit: usize,
     password: Option<char>,
+    number: bool,
+    case: TextInputCase,
     align: HTextAlign,
     readonly: bool,
     font: Option<&'a Font>,
@@ -536,6 +624,16 @@ impl<'a> TextInputBuilder<'a> {
         self
     }
 
+    pub fn number(mut self, number: bool) -> TextInputBuilder<'a> {
+        self.number = number;
+        self
+    }
+
+    pub fn case(mut self, case: TextInputCase) -> TextInputBuilder<'a> {
+        self.case = case;
+        self
+    }
+
     pub fn align(mut self, align: HTextAlign) -> TextInputBuilder<'a> {
         self.align = align;
         self
@@ -578,6 +676,16 @@ impl<'a> TextInputBuilder<'a> {
             },
         }
 
+        if self.number {
+            flags |= ES_NUMBER;
+        }
+
+        match self.case {
+            TextInputCase::Normal => {},
+            TextInputCase::Upper => flags |= ES_UPPERCASE,
+            TextInputCase::Lower => flags |= ES_LOWERCASE,
+        }
+
         let parent = match self.parent {
             Some(p) => Ok(p),
             None => Err(NwgError::no_parent("TextInput"))
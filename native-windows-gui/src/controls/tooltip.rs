@@ -1,4 +1,5 @@
 use winapi::shared::minwindef::{UINT, LPARAM, WPARAM};
+use winapi::shared::windef::HWND;
 use winapi::um::winnt::WCHAR;
 use crate::win32::window_helper as wh;
 use crate::win32::base_helper::{check_hwnd, to_utf16, from_utf16};
@@ -9,6 +10,9 @@ use std::{mem, ptr};
 const NOT_BOUND: &'static str = "Tooltip is not yet bound to a winapi object";
 const BAD_HANDLE: &'static str = "INTERNAL ERROR: Tooltip handle is not HWND!";
 
+/// Identifier of the tool used by `show_tracking`. Registered tools use the handle of their owner as id.
+const TRACKING_TOOL_ID: usize = 1;
+
 
 /// A select of default icon to show in a tooltip
 #[derive(Copy, Clone, Debug)]
@@ -33,6 +37,10 @@ as Tooltip as a standalone toolip, but more like a manager.
 
 A tooltip can support static text using `register` and dynamic text using `register_callback`.
 
+A tooltip can also be displayed anywhere on the screen with `show_tracking`. This is useful to display values that
+follow the cursor over a custom drawn control. Unlike the other tooltips, the tracking tooltip is never shown or hidden
+by the system: the application must call `show_tracking` each time the position must change and `hide_tracking` to hide it.
+
 Tooltip requires the `tooltip` features
 
 Example:
@@ -99,7 +107,8 @@ impl Tooltip {
             ico: None,
             default_ico: None,
             register: Vec::new(),
-            register_cb: Vec::new()
+            register_cb: Vec::new(),
+            delay: None
         }
     }
 
@@ -242,6 +251,44 @@ impl Tooltip {
         wh::send_message(handle, TTM_POP, 0, 0);
     }
 
+    /// Show a tracking tooltip with `text` at the position `x`, `y`, or move it if it is already visible.
+    /// The position is in screen coordinates (in physical pixels, like the cursor position). The tooltip is displayed until `hide_tracking` is called.
+    ///
+    /// The tracking tooltip is registered as a tool the first time this is called, so it is included in `count`.
+    pub fn show_tracking(&self, x: i32, y: i32, text: &str) {
+        use winapi::um::commctrl::{TTM_ADDTOOLW, TTM_GETTOOLINFOW, TTM_UPDATETIPTEXTW, TTM_TRACKPOSITION, TTM_TRACKACTIVATE, TTTOOLINFOW};
+        use winapi::shared::minwindef::MAKELONG;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let mut text = to_utf16(text);
+
+        let mut tool = Tooltip::tracking_tool(handle);
+        let tool_ptr = &mut tool as *mut TTTOOLINFOW;
+        let registered = wh::send_message(handle, TTM_GETTOOLINFOW, 0, tool_ptr as LPARAM) != 0;
+
+        let mut tool = Tooltip::tracking_tool(handle);
+        tool.lpszText = text.as_mut_ptr();
+        let tool_ptr = &tool as *const TTTOOLINFOW;
+        match registered {
+            true => wh::send_message(handle, TTM_UPDATETIPTEXTW, 0, tool_ptr as LPARAM),
+            false => wh::send_message(handle, TTM_ADDTOOLW, 0, tool_ptr as LPARAM)
+        };
+
+        let pos = MAKELONG(x as u16, y as u16);
+        wh::send_message(handle, TTM_TRACKPOSITION, 0, pos as LPARAM);
+        wh::send_message(handle, TTM_TRACKACTIVATE, 1, tool_ptr as LPARAM);
+    }
+
+    /// Hide the tracking tooltip shown by `show_tracking`. Does nothing if it is not visible.
+    pub fn hide_tracking(&self) {
+        use winapi::um::commctrl::{TTM_TRACKACTIVATE, TTTOOLINFOW};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let tool = Tooltip::tracking_tool(handle);
+        let tool_ptr = &tool as *const TTTOOLINFOW;
+        wh::send_message(handle, TTM_TRACKACTIVATE, 0, tool_ptr as LPARAM);
+    }
+
     /// Return the number of controls registered by the tooltip
     pub fn count(&self) -> usize {
         use winapi::um::commctrl::{TTM_GETTOOLCOUNT};
@@ -272,6 +319,48 @@ impl Tooltip {
         wh::send_message(handle, TTM_GETDELAYTIME, TTDT_INITIAL as WPARAM, 0) as u16
     }
 
+    /**
+        Sets the three delays of the tooltip, in milliseconds. The delays apply to every control registered on the tooltip,
+        with static text (`register`) or with dynamic text (`register_callback`).
+
+        * `initial`: How long the pointer must stay over a control before the tooltip appears (`TTDT_INITIAL`).
+          The default is the double click time of the system (usually 500ms). Use 0 for instant tooltips.
+        * `autopop`: How long the tooltip stays visible if the pointer does not move (`TTDT_AUTOPOP`).
+          The default is ten times the initial delay.
+        * `reshow`: How long it takes for the next tooltip to appear when the pointer moves from one control to another (`TTDT_RESHOW`).
+          The default is one fifth of the initial delay.
+
+        The maximum value of each delay is 32767ms. Use `reset_delay` to restore the defaults.
+    */
+    pub fn set_delay(&self, initial: u16, autopop: u16, reshow: u16) {
+        use winapi::um::commctrl::{TTDT_INITIAL, TTDT_AUTOPOP, TTDT_RESHOW, TTM_SETDELAYTIME};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let clamp = |v: u16| v.min(i16::MAX as u16) as LPARAM;
+
+        wh::send_message(handle, TTM_SETDELAYTIME, TTDT_INITIAL as WPARAM, clamp(initial));
+        wh::send_message(handle, TTM_SETDELAYTIME, TTDT_AUTOPOP as WPARAM, clamp(autopop));
+        wh::send_message(handle, TTM_SETDELAYTIME, TTDT_RESHOW as WPARAM, clamp(reshow));
+    }
+
+    /// Return the initial, autopop and reshow delays of the tooltip in milliseconds. See `set_delay`.
+    pub fn delay(&self) -> (u16, u16, u16) {
+        use winapi::um::commctrl::{TTDT_INITIAL, TTDT_AUTOPOP, TTDT_RESHOW, TTM_GETDELAYTIME};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let get = |kind: WPARAM| wh::send_message(handle, TTM_GETDELAYTIME, kind, 0) as u16;
+
+        (get(TTDT_INITIAL), get(TTDT_AUTOPOP), get(TTDT_RESHOW))
+    }
+
+    /// Restore the default delays of the tooltip. See `set_delay`.
+    pub fn reset_delay(&self) {
+        use winapi::um::commctrl::{TTDT_AUTOMATIC, TTM_SETDELAYTIME};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, TTM_SETDELAYTIME, TTDT_AUTOMATIC as WPARAM, -1);
+    }
+
     /// Enable or disable the control
     /// Windows does not support reading the enabled state of a tooltip btw.
     pub fn set_enabled(&self, v: bool) {
@@ -373,6 +462,23 @@ impl Tooltip {
         wh::send_message(handle, TTM_DELTOOLW, 0, tool_ptr as LPARAM);   
     }
 
+    fn tracking_tool(handle: HWND) -> winapi::um::commctrl::TTTOOLINFOW {
+        use winapi::um::commctrl::{TTTOOLINFOW, TTF_TRACK, TTF_ABSOLUTE};
+        use winapi::shared::windef::RECT;
+
+        TTTOOLINFOW {
+            cbSize: mem::size_of::<TTTOOLINFOW>() as UINT,
+            uFlags: TTF_TRACK | TTF_ABSOLUTE,
+            hwnd: handle,
+            uId: TRACKING_TOOL_ID,
+            rect: RECT { left: 0, top: 0, right: 0, bottom: 0 },
+            hinst: ptr::null_mut(),
+            lpszText: ptr::null_mut(),
+            lParam: 0,
+            lpReserved: ptr::null_mut()
+        }
+    }
+
     /// Winapi class name used during control creation
     pub fn class_name(&self) -> &'static str {
         winapi::um::commctrl::TOOLTIPS_CLASS
@@ -404,6 +510,7 @@ pub struct TooltipBuilder<'a> {
     default_ico: Option<TooltipIcon>,
     register: Vec<(ControlHandle, &'a str)>,
     register_cb: Vec<ControlHandle>,
+    delay: Option<(u16, u16, u16)>,
 }
 
 impl<'a> TooltipBuilder<'a> {
@@ -418,6 +525,11 @@ impl<'a> TooltipBuilder<'a> {
         self
     }
 
+    pub fn delay(mut self, initial: u16, autopop: u16, reshow: u16) -> TooltipBuilder<'a> {
+        self.delay = Some((initial, autopop, reshow));
+        self
+    }
+
     pub fn decoration(mut self, title: Option<&'a str>, ico: Option<&'a Icon>) -> TooltipBuilder<'a> {
         self.title = title;
         self.ico = ico;
@@ -448,6 +560,10 @@ impl<'a> TooltipBuilder<'a> {
             }
         }
         
+        if let Some((initial, autopop, reshow)) = self.delay {
+            tooltip.set_delay(initial, autopop, reshow);
+        }
+
         for (handle, text) in self.register {
             tooltip.register(&handle, text);
         }
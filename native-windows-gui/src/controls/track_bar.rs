@@ -1,5 +1,5 @@
 use winapi::shared::{
-    windef::HBRUSH,
+    windef::{HBRUSH, HWND},
     minwindef::{WPARAM, LPARAM}
 };
 use winapi::um::{
@@ -190,6 +190,44 @@ impl TrackBar {
         wh::send_message(handle, TBM_GETTIC, index as WPARAM, 0) as usize
     }
 
+    /**
+        Sets the buddy windows of the trackbar. Buddies are usually labels that display the minimum and the maximum of the range.
+        The trackbar moves a buddy next to its end when the buddy is set and every time the trackbar is moved or resized.
+
+        `left` is placed on the left of an horizontal trackbar or on top of a vertical trackbar.
+        `right` is placed on the right of an horizontal trackbar or at the bottom of a vertical trackbar.
+
+        The buddies are not resized, so their size must fit their content. Use `None` to keep the current buddy of a side.
+    */
+    pub fn set_buddy(&self, left: Option<&ControlHandle>, right: Option<&ControlHandle>) {
+        use winapi::um::commctrl::TBM_SETBUDDY;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let buddies = [(1, left), (0, right)];
+        for &(side, buddy) in buddies.iter() {
+            if let Some(buddy) = buddy {
+                let buddy = buddy.hwnd().expect("Cannot set a buddy that is not window-like");
+                wh::send_message(handle, TBM_SETBUDDY, side, buddy as LPARAM);
+            }
+        }
+    }
+
+    /// Returns the buddy windows of the trackbar set with `set_buddy`, as `(left, right)`
+    pub fn buddy(&self) -> (Option<ControlHandle>, Option<ControlHandle>) {
+        use winapi::um::commctrl::TBM_GETBUDDY;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let buddy = |side: WPARAM| {
+            let buddy = wh::send_message(handle, TBM_GETBUDDY, side, 0) as HWND;
+            match buddy.is_null() {
+                true => None,
+                false => Some(ControlHandle::Hwnd(buddy))
+            }
+        };
+
+        (buddy(1), buddy(0))
+    }
+
     //
     // Basic methods
     //
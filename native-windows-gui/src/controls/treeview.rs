@@ -4,12 +4,12 @@ A tree-view control is a window that displays a hierarchical list of items
 
 use winapi::shared::minwindef::{WPARAM, LPARAM};
 use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, WS_TABSTOP};
-use winapi::um::commctrl::{HTREEITEM, TVIS_EXPANDED, TVIS_SELECTED, TVS_SHOWSELALWAYS, TVITEMW};
+use winapi::um::commctrl::{HTREEITEM, TVIS_EXPANDED, TVIS_SELECTED, TVIS_BOLD, TVS_SHOWSELALWAYS, TVITEMW};
 use crate::win32::window_helper as wh;
 use crate::win32::base_helper::{check_hwnd, to_utf16, from_utf16};
-use crate::{Font, NwgError};
+use crate::{Font, NwgError, RawEventHandler, unbind_raw_event_handler};
 use super::{ControlBase, ControlHandle};
-use std::{mem, ptr};
+use std::{mem, ptr, rc::Rc, cell::RefCell, collections::HashMap};
 
 #[cfg(feature="image-list")]
 use winapi::um::commctrl::HIMAGELIST;
@@ -48,6 +48,7 @@ bitflags! {
     pub struct TreeItemState: u32 {
         const SELECTED = TVIS_SELECTED;
         const EXPANDED = TVIS_EXPANDED;
+        const BOLD = TVIS_BOLD;
     }
 }
 
@@ -134,17 +135,20 @@ Requires the `tree-view` feature
   * `OnMouseWheel`: Generic mouse wheel event
   * `OnTreeViewClick`: When the user has clicked the left mouse button within the control.
   * `OnTreeViewDoubleClick`: When the user has clicked the left mouse button within the control twice rapidly.
-  * `OnTreeViewRightClick`: When the user has clicked the right mouse button within the control.
+  * `OnTreeViewRightClick`: When the user has clicked the right mouse button within the control. Sends a `EventData::OnTreeViewRightClick`.
   * `OnTreeFocusLost`: When the control has lost the input focus
   * `OnTreeFocus`: When the control has acquired the input focus
   * `OnTreeItemDelete`: Just before an item is deleted. Also sent for all the children.
+  * `OnTreeItemExpanding`: Before an item is expanded or collapsed. Sends a `EventData::OnTreeItemExpanding` that can cancel the operation.
   * `OnTreeItemExpanded`: After an item was expanded or collapsed. Sends a `EventData::OnTreeItemUpdate`.
   * `OnTreeItemChanged`: After the state of an item was changed. Sends a `EventData::OnTreeItemUpdate`.
   * `OnTreeItemSelectionChanged`: After the current selection was changed. Sends a `EventData::OnTreeItemChanged`.
 */
-#[derive(Default, PartialEq, Eq)]
+#[derive(Default)]
 pub struct TreeView {
-    pub handle: ControlHandle
+    pub handle: ControlHandle,
+    item_colors: Rc<RefCell<HashMap<usize, [u8; 3]>>>,
+    handler0: RefCell<Option<RawEventHandler>>,
 } 
 
 
@@ -450,18 +454,30 @@ impl TreeView {
         wh::send_message(handle, TVM_DELETEITEM, 0, item.handle as LPARAM);
     }
 
-    /// Selects the specified tree-view item and scrolls the item into view.
+    /**
+        Selects the specified tree-view item, makes it the caret item and scrolls the item into view.
+        The parent items are expanded if needed.
+
+        Like a selection made by the user, this raises `OnTreeItemSelectionChanged` if the selection changes.
+    */
     pub fn select_item(&self, item: &TreeItem) {
-        use winapi::um::commctrl::{TVM_SETITEMW, TVIF_STATE};
+        use winapi::um::commctrl::{TVM_SELECTITEM, TVGN_CARET};
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let mut tree_item = blank_item();
-        tree_item.mask = TVIF_STATE;
-        tree_item.hItem = item.handle;
-        tree_item.state = TVIS_SELECTED;
-        tree_item.stateMask = TVIS_SELECTED;
+        wh::send_message(handle, TVM_SELECTITEM, TVGN_CARET, item.handle as LPARAM);
+    }
 
-        wh::send_message(handle, TVM_SETITEMW, 0, &mut tree_item as *mut TVITEMW as LPARAM);
+    /// Returns the caret item of the treeview: the item that has the focus and that is moved by the arrow keys.
+    pub fn caret_item(&self) -> Option<TreeItem> {
+        use winapi::um::commctrl::{TVM_GETNEXTITEM, TVGN_CARET};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let tree_handle = wh::send_message(handle, TVM_GETNEXTITEM, TVGN_CARET, 0) as HTREEITEM;
+        if tree_handle.is_null() {
+            None
+        } else {
+            Some(TreeItem { handle: tree_handle })
+        }
     }
 
     /// Unselects an item from the treeview
@@ -605,6 +621,78 @@ impl TreeView {
         wh::send_message(handle, TVM_EXPAND, state as WPARAM, item.handle as LPARAM);
     }
 
+    /**
+        Sets the text color of a single item. `None` restores the text color of the tree view.
+
+        The colors are applied by handling the `NM_CUSTOMDRAW` notification of the tree view. Custom draw is enabled
+        the first time this method is called and stays enabled until the tree view is dropped. Every visible item
+        is looked up when the tree is painted, which may slow down the painting of huge trees.
+    */
+    pub fn set_item_color(&self, item: &TreeItem, color: Option<[u8; 3]>) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        match color {
+            Some(c) => {
+                self.hook_custom_draw();
+                self.item_colors.borrow_mut().insert(item.handle as usize, c);
+            },
+            None => {
+                self.item_colors.borrow_mut().remove(&(item.handle as usize));
+            }
+        }
+
+        redraw_item(handle, item);
+    }
+
+    /// Returns the text color of an item set with `set_item_color`
+    pub fn item_color(&self, item: &TreeItem) -> Option<[u8; 3]> {
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        self.item_colors.borrow().get(&(item.handle as usize)).copied()
+    }
+
+    /// Displays the text of an item in bold. Unlike `set_item_color`, this uses the `TVIS_BOLD` item state and does not require custom draw.
+    pub fn set_item_bold(&self, item: &TreeItem, bold: bool) {
+        use winapi::um::commctrl::{TVM_SETITEMW, TVIF_STATE, TVIF_HANDLE};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let mut tv_item = blank_item();
+        tv_item.mask = TVIF_STATE | TVIF_HANDLE;
+        tv_item.hItem = item.handle;
+        tv_item.state = match bold { true => TVIS_BOLD, false => 0 };
+        tv_item.stateMask = TVIS_BOLD;
+
+        wh::send_message(handle, TVM_SETITEMW, 0, &mut tv_item as *mut TVITEMW as LPARAM);
+        redraw_item(handle, item);
+    }
+
+    /// Returns `true` if the text of an item is displayed in bold
+    pub fn item_bold(&self, item: &TreeItem) -> bool {
+        self.item_state(item)
+            .map(|state| state.contains(TreeItemState::BOLD))
+            .unwrap_or(false)
+    }
+
+    /// Returns the item at the position `(x, y)`, in client coordinates of the treeview, in physical pixels.
+    /// Returns `None` if there is no item at this position. Only the label and the icon of an item count as the item.
+    ///
+    /// The position sent with `OnTreeViewRightClick` can be used directly. To map a screen position
+    /// (ex: `GlobalCursor::position`), use `GlobalCursor::local_position`.
+    pub fn hit_test(&self, x: i32, y: i32) -> Option<TreeItem> {
+        use winapi::um::commctrl::{TVM_HITTEST, TVHITTESTINFO, TVHT_ONITEM};
+        use winapi::shared::windef::POINT;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let mut info = TVHITTESTINFO { pt: POINT { x, y }, flags: 0, hItem: ptr::null_mut() };
+        wh::send_message(handle, TVM_HITTEST, 0, &mut info as *mut TVHITTESTINFO as LPARAM);
+
+        match info.hItem.is_null() || info.flags & TVHT_ONITEM == 0 {
+            true => None,
+            false => Some(TreeItem { handle: info.hItem })
+        }
+    }
+
     /// Ensures that a tree-view item is visible, expanding the parent item or scrolling the tree-view control, if necessary.
     pub fn ensure_visible(&self, item: &TreeItem) {
         use winapi::um::commctrl::{TVM_ENSUREVISIBLE};
@@ -613,12 +701,24 @@ impl TreeView {
         wh::send_message(handle, TVM_ENSUREVISIBLE, 0, item.handle as LPARAM);
     }
 
+    /**
+        Scrolls the treeview so that `item` is the first visible item, if possible. The item is not selected.
+        Use `ensure_visible` to scroll as little as possible instead.
+    */
+    pub fn set_first_visible(&self, item: &TreeItem) {
+        use winapi::um::commctrl::{TVM_SELECTITEM, TVGN_FIRSTVISIBLE};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::send_message(handle, TVM_SELECTITEM, TVGN_FIRSTVISIBLE, item.handle as LPARAM);
+    }
+
     /// Remove every item from the treeview by removing the root item
     pub fn clear(&self) {
         use winapi::um::commctrl::{TVM_DELETEITEM, TVI_ROOT};
 
         let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
         wh::send_message(handle, TVM_DELETEITEM, 0, TVI_ROOT  as LPARAM);
+        self.item_colors.borrow_mut().clear();
     }
 
     /// Return the total number of item in the tree view
@@ -778,8 +878,80 @@ impl TreeView {
     }
 }
 
+impl TreeView {
+
+    /// Applies the item colors in `NM_CUSTOMDRAW` and forgets the colors of the deleted items
+    fn hook_custom_draw(&self) {
+        use crate::bind_raw_event_handler_inner;
+        use winapi::shared::{basetsd::UINT_PTR, minwindef::LRESULT};
+        use winapi::um::winuser::{WM_NOTIFY, NMHDR};
+        use winapi::um::wingdi::RGB;
+        use winapi::um::commctrl::{NM_CUSTOMDRAW, NMTVCUSTOMDRAW, NMTREEVIEWW, TVN_DELETEITEMW, CDDS_PREPAINT, CDDS_ITEMPREPAINT,
+          CDRF_NOTIFYITEMDRAW, CDRF_DODEFAULT};
+
+        if self.handler0.borrow().is_some() {
+            return;
+        }
+
+        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+        let parent_handle = ControlHandle::Hwnd(wh::get_window_parent(handle));
+        let colors = self.item_colors.clone();
+
+        let handler = bind_raw_event_handler_inner(&parent_handle, handle as UINT_PTR, move |_hwnd, msg, _w, l| {
+            if msg != WM_NOTIFY {
+                return None;
+            }
+
+            unsafe {
+                let header = &*(l as *const NMHDR);
+                if header.hwndFrom != handle {
+                    return None;
+                }
+
+                match header.code {
+                    NM_CUSTOMDRAW => {
+                        let draw = &mut *(l as *mut NMTVCUSTOMDRAW);
+                        match draw.nmcd.dwDrawStage {
+                            CDDS_PREPAINT => Some(CDRF_NOTIFYITEMDRAW as LRESULT),
+                            CDDS_ITEMPREPAINT => {
+                                if let Some(c) = colors.borrow().get(&draw.nmcd.dwItemSpec) {
+                                    draw.clrText = RGB(c[0], c[1], c[2]);
+                                }
+                                Some(CDRF_DODEFAULT as LRESULT)
+                            },
+                            _ => Some(CDRF_DODEFAULT as LRESULT)
+                        }
+                    },
+                    TVN_DELETEITEMW => {
+                        let data = &*(l as *const NMTREEVIEWW);
+                        colors.borrow_mut().remove(&(data.itemOld.hItem as usize));
+                        None
+                    },
+                    _ => None
+                }
+            }
+        });
+
+        *self.handler0.borrow_mut() = Some(handler.unwrap());
+    }
+
+}
+
+impl PartialEq for TreeView {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+impl Eq for TreeView {}
+
 impl Drop for TreeView {
     fn drop(&mut self) {
+        let handler = self.handler0.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
         self.handle.destroy();
     }
 }
@@ -923,6 +1095,23 @@ fn builder_set_image_list(builder: &TreeViewBuilder, out: &TreeView) {
 fn builder_set_image_list(_builder: &TreeViewBuilder, _out: &TreeView) {
 }
 
+/// Repaints the row of a single item
+fn redraw_item(handle: winapi::shared::windef::HWND, item: &TreeItem) {
+    use winapi::um::commctrl::TVM_GETITEMRECT;
+    use winapi::um::winuser::InvalidateRect;
+    use winapi::shared::windef::RECT;
+
+    unsafe {
+        // TVM_GETITEMRECT reads the item handle from the rect
+        let mut r: RECT = mem::zeroed();
+        *(&mut r as *mut RECT as *mut HTREEITEM) = item.handle;
+
+        if wh::send_message(handle, TVM_GETITEMRECT, 0, &mut r as *mut RECT as LPARAM) != 0 {
+            InvalidateRect(handle, &r, 1);
+        }
+    }
+}
+
 fn blank_item() -> TVITEMW {
     TVITEMW {
         mask: 0,
@@ -1,10 +1,15 @@
+use winapi::shared::windef::HWND;
+use winapi::um::winuser::HDEVNOTIFY;
 use winapi::um::winuser::{WS_OVERLAPPEDWINDOW, WS_CLIPCHILDREN, WS_VISIBLE, WS_DISABLED, WS_MAXIMIZE, WS_MINIMIZE, WS_CAPTION,
 WS_MINIMIZEBOX, WS_MAXIMIZEBOX, WS_SYSMENU, WS_THICKFRAME, WS_POPUP, WS_EX_TOPMOST, WS_EX_ACCEPTFILES};
 
 use crate::win32::window_helper as wh;
 use crate::win32::base_helper::check_hwnd;
-use crate::{NwgError, Icon};
+use crate::{NwgError, Icon, Region};
 use super::{ControlBase, ControlHandle};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 const NOT_BOUND: &'static str = "Window is not yet bound to a winapi object";
 const BAD_HANDLE: &'static str = "INTERNAL ERROR: Window handle is not HWND!";
@@ -44,6 +49,91 @@ bitflags! {
 }
 
 
+/**
+    The target of `Window::snap`. The halves and the quarters are computed from the work area of the monitor displaying the window.
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SnapEdge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Maximize,
+    Restore,
+}
+
+/**
+    The rounded corner policy of `Window::set_corner_preference`. Each value maps to a `DWM_WINDOW_CORNER_PREFERENCE` value:
+
+    * `Default`: `DWMWCP_DEFAULT`. Let the system decide
+    * `DoNotRound`: `DWMWCP_DONOTROUND`. Never round the corners
+    * `Round`: `DWMWCP_ROUND`. Round the corners if appropriate
+    * `RoundSmall`: `DWMWCP_ROUNDSMALL`. Round the corners if appropriate, with a small radius
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum CornerPreference {
+    Default = 0,
+    DoNotRound = 1,
+    Round = 2,
+    RoundSmall = 3,
+}
+
+/**
+    The system backdrop material of `Window::set_backdrop`. Each value maps to a `DWM_SYSTEMBACKDROP_TYPE` value:
+
+    * `Auto`: `DWMSBT_AUTO`. Let the system decide (usually no backdrop)
+    * `None`: `DWMSBT_NONE`. No backdrop material
+    * `Mica`: `DWMSBT_MAINWINDOW`. The Mica material, tinted with the desktop wallpaper. For long lived main windows
+    * `Acrylic`: `DWMSBT_TRANSIENTWINDOW`. The Acrylic material, a blur of what is behind the window. For transient windows (ex: popups)
+    * `Tabbed`: `DWMSBT_TABBEDWINDOW`. A Mica variant with a stronger tint. For windows with tabs in the title bar
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum BackdropType {
+    Auto = 0,
+    None = 1,
+    Mica = 2,
+    Acrylic = 3,
+    Tabbed = 4,
+}
+
+/**
+    Where the content of a window can be displayed. See `Window::set_display_affinity`.
+
+    * `None`: `WDA_NONE`. The window is displayed everywhere, including screenshots, screen recordings and screen sharing. This is the default.
+    * `Monitor`: `WDA_MONITOR`. The window is only displayed on a monitor. Screen captures show the window as a black rectangle.
+    * `ExcludeFromCapture`: `WDA_EXCLUDEFROMCAPTURE`. The window is only displayed on a monitor and is removed from the screen captures,
+      which show what is behind the window instead. Requires Windows 10 (build 19041). Older versions of Windows use `Monitor` instead.
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum DisplayAffinity {
+    None = 0,
+    Monitor = 1,
+    ExcludeFromCapture = 0x11,
+}
+
+/**
+    How a window handles the resize events while the user drags its border. See `Window::set_resize_mode`.
+
+    * `Live`: `OnResize` (and the layouts) are updated continuously during the drag. This is the default.
+    * `Deferred`: The resize events are ignored during the drag. The final size is applied once when the user releases the border.
+    * `DeferredPlaceholder`: Same as `Deferred`, but the children of the window are hidden during the drag
+      so that only the background of the window is painted.
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum ResizeMode {
+    #[default]
+    Live,
+    Deferred,
+    DeferredPlaceholder,
+}
+
 /**
     A basic top level window. At least one top level window is required to make a NWG application.
 
@@ -63,6 +153,7 @@ bitflags! {
       * `center`:      Center the window in the current monitor based on its size. If `true`, this overrides `position`
       * `topmost`:     If the window should always be on top of other system window
       * `parent`:      Logical parent of the window, unlike children controls, this is NOT required.
+      * `background_color`: The color of the window client area. See `Window::set_background_color`
 
     **Control events:**
       * `OnInit`: The window was created
@@ -80,11 +171,36 @@ bitflags! {
       * `OnMove`: When the window is moved by the user
       * `OnFileDrop`: When a file is dropped in the window (only raised if accept_file is set)
       * `OnMinMaxInfo`: When the size or position of the window is about to change and the size of the windows must be restricted
+      * `OnDeviceChange`: When a device or a volume is added or removed. See `set_device_notifications`
+      * `OnCopyData`: When the window receives data from `send_copydata` or another `WM_COPYDATA` sender
 
 */
-#[derive(Default, PartialEq, Eq)]
+#[derive(Default)]
 pub struct Window {
-    pub handle: ControlHandle
+    pub handle: ControlHandle,
+    background_color: Cell<Option<[u8; 3]>>,
+    fade: Rc<RefCell<Option<FadeState>>>,
+    device_notify: Cell<Option<HDEVNOTIFY>>,
+    resize_mode: Cell<ResizeMode>,
+    minimize_to_tray: Cell<Option<HWND>>,
+    dialog_buttons: Rc<Cell<DialogButtons>>,
+}
+
+/// The default and the cancel buttons of a window. See `set_default_button` and `set_cancel_button`
+#[derive(Default, Clone, Copy)]
+struct DialogButtons {
+    default: Option<HWND>,
+    cancel: Option<HWND>,
+}
+
+/// State of a running `fade_in` / `fade_out` animation
+struct FadeState {
+    start: Instant,
+    duration: Duration,
+    from: u8,
+    to: u8,
+    hide: bool,
+    done: Option<Box<dyn FnOnce()>>,
 }
 
 impl Window {
@@ -102,6 +218,7 @@ impl Window {
             flags: None,
             ex_flags: 0,
             icon: None,
+            background_color: None,
             parent: None
         }
     }
@@ -124,6 +241,238 @@ impl Window {
         wh::restore_window(handle);
     }
 
+    /**
+        Returns the rectangle of the window when it is restored, as `[left, top, right, bottom]` in logical pixels.
+        While the window is maximized or minimized, `position` and `size` return the current rectangle, but this method
+        still returns the rectangle that `restore` will give back. Save this value to remember the geometry of a window across sessions.
+
+        The rectangle is read from `WINDOWPLACEMENT.rcNormalPosition`, so it is in workspace coordinates: they are the screen coordinates
+        moved by the taskbar if the taskbar is docked at the top or on the left of the primary monitor. Only use it with `set_normal_rect`.
+    */
+    pub fn normal_rect(&self) -> [i32; 4] {
+        use winapi::um::winuser::{WINDOWPLACEMENT, GetWindowPlacement};
+        use crate::win32::high_dpi::physical_to_logical;
+        use std::mem;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe {
+            let mut placement: WINDOWPLACEMENT = mem::zeroed();
+            placement.length = mem::size_of::<WINDOWPLACEMENT>() as u32;
+            GetWindowPlacement(handle, &mut placement);
+
+            let r = placement.rcNormalPosition;
+            let (left, top) = physical_to_logical(r.left, r.top);
+            let (right, bottom) = physical_to_logical(r.right, r.bottom);
+            [left, top, right, bottom]
+        }
+    }
+
+    /**
+        Sets the rectangle of the window when it is restored, as `[left, top, right, bottom]` in logical pixels and in workspace coordinates.
+        See `normal_rect`.
+
+        If the window is maximized or minimized, it keeps its current state and gets the rectangle when it is restored.
+        Otherwise, the window is moved and resized immediately.
+    */
+    pub fn set_normal_rect(&self, rect: [i32; 4]) {
+        use winapi::um::winuser::{WINDOWPLACEMENT, GetWindowPlacement, SetWindowPlacement};
+        use winapi::shared::windef::RECT;
+        use crate::win32::high_dpi::logical_to_physical;
+        use std::mem;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe {
+            let mut placement: WINDOWPLACEMENT = mem::zeroed();
+            placement.length = mem::size_of::<WINDOWPLACEMENT>() as u32;
+            GetWindowPlacement(handle, &mut placement);
+
+            let [left, top, right, bottom] = rect;
+            let (left, top) = logical_to_physical(left, top);
+            let (right, bottom) = logical_to_physical(right, bottom);
+            placement.rcNormalPosition = RECT { left, top, right, bottom };
+
+            SetWindowPlacement(handle, &placement);
+        }
+    }
+
+    /**
+        Move and resize the window to a half or a quarter of the work area of its current monitor, 
+        replicating the Win+Arrow keys behaviour. This is mostly useful for windows with a custom title bar.
+        `SnapEdge::Maximize` and `SnapEdge::Restore` behave like `maximize` and `restore`.
+
+        A maximized window is restored before being snapped. 
+        The snapped rectangles are computed from the monitor work area in physical pixels and applied as is, 
+        so they are not affected by the logical/physical conversion done by `set_position` and `set_size`.
+    */
+    pub fn snap(&self, edge: SnapEdge) {
+        use winapi::um::winuser::{SetWindowPos, IsZoomed, SWP_NOZORDER, SWP_NOACTIVATE, SWP_NOOWNERZORDER};
+        use std::ptr;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        match edge {
+            SnapEdge::Maximize => { return wh::maximize_window(handle); },
+            SnapEdge::Restore => { return wh::restore_window(handle); },
+            _ => {}
+        }
+
+        unsafe {
+            if IsZoomed(handle) != 0 {
+                wh::restore_window(handle);
+            }
+        }
+
+        let [left, top, right, bottom] = crate::Monitor::work_rect_from_window(self);
+        let (half_w, half_h) = ((right - left) / 2, (bottom - top) / 2);
+        let (w, h) = (right - left, bottom - top);
+
+        let (x, y, width, height) = match edge {
+            SnapEdge::Left => (left, top, half_w, h),
+            SnapEdge::Right => (left + half_w, top, w - half_w, h),
+            SnapEdge::Top => (left, top, w, half_h),
+            SnapEdge::Bottom => (left, top + half_h, w, h - half_h),
+            SnapEdge::TopLeft => (left, top, half_w, half_h),
+            SnapEdge::TopRight => (left + half_w, top, w - half_w, half_h),
+            SnapEdge::BottomLeft => (left, top + half_h, half_w, h - half_h),
+            SnapEdge::BottomRight => (left + half_w, top + half_h, w - half_w, h - half_h),
+            SnapEdge::Maximize | SnapEdge::Restore => unreachable!(),
+        };
+
+        unsafe {
+            SetWindowPos(handle, ptr::null_mut(), x, y, width, height, SWP_NOZORDER|SWP_NOACTIVATE|SWP_NOOWNERZORDER);
+        }
+    }
+
+    /**
+        Show the window and animate its opacity from transparent to opaque over `duration`. 
+        `done` is called once the window is fully opaque.
+
+        See `fade_out` for the details of the animation.
+    */
+    pub fn fade_in<F: FnOnce() + 'static>(&self, duration: Duration, done: F) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        unsafe {
+            let from = match wh::get_window_visibility(handle) {
+                true => wh::get_window_alpha(handle),
+                false => 0
+            };
+
+            wh::set_window_alpha(handle, from);
+            wh::set_window_visibility(handle, true);
+            self.start_fade(handle, duration, from, 255, false, Box::new(done));
+        }
+    }
+
+    /**
+        Animate the opacity of the window from its current value to transparent over `duration` and then hide it. 
+        The opacity is reset to opaque after the window is hidden, and then `done` is called.
+
+        The opacity is set with `SetLayeredWindowAttributes`, which requires the `WS_EX_LAYERED` extended style.
+        The style is added to the window if it's missing. The animation is driven by `WM_TIMER` messages, 
+        so it runs on the UI thread and only progresses while the events loop is running.
+
+        If a new fade is requested while another one is running, the new fade replaces the old one and starts from the current opacity. 
+        The completion callback of the old fade is dropped without being called.
+    */
+    pub fn fade_out<F: FnOnce() + 'static>(&self, duration: Duration, done: F) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        unsafe {
+            let from = wh::get_window_alpha(handle);
+            wh::ensure_layered(handle);
+            self.start_fade(handle, duration, from, 0, true, Box::new(done));
+        }
+    }
+
+    unsafe fn start_fade(&self, handle: HWND, duration: Duration, from: u8, to: u8, hide: bool, done: Box<dyn FnOnce()>) {
+        use crate::{bind_raw_event_handler_inner, has_raw_handler};
+        use crate::win32::window_helper::FADE_HANDLER_ID;
+        use winapi::um::winuser::{SetTimer, KillTimer, WM_TIMER};
+        use winapi::shared::basetsd::UINT_PTR;
+
+        const FADE_INTERVAL: u32 = 15;
+
+        *self.fade.borrow_mut() = Some(FadeState { start: Instant::now(), duration, from, to, hide, done: Some(done) });
+
+        if !has_raw_handler(&self.handle, FADE_HANDLER_ID) {
+            let fade = self.fade.clone();
+            let handler = bind_raw_event_handler_inner(&self.handle, FADE_HANDLER_ID, move |hwnd, msg, w, _l| {
+                if msg != WM_TIMER || w != FADE_HANDLER_ID {
+                    return None;
+                }
+
+                unsafe {
+                    let mut state_ref = fade.borrow_mut();
+                    let (finished, hide, done) = match state_ref.as_mut() {
+                        Some(state) => {
+                            let elapsed = state.start.elapsed();
+                            let progress = match state.duration.as_millis() {
+                                0 => 1.0,
+                                d => (elapsed.as_millis() as f64 / d as f64).min(1.0)
+                            };
+
+                            let alpha = state.from as f64 + ((state.to as f64 - state.from as f64) * progress);
+                            wh::set_window_alpha(hwnd, alpha.round() as u8);
+
+                            let finished = progress >= 1.0;
+                            let done = match finished {
+                                true => state.done.take(),
+                                false => None
+                            };
+
+                            (finished, state.hide, done)
+                        },
+                        None => (true, false, None)
+                    };
+
+                    if finished {
+                        *state_ref = None;
+                        drop(state_ref);
+
+                        KillTimer(hwnd, FADE_HANDLER_ID as UINT_PTR);
+                        if hide {
+                            wh::set_window_visibility(hwnd, false);
+                            wh::set_window_alpha(hwnd, 255);
+                        }
+
+                        // Called last because the callback might start a new fade
+                        if let Some(done) = done {
+                            done();
+                        }
+                    }
+
+                    Some(0)
+                }
+            });
+
+            // The handler is freed when the window is dropped
+            drop(handler);
+        }
+
+        SetTimer(handle, FADE_HANDLER_ID as UINT_PTR, FADE_INTERVAL, None);
+    }
+
+    /**
+        Makes all the pixels of the window that have the color `key` fully transparent. The mouse clicks on these pixels go
+        through the window. This is a cheap way to make irregular (skinned) windows without using `set_region`.
+        Pass `None` to remove the color key.
+
+        The color key is set with `SetLayeredWindowAttributes`, which requires the `WS_EX_LAYERED` extended style.
+        The style is added to the window if it's missing. The color key and the opacity (see `fade_in` and `fade_out`) can be combined:
+        the pixels of the key color are transparent and the other pixels use the opacity of the window.
+    */
+    pub fn set_transparency_key(&self, key: Option<[u8; 3]>) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::set_window_color_key(handle, key); }
+    }
+
+    /// Returns the transparency color key of the window, or `None` if the window has none. See `set_transparency_key`.
+    pub fn transparency_key(&self) -> Option<[u8; 3]> {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { wh::get_window_color_key(handle) }
+    }
+
     /// Force the window to refraw iteself and all its children
     pub fn invalidate(&self) {
         use winapi::um::winuser::InvalidateRect;
@@ -140,6 +489,20 @@ impl Window {
         wh::post_message(handle, WM_CLOSE, 0, 0);
     }
 
+    /**
+        Close the window after a close was confirmed by the application. The `OnWindowClose` event is raised
+        with `WindowCloseData::confirmed` returning `true` and the handler cannot veto it.
+
+        Use this to finish a close that was vetoed in `OnWindowClose` while waiting for an async confirmation.
+        The message is posted, so this can safely be called from inside the `OnWindowClose` handler.
+    */
+    pub fn request_close(&self) {
+        use winapi::um::winuser::WM_CLOSE;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::post_message(handle, WM_CLOSE, wh::NWG_CONFIRMED_CLOSE, 0);
+    }
+
     /// Return the icon of the window
     pub fn icon(&self) -> Option<Icon> {
         use winapi::um::winuser::WM_GETICON;
@@ -241,6 +604,779 @@ impl Window {
         unsafe { wh::set_window_text(handle, v) }
     }
 
+    /**
+        Paint the client area of the window with a solid color.
+
+        All NWG windows share the same window class, so the class background brush (`hbrBackground`, set once at registration)
+        cannot be changed without changing the background of every window. Instead, this method hooks `WM_ERASEBKGND`
+        on this window only and fills the client area with its own brush. The brush is recreated when the system theme changes
+        (`WM_THEMECHANGED`) and freed when the background is cleared or the window is dropped.
+
+        Children controls are not affected.
+    */
+    pub fn set_background_color(&self, color: [u8; 3]) {
+        use crate::bind_raw_event_handler_inner;
+        use crate::win32::window_helper::BACKGROUND_HANDLER_ID;
+        use winapi::shared::windef::{HDC, HBRUSH, RECT};
+        use winapi::um::winuser::{WM_ERASEBKGND, WM_THEMECHANGED, GetClientRect, FillRect};
+        use winapi::um::wingdi::{CreateSolidBrush, DeleteObject, RGB};
+        use std::mem;
+
+        struct BackgroundBrush {
+            color: [u8; 3],
+            brush: Cell<HBRUSH>,
+        }
+
+        impl BackgroundBrush {
+            fn create(color: [u8; 3]) -> HBRUSH {
+                unsafe { CreateSolidBrush(RGB(color[0], color[1], color[2])) }
+            }
+        }
+
+        impl Drop for BackgroundBrush {
+            fn drop(&mut self) {
+                unsafe { DeleteObject(self.brush.get() as _); }
+            }
+        }
+
+        self.clear_background_color();
+
+        let background = BackgroundBrush { color, brush: Cell::new(BackgroundBrush::create(color)) };
+        let handler = bind_raw_event_handler_inner(&self.handle, BACKGROUND_HANDLER_ID, move |hwnd, msg, w, _l| {
+            match msg {
+                WM_ERASEBKGND => unsafe {
+                    let mut r: RECT = mem::zeroed();
+                    GetClientRect(hwnd, &mut r);
+                    FillRect(w as HDC, &r, background.brush.get());
+                    Some(1)
+                },
+                WM_THEMECHANGED => unsafe {
+                    DeleteObject(background.brush.get() as _);
+                    background.brush.set(BackgroundBrush::create(background.color));
+                    None
+                },
+                _ => None
+            }
+        });
+
+        // The handler is freed by `unbind_raw_event_handler_inner` when the background is cleared
+        drop(handler);
+        
+        self.background_color.set(Some(color));
+        self.invalidate();
+    }
+
+    /// Return the background color set with `set_background_color`
+    /// Return `None` if the window uses the default system background
+    pub fn background_color(&self) -> Option<[u8; 3]> {
+        self.background_color.get()
+    }
+
+    /// Remove the custom background color of the window and use the default system background
+    pub fn clear_background_color(&self) {
+        use crate::unbind_raw_event_handler_inner;
+        use crate::win32::window_helper::BACKGROUND_HANDLER_ID;
+
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        
+        if self.background_color.take().is_some() {
+            drop(unbind_raw_event_handler_inner(&self.handle, BACKGROUND_HANDLER_ID));
+            self.invalidate();
+        }
+    }
+
+    /**
+        Sets the color of the title bar. `None` restores the system color.
+
+        Requires Windows 11 (build 22000). This does nothing on older versions of Windows.
+    */
+    pub fn set_caption_color(&self, color: Option<[u8; 3]>) {
+        self.set_dwm_color(wh::DWMWA_CAPTION_COLOR, color);
+    }
+
+    /**
+        Sets the color of the title bar text. `None` restores the system color.
+
+        Requires Windows 11 (build 22000). This does nothing on older versions of Windows.
+    */
+    pub fn set_caption_text_color(&self, color: Option<[u8; 3]>) {
+        self.set_dwm_color(wh::DWMWA_TEXT_COLOR, color);
+    }
+
+    /**
+        Sets the color of the window border. `None` restores the system color.
+
+        Requires Windows 11 (build 22000). This does nothing on older versions of Windows.
+    */
+    pub fn set_border_color(&self, color: Option<[u8; 3]>) {
+        self.set_dwm_color(wh::DWMWA_BORDER_COLOR, color);
+    }
+
+    /**
+        Use the dark theme for the title bar of the window. 
+
+        Requires Windows 10 (build 18362) or later. Builds before 19041 use an undocumented attribute id that is tried as a fallback.
+        This does nothing on older versions of Windows.
+    */
+    pub fn set_dark_title_bar(&self, dark: bool) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let value: i32 = dark as i32;
+
+        unsafe {
+            if !wh::set_dwm_attribute(handle, wh::DWMWA_USE_IMMERSIVE_DARK_MODE, &value) {
+                wh::set_dwm_attribute(handle, wh::DWMWA_USE_IMMERSIVE_DARK_MODE_OLD, &value);
+            }
+        }
+    }
+
+    /**
+        Sets the rounded corner policy of the window. See `CornerPreference`.
+
+        Requires Windows 11 (build 22000). This does nothing on older versions of Windows.
+    */
+    pub fn set_corner_preference(&self, preference: CornerPreference) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let value = preference as u32;
+        unsafe { wh::set_dwm_attribute(handle, wh::DWMWA_WINDOW_CORNER_PREFERENCE, &value); }
+    }
+
+    /**
+        Sets the system backdrop material drawn behind the window. See `BackdropType`.
+
+        Requires Windows 11 (build 22621). This does nothing on older versions of Windows.
+
+        The material is drawn behind the title bar and the borders. It only shows through the client area where the window
+        paints nothing opaque, so the window usually also needs to extend the frame into the client area
+        (`DwmExtendFrameIntoClientArea` with margins of -1) and to paint its background in black (or not at all).
+        The backdrop follows the theme of the title bar: combine it with `set_dark_title_bar` for a dark material.
+    */
+    pub fn set_backdrop(&self, backdrop: BackdropType) {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let value = backdrop as u32;
+        unsafe { wh::set_dwm_attribute(handle, wh::DWMWA_SYSTEMBACKDROP_TYPE, &value); }
+    }
+
+    /**
+        Sets where the content of the window can be displayed. Use this to hide sensitive content (ex: a password prompt)
+        from screenshots and screen sharing. See `DisplayAffinity` for the difference between the modes.
+
+        The affinity only applies to top level windows that are composited by the desktop window manager.
+        Returns `false` if the affinity could not be set (ex: if the window is a child window).
+        `DisplayAffinity::ExcludeFromCapture` falls back to `DisplayAffinity::Monitor` on versions of Windows that do not support it.
+    */
+    pub fn set_display_affinity(&self, affinity: DisplayAffinity) -> bool {
+        use winapi::um::winuser::{SetWindowDisplayAffinity, WDA_MONITOR};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        unsafe {
+            match SetWindowDisplayAffinity(handle, affinity as u32) {
+                0 if affinity == DisplayAffinity::ExcludeFromCapture => SetWindowDisplayAffinity(handle, WDA_MONITOR) != 0,
+                0 => false,
+                _ => true
+            }
+        }
+    }
+
+    /**
+        Returns the display affinity of the window. See `Window::set_display_affinity`.
+    */
+    pub fn display_affinity(&self) -> DisplayAffinity {
+        use winapi::um::winuser::{GetWindowDisplayAffinity, WDA_MONITOR};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let mut affinity = 0;
+        unsafe { GetWindowDisplayAffinity(handle, &mut affinity); }
+
+        match affinity {
+            WDA_MONITOR => DisplayAffinity::Monitor,
+            wh::WDA_EXCLUDEFROMCAPTURE => DisplayAffinity::ExcludeFromCapture,
+            _ => DisplayAffinity::None
+        }
+    }
+
+    /**
+        Shows or hides the help button ("?") in the title bar (`WS_EX_CONTEXTHELP`). When the user clicks the button and then a control,
+        the control raises `OnHelp`. Pressing F1 raises the same event for the focused control.
+
+        The system only draws the help button if the window has no minimize and no maximize button.
+    */
+    pub fn set_help_button(&self, visible: bool) {
+        use winapi::um::winuser::{GWL_EXSTYLE, WS_EX_CONTEXTHELP};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe {
+            match visible {
+                true => wh::modify_window_style(handle, GWL_EXSTYLE, WS_EX_CONTEXTHELP, 0),
+                false => wh::modify_window_style(handle, GWL_EXSTYLE, 0, WS_EX_CONTEXTHELP)
+            };
+        }
+    }
+
+    /// Returns `true` if the window has the help button style. See `Window::set_help_button`.
+    pub fn help_button(&self) -> bool {
+        use winapi::um::winuser::WS_EX_CONTEXTHELP;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        wh::get_ex_style(handle) & WS_EX_CONTEXTHELP != 0
+    }
+
+    /**
+        Shows or hides the taskbar button of the window. This is useful for tool windows and for the applications that only live in the tray.
+
+        Hiding the button sets the `WS_EX_TOOLWINDOW` extended style, and showing it sets `WS_EX_APPWINDOW`.
+        The taskbar only reads these styles when a window is shown, so a visible window is briefly hidden and shown again without being activated.
+
+        Notes:
+        - A tool window has a smaller title bar and is not listed in the Alt+Tab switcher either.
+        - Without any of the two styles, a window that has an owner (ex: a window built with `.parent(...)`) has no taskbar button
+          and a window without owner has one. Showing the button of an owned window forces it with `WS_EX_APPWINDOW`.
+    */
+    pub fn set_taskbar_visible(&self, visible: bool) {
+        use winapi::um::winuser::{ShowWindow, IsWindowVisible, GWL_EXSTYLE, WS_EX_APPWINDOW, WS_EX_TOOLWINDOW, SW_HIDE, SW_SHOWNA};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let (add, remove) = match visible {
+            true => (WS_EX_APPWINDOW, WS_EX_TOOLWINDOW),
+            false => (WS_EX_TOOLWINDOW, WS_EX_APPWINDOW)
+        };
+
+        unsafe {
+            let shown = IsWindowVisible(handle) != 0;
+            if shown {
+                ShowWindow(handle, SW_HIDE);
+            }
+
+            wh::modify_window_style(handle, GWL_EXSTYLE, add, remove);
+
+            if shown {
+                ShowWindow(handle, SW_SHOWNA);
+            }
+        }
+    }
+
+    /**
+        Returns `true` if the window has a taskbar button when it is visible. See `Window::set_taskbar_visible`.
+    */
+    pub fn taskbar_visible(&self) -> bool {
+        use winapi::um::winuser::{GetWindow, GW_OWNER, WS_EX_APPWINDOW, WS_EX_TOOLWINDOW};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let ex_style = wh::get_ex_style(handle);
+
+        if ex_style & WS_EX_TOOLWINDOW != 0 {
+            false
+        } else if ex_style & WS_EX_APPWINDOW != 0 {
+            true
+        } else {
+            unsafe { GetWindow(handle, GW_OWNER).is_null() }
+        }
+    }
+
+    /**
+        Hides the window in the tray when it is minimized. `None` restores the normal minimize behavior.
+
+        When enabled, the minimize command (the minimize button, the system menu, a click on the taskbar button, etc)
+        hides the window with `ShowWindow(SW_HIDE)` instead of minimizing it. Double clicking `tray` shows the window again and brings it to the foreground.
+        `tray` must be built before calling this method. The tray events are still raised as usual.
+
+        Notes:
+        - A hidden window has no taskbar button, whatever the value of `set_taskbar_visible`. Call `set_taskbar_visible(false)`
+          to also hide the button while the window is shown (an application that only lives in the tray).
+        - Only the minimize command is intercepted. `set_visible(false)` and `ShowWindow(SW_MINIMIZE)` from code are not affected.
+        - The tray icon is not shown or hidden by this method. Use `TrayNotification::set_visibility` if needed.
+    */
+    #[cfg(feature = "tray-notification")]
+    pub fn set_minimize_to_tray(&self, tray: Option<&crate::TrayNotification>) {
+        use crate::{bind_raw_event_handler_inner, unbind_raw_event_handler_inner};
+        use crate::win32::window_helper::{MINIMIZE_TO_TRAY_HANDLER_ID, NWG_TRAY};
+        use winapi::um::winuser::{WM_SYSCOMMAND, SC_MINIMIZE, WM_LBUTTONDBLCLK, SW_HIDE, SW_SHOW, SW_RESTORE};
+        use winapi::um::winuser::{ShowWindow, IsIconic, SetForegroundWindow};
+        use winapi::shared::minwindef::{UINT, LPARAM, LOWORD};
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        drop(unbind_raw_event_handler_inner(&self.handle, MINIMIZE_TO_TRAY_HANDLER_ID));
+        if let Some(tray_hwnd) = self.minimize_to_tray.take() {
+            drop(unbind_raw_event_handler_inner(&ControlHandle::Hwnd(tray_hwnd), MINIMIZE_TO_TRAY_HANDLER_ID));
+        }
+
+        let tray_hwnd = match tray {
+            Some(tray) => tray.handle.tray().expect("TrayNotification is not initialized"),
+            None => { return; }
+        };
+
+        let window = handle as usize;
+        let restore = move |msg: UINT, l: LPARAM| {
+            if msg == NWG_TRAY && LOWORD(l as u32) as UINT == WM_LBUTTONDBLCLK {
+                let window = window as HWND;
+                unsafe {
+                    ShowWindow(window, SW_SHOW);
+                    if IsIconic(window) != 0 {
+                        ShowWindow(window, SW_RESTORE);
+                    }
+                    SetForegroundWindow(window);
+                }
+            }
+        };
+
+        let handler = bind_raw_event_handler_inner(&self.handle, MINIMIZE_TO_TRAY_HANDLER_ID, move |hwnd, msg, w, l| {
+            if msg == WM_SYSCOMMAND && (w & 0xFFF0) == SC_MINIMIZE {
+                unsafe { ShowWindow(hwnd, SW_HIDE); }
+                return Some(0);
+            }
+
+            restore(msg, l);
+            None
+        });
+        drop(handler);
+
+        // The tray messages are sent to the parent of the tray, which might not be this window
+        if tray_hwnd != handle {
+            let handler = bind_raw_event_handler_inner(&ControlHandle::Hwnd(tray_hwnd), MINIMIZE_TO_TRAY_HANDLER_ID, move |_hwnd, msg, _w, l| {
+                restore(msg, l);
+                None
+            });
+            drop(handler);
+            self.minimize_to_tray.set(Some(tray_hwnd));
+        }
+    }
+
+    /**
+        Gives the window a non rectangular shape. Only the area inside the region is drawn and receives the mouse input.
+        The region coordinates are relative to the upper left corner of the window, including the title bar and the borders.
+        This is mostly useful with borderless windows (ex: splash screens).
+
+        The system takes ownership of the region, so the region is consumed by this method and must not be used again.
+        It is freed by the system when the window is destroyed or when another region is set.
+
+        Pass `Region::default()` (a region with a null handle) to restore the rectangular shape of the window.
+        Returns `false` if the region could not be set.
+    */
+    pub fn set_region(&self, region: Region) -> bool {
+        use winapi::um::winuser::SetWindowRgn;
+        use crate::win32::resources_helper as rh;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let region = region.take();
+
+        unsafe {
+            match SetWindowRgn(handle, region, 1) {
+                0 => {
+                    // The system only owns the region if the call succeeds
+                    if !region.is_null() {
+                        rh::destroy_obj(region as _);
+                    }
+                    false
+                },
+                _ => true
+            }
+        }
+    }
+
+    fn set_dwm_color(&self, attribute: u32, color: Option<[u8; 3]>) {
+        use winapi::um::wingdi::RGB;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let value = match color {
+            Some([r, g, b]) => RGB(r, g, b),
+            None => wh::DWMWA_COLOR_DEFAULT
+        };
+
+        unsafe { wh::set_dwm_attribute(handle, attribute, &value); }
+    }
+
+    /**
+        Register or unregister the window for the device interface notifications of every device class.
+
+        Top level windows always receive the broadcast `WM_DEVICECHANGE` notifications: volumes (ex: a USB drive getting a letter), 
+        ports, and `DBT_DEVNODES_CHANGED`. These raise `OnDeviceChange` without any registration.
+        Arrival and removal of device interfaces (ex: a USB device that is not a drive) are only sent to windows registered with
+        `RegisterDeviceNotification`. With these notifications, `OnDeviceChange` also receives the device interface path.
+
+        The registration is removed when the window is dropped.
+    */
+    pub fn set_device_notifications(&self, enabled: bool) -> Result<(), NwgError> {
+        use winapi::um::winuser::{RegisterDeviceNotificationW, UnregisterDeviceNotification, DEVICE_NOTIFY_WINDOW_HANDLE, DEVICE_NOTIFY_ALL_INTERFACE_CLASSES};
+        use winapi::um::dbt::{DEV_BROADCAST_DEVICEINTERFACE_W, DBT_DEVTYP_DEVICEINTERFACE};
+        use winapi::shared::minwindef::DWORD;
+        use std::mem;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        if let Some(notify) = self.device_notify.take() {
+            unsafe { UnregisterDeviceNotification(notify); }
+        }
+
+        if !enabled {
+            return Ok(());
+        }
+
+        let notify = unsafe {
+            let mut filter: DEV_BROADCAST_DEVICEINTERFACE_W = mem::zeroed();
+            filter.dbcc_size = mem::size_of::<DEV_BROADCAST_DEVICEINTERFACE_W>() as DWORD;
+            filter.dbcc_devicetype = DBT_DEVTYP_DEVICEINTERFACE;
+
+            let filter_ptr = &mut filter as *mut DEV_BROADCAST_DEVICEINTERFACE_W;
+            RegisterDeviceNotificationW(handle as _, filter_ptr as _, DEVICE_NOTIFY_WINDOW_HANDLE | DEVICE_NOTIFY_ALL_INTERFACE_CLASSES)
+        };
+
+        if notify.is_null() {
+            return Err(NwgError::events_binding("Failed to register the window for device notifications"));
+        }
+
+        self.device_notify.set(Some(notify));
+
+        Ok(())
+    }
+
+    /// Return true if the window was registered for device interface notifications with `set_device_notifications`
+    pub fn device_notifications(&self) -> bool {
+        self.device_notify.get().is_some()
+    }
+
+    /**
+        Sets how the window handles the resize events while the user drags its border. See `ResizeMode`.
+
+        With complex layouts, recomputing the layout for every `WM_SIZE` of an interactive resize can make the drag stutter.
+        In the deferred modes, the `WM_SIZE` messages sent during the drag are blocked before they reach the layouts and the
+        `OnResize` handlers. When the user releases the border, a single `WM_SIZE` with the final size is sent, followed by `OnResizeEnd`.
+
+        The trade-off is visual: the content of the window does not follow the border during the drag. The newly exposed area shows
+        the background of the window and the children keep their old position (or are hidden with `DeferredPlaceholder`).
+        Maximizing, minimizing or resizing the window from code are not affected.
+
+        The resize messages are blocked by a raw event handler. Because the last handler bound to a window runs first,
+        call this method after the layouts of the window are built.
+    */
+    pub fn set_resize_mode(&self, mode: ResizeMode) {
+        use crate::{bind_raw_event_handler_inner, unbind_raw_event_handler_inner};
+        use crate::win32::window_helper::RESIZE_MODE_HANDLER_ID;
+        use winapi::um::winuser::{WM_ENTERSIZEMOVE, WM_EXITSIZEMOVE, WM_SIZE, SIZE_RESTORED, SW_HIDE, SW_SHOW, GW_CHILD, GW_HWNDNEXT};
+        use winapi::um::winuser::{GetClientRect, GetWindow, IsWindowVisible, ShowWindow};
+        use winapi::shared::windef::RECT;
+        use winapi::shared::minwindef::MAKELONG;
+        use std::mem;
+
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        drop(unbind_raw_event_handler_inner(&self.handle, RESIZE_MODE_HANDLER_ID));
+        self.resize_mode.set(mode);
+        if mode == ResizeMode::Live {
+            return;
+        }
+
+        let placeholder = mode == ResizeMode::DeferredPlaceholder;
+        let in_drag = Cell::new(false);
+        let pending = Cell::new(false);
+        let hidden: RefCell<Vec<HWND>> = RefCell::new(Vec::new());
+
+        let handler = bind_raw_event_handler_inner(&self.handle, RESIZE_MODE_HANDLER_ID, move |hwnd, msg, w, _l| {
+            match msg {
+                WM_ENTERSIZEMOVE => {
+                    in_drag.set(true);
+                    None
+                },
+                WM_SIZE if in_drag.get() && w == SIZE_RESTORED => unsafe {
+                    if placeholder && !pending.get() {
+                        let mut hidden = hidden.borrow_mut();
+                        let mut child = GetWindow(hwnd, GW_CHILD);
+                        while !child.is_null() {
+                            if IsWindowVisible(child) != 0 {
+                                ShowWindow(child, SW_HIDE);
+                                hidden.push(child);
+                            }
+                            child = GetWindow(child, GW_HWNDNEXT);
+                        }
+                    }
+
+                    pending.set(true);
+                    Some(0)
+                },
+                WM_EXITSIZEMOVE => unsafe {
+                    in_drag.set(false);
+
+                    if pending.replace(false) {
+                        let mut r: RECT = mem::zeroed();
+                        GetClientRect(hwnd, &mut r);
+                        wh::send_message(hwnd, WM_SIZE, SIZE_RESTORED, MAKELONG(r.right as u16, r.bottom as u16) as _);
+
+                        for child in hidden.borrow_mut().drain(..) {
+                            ShowWindow(child, SW_SHOW);
+                        }
+                    }
+
+                    // `OnResizeEnd` is raised after the final size is applied
+                    None
+                },
+                _ => None
+            }
+        });
+
+        // The handler is freed when the mode is changed or when the window is dropped
+        drop(handler);
+    }
+
+    /**
+        Sets the button clicked when the user presses Enter in the window. `None` removes the default button.
+
+        The default button is drawn with the thicker border (`BS_DEFPUSHBUTTON`). When Enter is pressed, the push button that
+        has the keyboard focus is clicked if there is one, otherwise the default button is clicked. The button is clicked with `BM_CLICK`,
+        so it is pressed visually and raises `OnButtonClick` like a real click. Nothing happens if the button is disabled.
+        When the keyboard navigation moves the focus to another push button, the dialog manager moves the thicker border to that button
+        and gives it back to the default button when the focus leaves the push buttons.
+
+        Keyboard navigation:
+        - Enter is handled by `IsDialogMessage` in the NWG events loop (`dispatch_thread_events`). A custom events loop must call it too.
+        - Enter is not handled if the focused control wants it (ex: a multi-line `TextBox`, or `set_want_keys` with `WantKeys::ALL_KEYS`).
+        - While a default button is set, `OnKeyEnter` is no longer raised on the window.
+
+        Panics if `button` is not a window-like control.
+    */
+    pub fn set_default_button(&self, button: Option<&ControlHandle>) {
+        use winapi::um::winuser::{BS_PUSHBUTTON, BS_DEFPUSHBUTTON};
+
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let mut buttons = self.dialog_buttons.get();
+        if let Some(old) = buttons.default {
+            set_push_button_type(old, BS_PUSHBUTTON);
+        }
+
+        buttons.default = button.map(|b| b.hwnd().expect("The default button must be a window-like control"));
+        if let Some(new) = buttons.default {
+            set_push_button_type(new, BS_DEFPUSHBUTTON);
+        }
+
+        self.dialog_buttons.set(buttons);
+        self.hook_dialog_buttons();
+    }
+
+    /// Returns the default button of the window. See `set_default_button`.
+    pub fn default_button(&self) -> Option<ControlHandle> {
+        self.dialog_buttons.get().default.map(ControlHandle::Hwnd)
+    }
+
+    /**
+        Sets the button clicked when the user presses Esc in the window. `None` removes the cancel button.
+
+        The button is clicked with `BM_CLICK` and raises `OnButtonClick` like a real click. Nothing happens if the button is disabled.
+        Like Enter for `set_default_button`, Esc is handled by `IsDialogMessage` in the events loop and `OnKeyEsc` is no longer raised
+        on the window while a cancel button is set.
+
+        Panics if `button` is not a window-like control.
+    */
+    pub fn set_cancel_button(&self, button: Option<&ControlHandle>) {
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        let mut buttons = self.dialog_buttons.get();
+        buttons.cancel = button.map(|b| b.hwnd().expect("The cancel button must be a window-like control"));
+
+        self.dialog_buttons.set(buttons);
+        self.hook_dialog_buttons();
+    }
+
+    /// Returns the cancel button of the window. See `set_cancel_button`.
+    pub fn cancel_button(&self) -> Option<ControlHandle> {
+        self.dialog_buttons.get().cancel.map(ControlHandle::Hwnd)
+    }
+
+    /**
+        Handles the commands sent by `IsDialogMessage` for Enter (`IDOK` or the id of the default button) and Esc (`IDCANCEL`).
+        `DM_GETDEFID` is answered so that the dialog manager knows which button has the default border.
+    */
+    fn hook_dialog_buttons(&self) {
+        use crate::{bind_raw_event_handler_inner, has_raw_handler};
+        use crate::win32::window_helper::DIALOG_BUTTONS_HANDLER_ID;
+        use winapi::um::winuser::{WM_COMMAND, DM_GETDEFID, DC_HASDEFID, BN_CLICKED, IDOK, IDCANCEL, GetDlgCtrlID};
+        use winapi::shared::minwindef::{LOWORD, HIWORD, MAKELONG};
+
+        if has_raw_handler(&self.handle, DIALOG_BUTTONS_HANDLER_ID) {
+            return;
+        }
+
+        let dialog_buttons = self.dialog_buttons.clone();
+        let handler = bind_raw_event_handler_inner(&self.handle, DIALOG_BUTTONS_HANDLER_ID, move |hwnd, msg, w, l| {
+            let buttons = dialog_buttons.get();
+            match msg {
+                DM_GETDEFID => {
+                    let default = buttons.default?;
+                    let id = unsafe { GetDlgCtrlID(default) };
+                    Some(MAKELONG(id as u16, DC_HASDEFID) as _)
+                },
+                WM_COMMAND if l == 0 && HIWORD(w as u32) == BN_CLICKED => {
+                    let id = LOWORD(w as u32) as i32;
+                    match (buttons.default, buttons.cancel) {
+                        (Some(default), _) if id == IDOK || id == unsafe { GetDlgCtrlID(default) } => {
+                            click_dialog_button(focused_push_button(hwnd).unwrap_or(default));
+                            Some(0)
+                        },
+                        (_, Some(cancel)) if id == IDCANCEL => {
+                            click_dialog_button(cancel);
+                            Some(0)
+                        },
+                        _ => None
+                    }
+                },
+                _ => None
+            }
+        });
+
+        // The handler is freed when the window is dropped
+        drop(handler);
+    }
+
+    /**
+        If `remember` is `true`, the window saves the child control that has the keyboard focus when it is deactivated
+        and gives the focus back to that control when it is activated again. Without it, the focus lands on the window itself
+        or on the first control, depending on how the window is activated.
+
+        If the saved control was destroyed, hidden or disabled in the meantime, the focus is not restored and the system default applies.
+
+        The restored control keeps the behavior of a focused control: if it is a push button, Enter clicks it instead of
+        the default button of the window (see `set_default_button`).
+    */
+    pub fn set_remember_focus(&self, remember: bool) {
+        use crate::{bind_raw_event_handler_inner, unbind_raw_event_handler_inner, has_raw_handler};
+        use crate::win32::window_helper::REMEMBER_FOCUS_HANDLER_ID;
+        use winapi::um::winuser::{WM_ACTIVATE, WA_INACTIVE, GetFocus, SetFocus, IsChild, IsWindowVisible, IsWindowEnabled};
+        use winapi::shared::minwindef::{LOWORD, HIWORD};
+
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+
+        if !remember {
+            drop(unbind_raw_event_handler_inner(&self.handle, REMEMBER_FOCUS_HANDLER_ID));
+            return;
+        }
+
+        if has_raw_handler(&self.handle, REMEMBER_FOCUS_HANDLER_ID) {
+            return;
+        }
+
+        let focused: Cell<Option<HWND>> = Cell::new(None);
+        let handler = bind_raw_event_handler_inner(&self.handle, REMEMBER_FOCUS_HANDLER_ID, move |hwnd, msg, w, _l| {
+            if msg != WM_ACTIVATE {
+                return None;
+            }
+
+            unsafe {
+                let state = LOWORD(w as u32) as u16;
+                if state == WA_INACTIVE {
+                    let focus = GetFocus();
+                    if !focus.is_null() && IsChild(hwnd, focus) != 0 {
+                        focused.set(Some(focus));
+                    }
+                    return None;
+                }
+
+                // Minimized windows do not take the focus
+                if HIWORD(w as u32) != 0 {
+                    return None;
+                }
+
+                // `IsChild` also fails if the control was destroyed
+                match focused.get() {
+                    Some(focus) if IsChild(hwnd, focus) != 0 && IsWindowVisible(focus) != 0 && IsWindowEnabled(focus) != 0 => {
+                        SetFocus(focus);
+                        Some(0)
+                    },
+                    _ => None
+                }
+            }
+        });
+
+        // The handler is freed when the window is dropped
+        drop(handler);
+    }
+
+    /// Returns `true` if the window restores the focus of its last focused control. See `set_remember_focus`.
+    pub fn remember_focus(&self) -> bool {
+        use crate::{has_raw_handler, win32::window_helper::REMEMBER_FOCUS_HANDLER_ID};
+
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        has_raw_handler(&self.handle, REMEMBER_FOCUS_HANDLER_ID)
+    }
+
+    /**
+        Raises `OnCustomMessage` on the window when it receives the registered message `msg`, for example from `nwg::broadcast_message`.
+        `msg` is an identifier returned by `nwg::register_message`. Other messages are ignored.
+
+        The subscription is saved in a window property, so it ends when the window is destroyed.
+    */
+    pub fn subscribe_message(&self, msg: u32) {
+        use winapi::um::winuser::SetPropW;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        if !(0xC000..=0xFFFF).contains(&msg) {
+            return;
+        }
+
+        let prop_name = wh::custom_message_prop(msg);
+        unsafe { SetPropW(handle, prop_name.as_ptr(), 1 as _); }
+    }
+
+    /// Returns the system menu of the window, to disable or remove its standard items and to add custom items. See `SystemMenu`.
+    pub fn system_menu(&self) -> super::SystemMenu {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        super::SystemMenu::new(handle)
+    }
+
+    /**
+        Runs the validator of every control in the window that has one (see `ControlHandle::set_validator`), including the controls in frames and tabs.
+        Every error is displayed and the first invalid control, in the creation order, receives the focus.
+
+        Returns `true` if all the controls are valid.
+    */
+    pub fn validate_all(&self) -> bool {
+        use crate::win32::validation::validate_children;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { validate_children(handle) }
+    }
+
+    /// Stops raising `OnCustomMessage` for the registered message `msg`. See `subscribe_message`.
+    pub fn unsubscribe_message(&self, msg: u32) {
+        use winapi::um::winuser::RemovePropW;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let prop_name = wh::custom_message_prop(msg);
+        unsafe { RemovePropW(handle, prop_name.as_ptr()); }
+    }
+
+    /**
+        Sends `data` to the window `target` with a `WM_COPYDATA` message. `target` raises `OnCopyData` with `id` and a copy of the bytes.
+        The target can be in the same process or in another process (ex: another NWG application).
+
+        `WM_COPYDATA` is synchronous: this call blocks until the handlers of the target returned. The system copies the bytes
+        in the address space of the target for the duration of the call, so `OnCopyData` must copy them (NWG does it).
+        If the target is hung, this call blocks as well.
+
+        Returns `true` if the target processed the message. The ids used internally by NWG (ex: `single_instance`) are not raised as `OnCopyData`.
+
+        Panics if `target` is not a window-like control.
+    */
+    pub fn send_copydata(&self, target: &ControlHandle, id: usize, data: &[u8]) -> bool {
+        use winapi::um::winuser::{WM_COPYDATA, COPYDATASTRUCT};
+        use winapi::shared::minwindef::DWORD;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let target = target.hwnd().expect("The target of WM_COPYDATA must be a window-like control");
+
+        let copy_data = COPYDATASTRUCT {
+            dwData: id,
+            cbData: data.len() as DWORD,
+            lpData: data.as_ptr() as *mut _,
+        };
+
+        wh::send_message(target, WM_COPYDATA, handle as _, &copy_data as *const COPYDATASTRUCT as _) != 0
+    }
+
+    /// Returns the resize mode of the window. See `set_resize_mode`.
+    pub fn resize_mode(&self) -> ResizeMode {
+        self.resize_mode.get()
+    }
+
     /// Winapi class name used during control creation
     pub fn class_name(&self) -> &'static str {
         "NativeWindowsGuiWindow"
@@ -257,8 +1393,93 @@ impl Window {
     }
 }
 
+impl PartialEq for Window {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+impl Eq for Window {}
+
+/// Button type bits of the button style
+const BUTTON_TYPE_MASK: u32 = 0x000F;
+
+/// Switches a push button between `BS_PUSHBUTTON` and `BS_DEFPUSHBUTTON`. The other types of buttons are not changed.
+fn set_push_button_type(button: HWND, button_type: u32) {
+    use winapi::um::winuser::{BM_SETSTYLE, BS_PUSHBUTTON, BS_DEFPUSHBUTTON};
+
+    let style = wh::get_style(button);
+    let current = style & BUTTON_TYPE_MASK;
+    if current == BS_PUSHBUTTON || current == BS_DEFPUSHBUTTON {
+        wh::send_message(button, BM_SETSTYLE, ((style & !BUTTON_TYPE_MASK) | button_type) as _, 1);
+    }
+}
+
+/// Returns the push button of `window` that has the keyboard focus
+fn focused_push_button(window: HWND) -> Option<HWND> {
+    use winapi::um::winuser::{GetFocus, IsChild, WM_GETDLGCODE, DLGC_DEFPUSHBUTTON, DLGC_UNDEFPUSHBUTTON};
+    use winapi::shared::minwindef::LRESULT;
+
+    unsafe {
+        let focus = GetFocus();
+        if focus.is_null() || IsChild(window, focus) == 0 {
+            return None;
+        }
+
+        let code = wh::send_message(focus, WM_GETDLGCODE, 0, 0);
+        match code & (DLGC_DEFPUSHBUTTON | DLGC_UNDEFPUSHBUTTON) as LRESULT != 0 {
+            true => Some(focus),
+            false => None
+        }
+    }
+}
+
+/// Clicks a default or cancel button if it can be used
+fn click_dialog_button(button: HWND) {
+    use winapi::um::winuser::{IsWindowEnabled, BM_CLICK};
+
+    unsafe {
+        if IsWindowEnabled(button) != 0 {
+            wh::send_message(button, BM_CLICK, 0, 0);
+        }
+    }
+}
+
 impl Drop for Window {
     fn drop(&mut self) {
+        use crate::unbind_raw_event_handler_inner;
+        use crate::win32::window_helper::{BACKGROUND_HANDLER_ID, FADE_HANDLER_ID, RESIZE_MODE_HANDLER_ID, MINIMIZE_TO_TRAY_HANDLER_ID, DIALOG_BUTTONS_HANDLER_ID};
+        use crate::win32::window_helper::REMEMBER_FOCUS_HANDLER_ID;
+
+        if self.background_color.get().is_some() {
+            drop(unbind_raw_event_handler_inner(&self.handle, BACKGROUND_HANDLER_ID));
+        }
+
+        if self.handle.hwnd().is_some() {
+            drop(unbind_raw_event_handler_inner(&self.handle, FADE_HANDLER_ID));
+        }
+
+        if self.resize_mode.get() != ResizeMode::Live {
+            drop(unbind_raw_event_handler_inner(&self.handle, RESIZE_MODE_HANDLER_ID));
+        }
+
+        if self.handle.hwnd().is_some() {
+            drop(unbind_raw_event_handler_inner(&self.handle, MINIMIZE_TO_TRAY_HANDLER_ID));
+        }
+
+        if let Some(tray_hwnd) = self.minimize_to_tray.take() {
+            drop(unbind_raw_event_handler_inner(&ControlHandle::Hwnd(tray_hwnd), MINIMIZE_TO_TRAY_HANDLER_ID));
+        }
+
+        if self.handle.hwnd().is_some() {
+            drop(unbind_raw_event_handler_inner(&self.handle, DIALOG_BUTTONS_HANDLER_ID));
+            drop(unbind_raw_event_handler_inner(&self.handle, REMEMBER_FOCUS_HANDLER_ID));
+        }
+
+        if let Some(notify) = self.device_notify.take() {
+            unsafe { winapi::um::winuser::UnregisterDeviceNotification(notify); }
+        }
+
         self.handle.destroy();
     }
 }
@@ -298,6 +1519,7 @@ pub struct WindowBuilder<'a> {
     flags: Option<WindowFlags>,
     ex_flags: u32,
     icon: Option<&'a Icon>,
+    background_color: Option<[u8; 3]>,
     parent: Option<ControlHandle>
 }
 
@@ -358,6 +1580,11 @@ impl<'a> WindowBuilder<'a> {
         self
     }
 
+    pub fn background_color(mut self, color: Option<[u8; 3]>) -> WindowBuilder<'a> {
+        self.background_color = color;
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: Option<C>) -> WindowBuilder<'a> {
         self.parent = p.map(|p2| p2.into());
         self
@@ -389,6 +1616,10 @@ impl<'a> WindowBuilder<'a> {
             out.set_icon(self.icon);
         }
 
+        if let Some(color) = self.background_color {
+            out.set_background_color(color);
+        }
+
         if self.center {
             let [left, top, right, bottom] = crate::Monitor::monitor_rect_from_window(out as &Window);
             let (m_width, m_height) = unsafe { physical_to_logical(right-left, bottom-top) };
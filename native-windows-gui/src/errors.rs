@@ -41,6 +41,10 @@ pub enum NwgError {
     #[cfg(feature = "winnls")]
     BadLocale(String),
 
+    /// Error raised by a drag and drop operation
+    #[cfg(feature = "drag-drop")]
+    DragDropError(String),
+
     /// Error raised by one of the locale functions
     #[cfg(feature = "plotting")]
     Plotters(PlottersError),
@@ -87,6 +91,11 @@ impl NwgError {
         NwgError::ImageDecoderError(code, e.into())
     }
 
+    #[cfg(feature = "drag-drop")]
+    pub fn drag_drop<S: Into<String>>(e: S) -> NwgError {
+        NwgError::DragDropError(e.into())
+    }
+
     pub fn no_parent(name: &'static str) -> NwgError {
         NwgError::ControlCreationError(format!("No parent defined for {:?} control", name))
     }
@@ -119,6 +128,9 @@ impl fmt::Display for NwgError {
             #[cfg(feature = "winnls")]
             BadLocale(reason) => write!(f, "Windows locale functions failed: {:?}", reason),
 
+            #[cfg(feature = "drag-drop")]
+            DragDropError(reason) => write!(f, "Drag and drop failed: {:?}", reason),
+
             #[cfg(feature = "plotting")]
             Plotters(reason) => write!(f, "Plotting canvas function failed: {}", reason),
         }
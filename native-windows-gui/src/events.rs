@@ -10,6 +10,98 @@ pub enum MousePressEvent {
     MousePressRightDown
 }
 
+/// The kind of a `WM_DEVICECHANGE` notification. See `Event::OnDeviceChange`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeviceChangeKind {
+    /// A device or a volume was inserted and is now available (`DBT_DEVICEARRIVAL`)
+    Arrival,
+    /// The system asks permission to remove a device (`DBT_DEVICEQUERYREMOVE`)
+    RemoveQuery,
+    /// A request to remove a device was canceled (`DBT_DEVICEQUERYREMOVEFAILED`)
+    RemoveQueryFailed,
+    /// A device is about to be removed. It cannot be vetoed (`DBT_DEVICEREMOVEPENDING`)
+    RemovePending,
+    /// A device was removed (`DBT_DEVICEREMOVECOMPLETE`)
+    RemoveComplete,
+    /// A device was added or removed from the system, without any details (`DBT_DEVNODES_CHANGED`)
+    NodesChanged,
+}
+
+/// What made a layout move and resize its children. See `Event::OnLayout`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayoutCause {
+    /// The parent of the layout was resized (`WM_SIZE`)
+    Resize,
+    /// The parent of the layout was resized because its DPI changed (`WM_SIZE` sent while handling `WM_DPICHANGED`)
+    Dpi,
+    /// The layout was updated by the application: `build`, `fit`, `resize`, `resume` or a change of the children
+    Manual,
+}
+
+/// The edge of the window that is being dragged during an interactive resize. See `Event::OnSizing`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizingEdge {
+    /// `WMSZ_LEFT`
+    Left,
+    /// `WMSZ_RIGHT`
+    Right,
+    /// `WMSZ_TOP`
+    Top,
+    /// `WMSZ_TOPLEFT`
+    TopLeft,
+    /// `WMSZ_TOPRIGHT`
+    TopRight,
+    /// `WMSZ_BOTTOM`
+    Bottom,
+    /// `WMSZ_BOTTOMLEFT`
+    BottomLeft,
+    /// `WMSZ_BOTTOMRIGHT`
+    BottomRight,
+}
+
+/**
+    The scroll command of a `WM_HSCROLL` or `WM_VSCROLL` message. See `Event::OnHorizontalScroll` and `Event::OnVerticalScroll`
+
+    The horizontal and the vertical commands share the same values, so a single variant covers both:
+
+    | Variant            | Vertical scroll      | Horizontal scroll    |
+    |--------------------|----------------------|----------------------|
+    | `LineUp`           | `SB_LINEUP`          | `SB_LINELEFT`        |
+    | `LineDown`         | `SB_LINEDOWN`        | `SB_LINERIGHT`       |
+    | `PageUp`           | `SB_PAGEUP`          | `SB_PAGELEFT`        |
+    | `PageDown`         | `SB_PAGEDOWN`        | `SB_PAGERIGHT`       |
+    | `ThumbPosition(_)` | `SB_THUMBPOSITION`   | `SB_THUMBPOSITION`   |
+    | `ThumbTrack(_)`    | `SB_THUMBTRACK`      | `SB_THUMBTRACK`      |
+    | `Top`              | `SB_TOP`             | `SB_LEFT`            |
+    | `Bottom`           | `SB_BOTTOM`          | `SB_RIGHT`           |
+    | `EndScroll`        | `SB_ENDSCROLL`       | `SB_ENDSCROLL`       |
+
+    Track bars send the `TB_*` codes, which have the same values.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScrollKind {
+    /// Scroll by one line (`SB_LINEUP`/`SB_LINELEFT`)
+    LineUp,
+    /// Scroll by one line (`SB_LINEDOWN`/`SB_LINERIGHT`)
+    LineDown,
+    /// Scroll by one page (`SB_PAGEUP`/`SB_PAGELEFT`)
+    PageUp,
+    /// Scroll by one page (`SB_PAGEDOWN`/`SB_PAGERIGHT`)
+    PageDown,
+    /// The user released the thumb at this position (`SB_THUMBPOSITION`)
+    ThumbPosition(i32),
+    /// The user is dragging the thumb and it is at this position (`SB_THUMBTRACK`)
+    ThumbTrack(i32),
+    /// Scroll to the start (`SB_TOP`/`SB_LEFT`)
+    Top,
+    /// Scroll to the end (`SB_BOTTOM`/`SB_RIGHT`)
+    Bottom,
+    /// The scroll operation is over (`SB_ENDSCROLL`)
+    EndScroll,
+    /// A command that is not part of the `SB_*` values
+    Other(u16),
+}
+
 /// Events are identifiers that are sent by controls on user interaction
 /// Some events also have data that can be further processed by the event loop. See `EventData`
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -28,9 +120,28 @@ pub enum Event {
     /// Read the delta value with `EventData::OnMouseWheel` to check which key.
     OnMouseWheel,
 
-    /// Generic window event when the user right clicks a window
+    /// Generic horizontal mouse wheel event (tilt wheel or horizontal swipe on a touchpad) that can be generated by most window controls
+    /// Read the delta value with `EventData::OnMouseHWheel`.
+    OnMouseHWheel,
+
+    /// Generic window event when the user right clicks a window, or presses Shift+F10 or the context menu key.
+    /// The source is the control under the mouse (or the focused control for the keyboard).
+    ///
+    /// Read the position and suppress the default context menu of the control with `EventData::OnContextMenu`.
+    /// The edit controls (`TextInput`, `TextBox`, the edit of a `ComboBox` or a `NumberSelect`) and `RichTextBox` show a default menu
+    /// (Undo, Cut, Copy, Paste, ...) after the event unless it is suppressed. The other built-in controls have no default menu.
+    ///
+    /// The event of a `TrayNotification` has no data (`EventData::NoData`).
     OnContextMenu,
 
+    /// When a top level window receives a system command (`WM_SYSCOMMAND`): the buttons of the title bar (minimize, maximize,
+    /// restore, close), the items of the system menu, Alt+F4, Alt+Space, or a double click on the title bar.
+    /// The handler can prevent the default action with `EventData::OnSysCommand`. See `SysCommandData`.
+    ///
+    /// It is raised before the dedicated events of the action (ex: `OnWindowMaximize`, `OnWindowClose`). The custom items of the
+    /// system menu (see `Window::system_menu`) raise `OnMenuItemSelected` instead.
+    OnSysCommand,
+
     /// When a top level window control is created.
     OnInit,
 
@@ -38,9 +149,11 @@ pub enum Event {
     OnPaint,
 
     /// When a key is pressed on a keyboard. Unlike OnKeyDown, this returns a char (ex: 'c') in a EventData::OnChar.
+    /// The character can be consumed with `CharData::consume` to stop it from reaching the control (see `CharData`).
     OnChar,
 
     /// When a key is pressed on a keyboard. Use `EventData::OnKey` to check which key.
+    /// The key can be consumed with `KeyData::consume` to skip the default processing of the control (see `KeyData`).
     OnKeyPress,
 
     /// When a key is released on a keyboard. Use EventData::OnKey to check which key.
@@ -68,37 +181,163 @@ pub enum Event {
 
     /// When a control is about to be resized by the user. 
     /// This does not trigger on maximize
+    /// This is raised by `WM_ENTERSIZEMOVE`, so it is also raised when the user starts moving the window. See `OnEnterSizeMove`
     OnResizeBegin,
 
     /// When a control stops being resized
     /// This does not trigger on maximize
+    /// This is raised by `WM_EXITSIZEMOVE`, so it is also raised when the user stops moving the window. See `OnExitSizeMove`
     OnResizeEnd,
 
+    /// When the user starts moving or resizing a window by dragging its caption or its borders (`WM_ENTERSIZEMOVE`).
+    /// Use this event to pause expensive work (ex: live previews) until `OnExitSizeMove`.
+    /// Raised before `OnResizeBegin`, which comes from the same message. Neither is raised on maximize, minimize
+    /// or when the application changes the size or the position.
+    OnEnterSizeMove,
+
+    /// When the user stops moving or resizing a window (`WM_EXITSIZEMOVE`). Raised after `OnResizeEnd`.
+    /// Unlike `OnResize`, which is raised for each size change, this is raised once at the end of the drag.
+    OnExitSizeMove,
+
+    /// While a window is being resized by the user (`WM_SIZING`). This only fires during an interactive resize,
+    /// not when the size is set by the application.
+    /// The handler can adjust the drag rectangle live (ex: to lock the aspect ratio) with `EventData::OnSizing`
+    OnSizing,
+
+    /// When a splitter is dragged by the user. See `EventData::OnSplitterMove`
+    OnSplitterMove,
+
+    /// When a layout (`GridLayout`, `DynLayout` or `FlexboxLayout`) finished moving and resizing its children.
+    /// The source is the parent of the layout and `EventData::OnLayout` tells what updated the layout.
+    /// Use it to position what depends on the final rectangles of the children (ex: overlays or custom drawn decorations).
+    ///
+    /// The event is posted, so it is raised once the children are placed and after the `OnResize` of the resize that
+    /// triggered the layout, whatever the order of the handlers. A parent with several layouts raises one event per layout.
+    /// A layout that is suspended, or too small to place its children, does not raise it.
+    OnLayout,
+
+    /// When a pointer (finger, pen or touchpad contact) touches a window (`WM_POINTERDOWN`). See `EventData::OnPointer`.
+    /// 
+    /// Pointer events require Windows 8. They are sent for touch and pen input without any registration, and the
+    /// input is also promoted to the mouse events (`OnMousePress`, `OnMouseMove`), so an application that does not need
+    /// multi-touch can keep using the mouse events. The mouse only raises pointer events after `enable_mouse_in_pointer`.
+    OnPointerDown,
+
+    /// When a pointer stops touching a window (`WM_POINTERUP`). See `OnPointerDown`.
+    OnPointerUp,
+
+    /// When a pointer moves or changes state (`WM_POINTERUPDATE`). See `OnPointerDown`.
+    OnPointerUpdate,
+
     // When a window control is maximized
     OnWindowMaximize,
 
     // When a window control is minimized
     OnWindowMinimize,
 
+    /// When a top level window becomes the active window (`WM_ACTIVATE` with `WA_ACTIVE` or `WA_CLICKACTIVE`).
+    /// Unlike the focus of a control, this is about the whole window. Use it, with `OnWindowDeactivate`, to pause
+    /// the animations and the timers while the user works in another window.
+    /// `EventData::OnWindowActivate` tells if the window was activated by a mouse click (`WA_CLICKACTIVE`)
+    /// or by another way (`WA_ACTIVE`, ex: Alt+Tab or `SetActiveWindow`).
+    OnWindowActivate,
+
+    /// When a top level window stops being the active window (`WM_ACTIVATE` with `WA_INACTIVE`)
+    OnWindowDeactivate,
+
     /// When a control is moved by the user. This is typically applied to top level windows.
     /// This is typically applied to top level windows but it also applies to children when layouts are used.
     OnMove,
 
     /// When a bar-like control value is changed.
+    /// The source is the scroll bar control, or the window itself for the scroll bars of a window (`WS_VSCROLL`).
+    /// Read the scroll command with `EventData::OnScroll`.
     OnVerticalScroll,
 
     /// When a bar-like control value is changed.
+    /// The source is the scroll bar control, or the window itself for the scroll bars of a window (`WS_HSCROLL`).
+    /// Read the scroll command with `EventData::OnScroll`.
     OnHorizontalScroll,
 
     /// When a file is dropped into a control
     OnFileDrop,
 
-    /// When a button is clicked. Similar to a MouseUp event, but only for button control
+    /// When a drag and drop operation enters a control registered with `ControlHandle::set_drop_target`.
+    /// The handler inspects the dragged data and sets the drop effect. See `EventData::OnDropTarget`.
+    OnDragEnter,
+
+    /// When a drag and drop operation moves over a control registered with `ControlHandle::set_drop_target`.
+    /// The effect chosen in `OnDragEnter` (or in the previous `OnDragOver`) is kept unless the handler changes it.
+    OnDragOver,
+
+    /// When a drag and drop operation leaves a control registered with `ControlHandle::set_drop_target` without dropping.
+    OnDragLeave,
+
+    /// When data is dropped on a control registered with `ControlHandle::set_drop_target`.
+    /// Not raised if the drop effect was `DropEffect::None`.
+    OnDragDrop,
+
+    /// When another instance of the application is started and forwards its command line.
+    /// Only raised on the window passed to `SingleInstanceGuard::listen`. See `EventData::OnSecondInstance`.
+    OnSecondInstance,
+
+    /// When a window receives a `WM_COPYDATA` message, for example from `Window::send_copydata`. See `EventData::OnCopyData`.
+    OnCopyData,
+
+    /// When the keyboard layout of a window was changed by the user (`WM_INPUTLANGCHANGE`). Sent to the top level window and its children.
+    /// Read the language identifier of the new layout with `EventData::OnInputLanguageChanged`.
+    ///
+    /// This is raised after the change. The system first sends a change request (`WM_INPUTLANGCHANGEREQUEST`) to the
+    /// focused window, which can refuse it. The request is not exposed as an event, use a raw event handler to intercept it.
+    OnInputLanguageChanged,
+
+    /// When the user changed a system setting (`WM_SETTINGCHANGE`). Only sent to the top level windows.
+    /// Read the area of the setting and the `SPI_*` action with `EventData::OnSettingChange`. Common areas:
+    ///   * `"ImmersiveColorSet"`: the light/dark theme or the accent color changed
+    ///   * `"intl"`: the regional settings (locale, number and date formats) changed
+    ///   * `"Policy"`: a group policy changed
+    ///   * `"Environment"`: the user environment variables changed
+    ///
+    /// The event only tells that something changed, the new values must be queried again with the relevant API
+    /// (ex: `SystemParametersInfo` for the mouse and keyboard settings, `DwmGetColorizationColor` for the accent color).
+    /// The running process does not see the new environment variables.
+    OnSettingChange,
+
+    /// When a window receives a registered message it subscribed to with `Window::subscribe_message`.
+    /// Read the message and its parameters with `EventData::OnCustomMessage`. See `nwg::register_message` and `nwg::broadcast_message`.
+    OnCustomMessage,
+
+    /// When the input method editor (IME) starts a composition (`WM_IME_STARTCOMPOSITION`), for example when the user starts typing CJK text.
+    /// Like the other IME events, this is advanced and mostly needed by the controls that draw their own text.
+    /// Edit controls (`TextInput`, `TextBox`, ...) handle the IME themselves.
+    OnImeStartComposition,
+
+    /// When the composition string of the input method editor changes or when a result is committed (`WM_IME_COMPOSITION`).
+    /// Read the composition and the result strings with `EventData::OnImeComposition`.
+    /// Use `ControlHandle::set_ime_position` to place the composition window next to the caret.
+    ///
+    /// The default processing is not prevented: the committed result is still sent to the control as `OnChar` events.
+    OnImeComposition,
+
+    /// When the input method editor ends a composition (`WM_IME_ENDCOMPOSITION`)
+    OnImeEndComposition,
+
+    /// When the user asks for help (`WM_HELP`): F1 is pressed, or a control is clicked after the title bar help button (see `Window::set_help_button`).
+    /// The source is the control that has the focus (or that was clicked), or the window itself for a menu.
+    /// Read the details with `EventData::OnHelp`.
+    OnHelp,
+
+    /// When a button is clicked. Similar to a MouseUp event, but only for button control.
+    /// Read how the button was activated (mouse, keyboard or `Button::click`) with `EventData::OnButtonClick`.
     OnButtonClick,
 
     /// When a button is clicked twice rapidly
     OnButtonDoubleClick,
 
+    /// When the dropdown arrow of a split button (`ButtonFlags::SPLIT`) is clicked. Clicking the rest of the button still raises `OnButtonClick`.
+    /// Read the rectangle of the button with `EventData::OnButtonDropdown` to position the dropdown menu.
+    OnButtonDropdown,
+
     /// When a label is clicked
     OnLabelClick,
 
@@ -123,6 +362,9 @@ pub enum Event {
     /// When the current selection of the combobox was changed
     OnComboxBoxSelection,
 
+    /// When an item of an owner drawn combobox must be painted. See `EventData::OnComboBoxDraw`
+    OnComboBoxDraw,
+
     /// When the date select dropdown is expanded
     OnDatePickerDropdown,
 
@@ -135,9 +377,20 @@ pub enum Event {
     /// When an item on a list box is clicked twice
     OnListBoxDoubleClick,
 
-    /// When an item on a list box is selected
+    /// When a list box has acquired the input focus
+    OnListBoxFocus,
+
+    /// When a list box has lost the input focus
+    OnListBoxFocusLost,
+
+    /// When an item on a list box is selected. 
+    /// Read the old and the new selection with `EventData::OnListBoxSelect`.
     OnListBoxSelect,
 
+    /// When a row of a virtual list box (`ListBoxFlags::VIRTUAL`) is painted and needs its text.
+    /// Give the text with `EventData::OnListBoxGetText`.
+    OnListBoxGetText,
+
     /// The selected tab of a TabsContainer changed
     TabsContainerChanged,
 
@@ -147,7 +400,9 @@ pub enum Event {
     /// When the trackbar thumb is released by the user
     TrackBarUpdated,
 
-    /// When a menu control is opened
+    /// When a menu or a submenu is about to open (`WM_INITMENUPOPUP`). The source is the menu that opens.
+    /// `EventData::OnMenuOpen` can fill the menu at this moment, so that large or dynamic menus (ex: recent files, plugins)
+    /// are only built when the user opens them. See `MenuOpenData`.
     OnMenuOpen,
 
     /// When the user enters the menu modal loop
@@ -159,7 +414,7 @@ pub enum Event {
     /// When a menu is hovered (either through mouse or keyboard)
     OnMenuHover,
 
-    /// When the user selects on a menu item
+    /// When the user selects on a menu item. Also raised on a window for the custom items of its system menu (see `SystemMenu::append`).
     OnMenuItemSelected,
 
     /// When the user hovers over a callback tooltip
@@ -173,6 +428,7 @@ pub enum Event {
     OnTreeViewDoubleClick,
 
     /// When the user has clicked the right mouse button within the control.
+    /// Generates an `EventData::OnTreeViewRightClick` with the position of the click. Use `TreeView::hit_test` to find the clicked item.
     OnTreeViewRightClick,
 
     /// When begins in-place editing of the specified item's text.
@@ -190,9 +446,17 @@ pub enum Event {
     /// When an item is removed from the treeview. The item being deleted is passed in `EventData::OnTreeItemDelete`
     OnTreeItemDelete,
 
-    /// When an item is expanded. Generates a `EventData::OnTreeItemDelete`
+    /// After an item was expanded or collapsed. Generates a `EventData::OnTreeItemUpdate` with a `TreeItemAction::Expand` action.
+    /// `EventData::on_tree_item_expand` returns the item and the direction.
+    ///
+    /// Expanding an item raises two events. `OnTreeItemExpanding` is raised first and can cancel the operation
+    /// (ex: to fill the children of an item before it is displayed). `OnTreeItemExpanded` is raised once the item has changed.
     OnTreeItemExpanded,
 
+    /// Before an item is expanded or collapsed. Generates a `EventData::OnTreeItemExpanding` that can cancel the operation.
+    /// See `OnTreeItemExpanded`.
+    OnTreeItemExpanding,
+
     /// When the state of a tree item is changed.
     OnTreeItemChanged,
 
@@ -222,7 +486,7 @@ pub enum Event {
     OnListViewClick,
 
     /// When the user has clicked the right mouse button within the control
-    /// Generates an `EventData::ListViewItemIndex`
+    /// Generates an `EventData::ListViewItemIndex`. The position of the click can be read with `GlobalCursor::local_position`
     OnListViewRightClick,
 
     /// When the user has clicked the left mouse button within the control twice rapidly
@@ -266,6 +530,15 @@ pub enum Event {
 
     /// When a user clicks on the X button of a window
     OnWindowClose,
+
+    /// When a device or a volume is added or removed from the system. See `EventData::OnDeviceChange`
+    /// and `Window::set_device_notifications`
+    OnDeviceChange,
+
+    /// When a child control sends a `WM_NOTIFY` or a `WM_COMMAND` notification that is not handled by NWG.
+    /// This is raised for controls that NWG does not wrap (ex: a third party common control).
+    /// See `EventData::OnRawNotification`. The event handle is the handle of the control that sent the notification.
+    OnRawNotification,
 }
 
 
@@ -278,6 +551,12 @@ pub enum EventData {
     /// Sets if the window should be closed after the event
     OnWindowClose(WindowCloseData),
 
+    /// The position of a context menu request and a flag to suppress the default context menu of the control
+    OnContextMenu(ContextMenuData),
+
+    /// The system command and a flag to prevent its default action
+    OnSysCommand(SysCommandData),
+
     /// Contains the default maximized position and dimensions, and the default minimum and maximum tracking sizes. 
     /// An application can override the defaults by setting the members of this event.
     OnMinMaxInfo(MinMaxInfo),
@@ -287,10 +566,10 @@ pub enum EventData {
     OnTooltipText(ToolTipTextData),
 
     /// The character entered by a user by an `OnChar` event
-    OnChar(char),
+    OnChar(CharData),
 
     /// The windows key code entered by a user. See the `nwg::keys` module
-    OnKey(u32),
+    OnKey(KeyData),
 
     /// Hold resources that will most likely be used during painting. 
     OnPaint(PaintData),
@@ -299,6 +578,11 @@ pub enum EventData {
     /// a negative value indicates that the wheel was rotated to the left.
     OnMouseWheel(i32),
 
+    /// The delta value of a horizontal mouse wheel event, in the same unit as `OnMouseWheel` (`WHEEL_DELTA` is 120).
+    /// A positive value indicates that the wheel was tilted (or the content scrolled) to the right;
+    /// a negative value indicates that the wheel was tilted to the left.
+    OnMouseHWheel(i32),
+
     /// The path to one or more files that were dropped in the application
     OnFileDrop(DropFiles),
 
@@ -309,11 +593,20 @@ pub enum EventData {
     /// The handle to the item being changed.
     #[cfg(feature="tree-view")]
     OnTreeItemUpdate{ item: crate::TreeItem, action: crate::TreeItemAction },
+
+    /// The item that is about to be expanded or collapsed.
+    #[cfg(feature="tree-view")]
+    OnTreeItemExpanding(TreeItemExpandingData),
     
     /// When ends the editing of a treeview item's label.
     #[cfg(feature="tree-view")]
     OnTreeViewEndItemEdit{ f_cancel: bool, new_text: String },
 
+    /// The position of a right click in a treeview, in client coordinates of the treeview, in physical pixels.
+    /// This is the coordinate space expected by `TreeView::hit_test`.
+    #[cfg(feature="tree-view")]
+    OnTreeViewRightClick { x: i32, y: i32 },
+
     /// The handles the the old item and the new item.
     #[cfg(feature="tree-view")]
     OnTreeItemSelectionChanged{ old: crate::TreeItem, new: crate::TreeItem },
@@ -326,6 +619,88 @@ pub enum EventData {
     /// Row index, column index, and selected state of the list view item that raised the event
     #[cfg(feature="list-view")]
     OnListViewItemChanged { row_index: usize, column_index: usize, selected: bool },
+
+    /// The notification code and the source of a notification that is not handled by NWG
+    OnRawNotification(RawNotificationData),
+
+    /// The item of an owner drawn combobox to paint
+    OnComboBoxDraw(ComboBoxDrawData),
+
+    /// The kind of device change and, when the system sends it, the path of the device.
+    /// For a device interface, the path is the device interface name (ex: `\\?\USB#VID_...`).
+    /// For a volume, the path is the root of the drive (ex: `E:\`).
+    OnDeviceChange { kind: DeviceChangeKind, path: Option<String> },
+
+    /// The drag rectangle of a window being resized and the edge that is dragged
+    OnSizing(SizingData),
+
+    /// The new position of a splitter along its axis (the size of the first panel) and the distance it moved since the last event.
+    OnSplitterMove { position: i32, delta: i32 },
+
+    /// What made a layout update its children
+    OnLayout(LayoutCause),
+
+    /// The id, the position and the state of a pointer
+    OnPointer(PointerData),
+
+    /// The dragged data and the drop effect of a drop target event
+    #[cfg(feature="drag-drop")]
+    OnDropTarget(DropTargetData),
+
+    /// The command line arguments of the second instance, including the executable path
+    OnSecondInstance(Vec<String>),
+
+    /// The id and a copy of the bytes of a `WM_COPYDATA` message
+    OnCopyData { id: usize, data: Vec<u8> },
+
+    /// The language identifier of the new keyboard layout. See `nwg::keyboard::current_layout`
+    OnInputLanguageChanged(u16),
+
+    /// The area of the setting (empty if the change has no area, ex: a mouse setting) and the `SPI_*` action that changed it
+    /// (0 if the setting was not changed by `SystemParametersInfo`)
+    OnSettingChange { area: String, action: u32 },
+
+    /// The identifier of a registered message and its parameters
+    OnCustomMessage { msg: u32, wparam: usize, lparam: isize },
+
+    /// `by_click` is `true` if the window was activated by a mouse click (`WA_CLICKACTIVE`).
+    /// `minimized` is `true` if the window is minimized while being activated.
+    OnWindowActivate { by_click: bool, minimized: bool },
+
+    /// How a button was activated. `programmatic` is `true` for `Button::click` and `BM_CLICK`.
+    /// Otherwise, `by_keyboard` is `true` if the button was activated with Space or Enter and `false` for a mouse click.
+    ///
+    /// The source is tracked exactly for the `Button` controls. For the other button controls (check boxes, radio buttons, ...),
+    /// `programmatic` is always `false` and `by_keyboard` is guessed from the state of the Space and Enter keys.
+    OnButtonClick { by_keyboard: bool, programmatic: bool },
+
+    /// The rectangle of the split button that raised `OnButtonDropdown`, as `[left, top, right, bottom]` in physical pixels
+    /// and in screen coordinates. Pass `(left, bottom)` to `Menu::popup` to open a menu under the button.
+    OnButtonDropdown { rect: [i32; 4] },
+
+    /// The row of a virtual list box that needs its text
+    OnListBoxGetText(ListBoxGetTextData),
+
+    /// The menu that is about to open
+    OnMenuOpen(MenuOpenData),
+
+    /// The composition and the result strings of the input method editor
+    OnImeComposition(ImeCompositionData),
+
+    /// The fields of the `HELPINFO` structure of a help request
+    OnHelp(HelpData),
+
+    /// The scroll command of a horizontal or vertical scroll event
+    OnScroll(ScrollKind),
+
+    /// The item selected before and after the user changed the selection of a list box.
+    /// For multi selection list boxes, the values are the item that has the focus rectangle.
+    OnListBoxSelect { old: Option<usize>, new: Option<usize> },
+
+    /// The text of a `TextInput` or a `TextBox` changed. `programmatic` is `true` only for the change made by the
+    /// `set_text`, `set_text_unix2dos`, `clear`, `append` and `appendln` methods of the control, while they run. The changes made by the user
+    /// (typing, paste, undo) and by raw `WM_SETTEXT`/`EM_REPLACESEL` messages report `false`.
+    OnTextInput { programmatic: bool },
 }
 
 impl EventData {
@@ -346,10 +721,51 @@ impl EventData {
         }
     }
 
+    /// Unwraps event data into the position of a splitter and the distance it moved. Panics if it's not the right type.
+    pub fn on_splitter_move(&self) -> (i32, i32) {
+        match self {
+            EventData::OnSplitterMove { position, delta } => (*position, *delta),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into what made a layout update its children. Panics if it's not the right type.
+    pub fn on_layout(&self) -> LayoutCause {
+        match self {
+            EventData::OnLayout(cause) => *cause,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&PointerData`. Panics if it's not the right type.
+    pub fn on_pointer(&self) -> &PointerData {
+        match self {
+            EventData::OnPointer(p) => p,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&SizingData`. Panics if it's not the right type.
+    pub fn on_sizing(&self) -> &SizingData {
+        match self {
+            EventData::OnSizing(s) => s,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
     /// Unwraps event data into a `char`. Panics if it's not the right type.
     pub fn on_char(&self) -> char {
         match self {
-            EventData::OnChar(c) => *c,
+            EventData::OnChar(c) => c.char(),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&CharData`. Panics if it's not the right type.
+    /// Use this instead of `on_char` to consume the character.
+    pub fn on_char_data(&self) -> &CharData {
+        match self {
+            EventData::OnChar(c) => c,
             d => panic!("Wrong data type: {:?}", d)
         }
     }
@@ -370,10 +786,204 @@ impl EventData {
         }
     }
 
+    /// Unwraps event data into a `&DropTargetData`. Panics if it's not the right type.
+    #[cfg(feature="drag-drop")]
+    pub fn on_drop_target(&self) -> &DropTargetData {
+        match self {
+            EventData::OnDropTarget(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the command line arguments of a second instance. Panics if it's not the right type.
+    pub fn on_second_instance(&self) -> &[String] {
+        match self {
+            EventData::OnSecondInstance(args) => args,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the id and the bytes of a `WM_COPYDATA` message. Panics if it's not the right type.
+    pub fn on_copy_data(&self) -> (usize, &[u8]) {
+        match self {
+            EventData::OnCopyData { id, data } => (*id, data),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the delta of a vertical mouse wheel event. Panics if it's not the right type.
+    pub fn on_mouse_wheel(&self) -> i32 {
+        match self {
+            EventData::OnMouseWheel(delta) => *delta,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the delta of a horizontal mouse wheel event (positive to the right). Panics if it's not the right type.
+    pub fn on_mouse_hwheel(&self) -> i32 {
+        match self {
+            EventData::OnMouseHWheel(delta) => *delta,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the language identifier of a keyboard layout. Panics if it's not the right type.
+    pub fn on_input_language_changed(&self) -> u16 {
+        match self {
+            EventData::OnInputLanguageChanged(language) => *language,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the identifier and the parameters of a registered message. Panics if it's not the right type.
+    pub fn on_custom_message(&self) -> (u32, usize, isize) {
+        match self {
+            EventData::OnCustomMessage { msg, wparam, lparam } => (*msg, *wparam, *lparam),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the area and the `SPI_*` action of a setting change. Panics if it's not the right type.
+    pub fn on_setting_change(&self) -> (&str, u32) {
+        match self {
+            EventData::OnSettingChange { area, action } => (area, *action),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into how a window was activated, as `(by_click, minimized)`. Panics if it's not the right type.
+    pub fn on_window_activate(&self) -> (bool, bool) {
+        match self {
+            EventData::OnWindowActivate { by_click, minimized } => (*by_click, *minimized),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into how a button was activated, as `(by_keyboard, programmatic)`. Panics if it's not the right type.
+    pub fn on_button_click(&self) -> (bool, bool) {
+        match self {
+            EventData::OnButtonClick { by_keyboard, programmatic } => (*by_keyboard, *programmatic),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the screen rectangle of a split button, as `[left, top, right, bottom]`. Panics if it's not the right type.
+    pub fn on_button_dropdown(&self) -> [i32; 4] {
+        match self {
+            EventData::OnButtonDropdown { rect } => *rect,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&MenuOpenData`. Panics if it's not the right type.
+    pub fn on_menu_open(&self) -> &MenuOpenData {
+        match self {
+            EventData::OnMenuOpen(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&ListBoxGetTextData`. Panics if it's not the right type.
+    pub fn on_list_box_get_text(&self) -> &ListBoxGetTextData {
+        match self {
+            EventData::OnListBoxGetText(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&HelpData`. Panics if it's not the right type.
+    pub fn on_help(&self) -> &HelpData {
+        match self {
+            EventData::OnHelp(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&ContextMenuData`. Panics if it's not the right type.
+    pub fn on_context_menu(&self) -> &ContextMenuData {
+        match self {
+            EventData::OnContextMenu(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&SysCommandData`. Panics if it's not the right type.
+    pub fn on_sys_command(&self) -> &SysCommandData {
+        match self {
+            EventData::OnSysCommand(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&ImeCompositionData`. Panics if it's not the right type.
+    pub fn on_ime_composition(&self) -> &ImeCompositionData {
+        match self {
+            EventData::OnImeComposition(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the `programmatic` flag of a text change. Panics if it's not the right type.
+    pub fn on_text_input(&self) -> bool {
+        match self {
+            EventData::OnTextInput { programmatic } => *programmatic,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the old and the new selection of a list box. Panics if it's not the right type.
+    pub fn on_list_box_select(&self) -> (Option<usize>, Option<usize>) {
+        match self {
+            EventData::OnListBoxSelect { old, new } => (*old, *new),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `ScrollKind`. Panics if it's not the right type.
+    pub fn on_scroll(&self) -> ScrollKind {
+        match self {
+            EventData::OnScroll(kind) => *kind,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&RawNotificationData`. Panics if it's not the right type.
+    pub fn on_raw_notification(&self) -> &RawNotificationData {
+        match self {
+            EventData::OnRawNotification(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&ComboBoxDrawData`. Panics if it's not the right type.
+    pub fn on_combo_box_draw(&self) -> &ComboBoxDrawData {
+        match self {
+            EventData::OnComboBoxDraw(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the kind of device change and the path of the device. Panics if it's not the right type.
+    pub fn on_device_change(&self) -> (DeviceChangeKind, Option<&str>) {
+        match self {
+            EventData::OnDeviceChange { kind, path } => (*kind, path.as_ref().map(|p| p as &str)),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
     /// Unwraps event data into the virtual key code for `OnKeyPress` and `OnKeyRelease`
     pub fn on_key(&self) -> u32 {
         match self {
-            EventData::OnKey(key) => *key,
+            EventData::OnKey(key) => key.key(),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&KeyData` for `OnKeyPress`, `OnKeyRelease`, `OnSysKeyPress` and `OnSysKeyRelease`.
+    /// Use this instead of `on_key` to consume the key.
+    pub fn on_key_data(&self) -> &KeyData {
+        match self {
+            EventData::OnKey(key) => key,
             d => panic!("Wrong data type: {:?}", d)
         }
     }
@@ -396,6 +1006,26 @@ impl EventData {
         }
     }
 
+    /// unwraps the data of an `OnTreeItemExpanded` event into the tree item that was expanded or collapsed and `true` if it was expanded
+    #[cfg(feature="tree-view")]
+    pub fn on_tree_item_expand(&self) -> (&crate::TreeItem, bool) {
+        use crate::{TreeItemAction, ExpandState};
+
+        match self {
+            EventData::OnTreeItemUpdate { item, action: TreeItemAction::Expand(state) } => (item, matches!(state, ExpandState::Expand | ExpandState::ExpandPartial)),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// unwraps event data into a `&TreeItemExpandingData`
+    #[cfg(feature="tree-view")]
+    pub fn on_tree_item_expanding(&self) -> &TreeItemExpandingData {
+        match self {
+            EventData::OnTreeItemExpanding(data) => data,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
     /// unwraps event data into the removed tree item
     #[cfg(feature="tree-view")]
     pub fn on_tree_item_selection_changed(&self) -> (&crate::TreeItem, &crate::TreeItem) {
@@ -416,6 +1046,15 @@ impl EventData {
         }
     }
     
+    /// unwraps event data into the position of a right click in a treeview (x, y)
+    #[cfg(feature="tree-view")]
+    pub fn on_tree_view_right_click(&self) -> (i32, i32) {
+        match self {
+            &EventData::OnTreeViewRightClick { x, y } => (x, y),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
     /// unwraps event data into the indices of a list view index (row_index, column_index)
     #[cfg(feature="list-view")]
     pub fn on_list_view_item_index(&self) -> (usize, usize) {
@@ -441,9 +1080,9 @@ impl EventData {
 //
 
 use winapi::um::commctrl::NMTTDISPINFOW;
-use winapi::um::winuser::{PAINTSTRUCT, MINMAXINFO, BeginPaint, EndPaint};
+use winapi::um::winuser::{PAINTSTRUCT, MINMAXINFO, NMHDR, DRAWITEMSTRUCT, BeginPaint, EndPaint};
 use winapi::um::shellapi::{HDROP, DragFinish};
-use winapi::shared::windef::{HWND, POINT};
+use winapi::shared::windef::{HWND, HDC, HBITMAP, HGDIOBJ, HMENU, POINT, RECT};
 use std::fmt;
 
 /// A wrapper structure that sets the tooltip text on an `OnTooltipText` callback
@@ -466,22 +1105,32 @@ impl ToolTipTextData {
     }
 
     /// Sets the text of the callback. This function will copy the text.
-    /// WINAPI does not easily allow tooltips with more than 79 characters (80 with NULL)
-    /// With a text > 79 characters, this method will do nothing.
+    /// Texts of 79 characters or less are copied in the notification. Longer texts are copied in a buffer
+    /// owned by NWG that stays valid until the next call to this method.
     pub fn set_text<'b>(&self, text: &'b str) {
         use crate::win32::base_helper::to_utf16;
-        use std::ptr;
-        
-        let text_len = text.len();
-        if text_len > 79 {
-            return;
+        use std::{ptr, cell::RefCell};
+
+        thread_local! {
+            static LONG_TEXT: RefCell<Vec<u16>> = const { RefCell::new(Vec::new()) };
         }
 
         self.clear();
+
+        let local_text = to_utf16(text);
+        let text_len = local_text.len() - 1;
         unsafe {
             let data = &mut *self.data;
-            let local_text = to_utf16(text);
-            ptr::copy_nonoverlapping(local_text.as_ptr(), data.szText.as_mut_ptr(), text_len);
+            if text_len > 79 {
+                data.lpszText = LONG_TEXT.with(|buffer| {
+                    let mut buffer = buffer.borrow_mut();
+                    *buffer = local_text;
+                    buffer.as_mut_ptr()
+                });
+            } else {
+                data.lpszText = data.szText.as_mut_ptr();
+                ptr::copy_nonoverlapping(local_text.as_ptr(), data.szText.as_mut_ptr(), text_len);
+            }
         }
     }
 
@@ -504,13 +1153,29 @@ impl fmt::Debug for ToolTipTextData {
 }
 
 
-/// Opaque type that manages if a window should be closed after an OnClose event
+/**
+    Opaque type that manages if a window should be closed after an OnClose event
+
+    Async confirmation: instead of opening a modal dialog inside the close handler (which starts a nested events loop),
+    the handler can veto the close with `close(false)`, start the confirmation work, and later call `Window::request_close`.
+    `request_close` posts a `WM_CLOSE` flagged as confirmed: `OnWindowClose` is raised again with `confirmed()` returning `true`
+    and the window closes even if the handler calls `close(false)`.
+
+    Because the message is posted, `request_close` can be called from inside the `OnWindowClose` handler itself.
+    The new event is only raised after the current handler returns.
+*/
 pub struct WindowCloseData {
-    pub(crate) data: *mut bool
+    pub(crate) data: *mut bool,
+    pub(crate) confirmed: bool,
 }
 
 impl WindowCloseData {
 
+    /// Returns true if the close was requested with `Window::request_close`. A confirmed close cannot be vetoed.
+    pub fn confirmed(&self) -> bool {
+        self.confirmed
+    }
+
     /// Sets if the window should close after the event
     pub fn close(&self, value: bool) {
         unsafe{ *self.data = value; }
@@ -524,44 +1189,711 @@ impl WindowCloseData {
 
 impl fmt::Debug for WindowCloseData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "WindowCloseData({})", self.closing())
+        write!(f, "WindowCloseData {{ closing: {}, confirmed: {} }}", self.closing(), self.confirmed)
     }
 }
 
+/**
+    The data of a `OnContextMenu` event
 
-/// Opaque type over a paint event's data
-#[derive(Debug)]
-pub struct PaintData {
-    pub(crate) hwnd: HWND
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn show_menu(data: &nwg::EventData, menu: &nwg::Menu) {
+        let menu_data = data.on_context_menu();
+        menu_data.suppress_default();
+
+        let (x, y) = menu_data.position().unwrap_or_else(nwg::GlobalCursor::position);
+        menu.popup(x, y);
+    }
+    ```
+*/
+pub struct ContextMenuData {
+    pub(crate) suppress: *mut bool,
+    pub(crate) position: Option<(i32, i32)>,
 }
 
-impl PaintData {
+impl ContextMenuData {
 
-    /// Wrapper over BeginPaint
-    pub fn begin_paint(&self) -> PAINTSTRUCT {
-        unsafe {
-            let mut paint: PAINTSTRUCT = ::std::mem::zeroed();
-            BeginPaint(self.hwnd, &mut paint);
-            paint
-        }
+    /// Returns the position of the mouse in screen coordinates (physical pixels), or `None` if the menu was requested with the keyboard.
+    /// For the keyboard, the application chooses the position, usually next to the focused item.
+    pub fn position(&self) -> Option<(i32, i32)> {
+        self.position
     }
 
-    /// Wrapper over EndPaint
-    pub fn end_paint(&self, p: &PAINTSTRUCT) {
-        unsafe {
-            EndPaint(self.hwnd, p);
-        }
+    /// Prevents the control from showing its default context menu after the event.
+    /// The request is not sent to the parent control either.
+    pub fn suppress_default(&self) {
+        unsafe{ *self.suppress = true; }
     }
 
+    /// Returns true if the default context menu was suppressed
+    pub fn default_suppressed(&self) -> bool {
+        unsafe{ *self.suppress }
+    }
 }
 
-
-/// Opaque type over one or more dragged files.
-pub struct DropFiles {
-    pub(crate) drop: HDROP,
+impl fmt::Debug for ContextMenuData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ContextMenuData {{ position: {:?}, suppressed: {} }}", self.position, self.default_suppressed())
+    }
 }
 
-impl DropFiles {
+
+/**
+    The data of an `OnSysCommand` event.
+
+    `command` is the `wParam` of `WM_SYSCOMMAND` masked with `0xFFF0`: the four low bits are used internally by the system
+    (ex: they tell which edge is dragged for `SC_SIZE`), so the raw value must never be compared to the constants directly.
+    Compare `command` to the `SC_*` constants of `winapi::um::winuser`:
+
+    * `SC_MINIMIZE`, `SC_MAXIMIZE`, `SC_RESTORE`: The buttons of the title bar, the items of the system menu, or a double click on the title bar
+    * `SC_CLOSE`: The close button, the item of the system menu, or Alt+F4. The window receives `WM_CLOSE` (`OnWindowClose`) after the event
+    * `SC_MOVE`, `SC_SIZE`: The move and resize modes of the system menu, or a drag of the title bar or of a border
+    * `SC_KEYMENU`, `SC_MOUSEMENU`: The menu bar or the system menu is opened with the keyboard (ex: Alt, Alt+Space) or with the mouse
+    * `SC_CONTEXTHELP`: The help button (`?`) of the title bar
+    * `SC_SCREENSAVE`, `SC_MONITORPOWER`: The screen saver is about to start, or the display is about to be turned off
+
+    Calling `consume` prevents the default action: the window is not minimized, maximized, closed, etc. The handler can then
+    replace the action (ex: confirm before closing, minimize to the system tray).
+
+    ```rust
+    use native_windows_gui as nwg;
+    use winapi::um::winuser::SC_MAXIMIZE;
+
+    fn no_maximize(data: &nwg::EventData) {
+        let data = data.on_sys_command();
+        if data.command() == SC_MAXIMIZE as u32 {
+            data.consume();
+        }
+    }
+    ```
+*/
+pub struct SysCommandData {
+    pub(crate) command: u32,
+    pub(crate) consumed: *mut bool,
+}
+
+impl SysCommandData {
+
+    /// Returns the system command, masked with `0xFFF0`. See `SysCommandData`
+    pub fn command(&self) -> u32 {
+        self.command
+    }
+
+    /// Prevents the default action of the system command
+    pub fn consume(&self) {
+        unsafe { *self.consumed = true; }
+    }
+
+    /// Returns true if the system command was consumed by an event handler
+    pub fn consumed(&self) -> bool {
+        unsafe { *self.consumed }
+    }
+}
+
+impl fmt::Debug for SysCommandData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SysCommandData {{ command: {:#X}, consumed: {} }}", self.command, self.consumed())
+    }
+}
+
+/**
+    The character of an `OnChar` event.
+
+    Calling `consume` tells NWG that the character was handled by the application. In that case, the `WM_CHAR` message
+    is not passed to the default window procedure of the control (nor to any other subclass bound before the event handler).
+    For example, a `TextInput` will not display a consumed character. This is how input filtering should be done:
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn digits_only(data: &nwg::EventData) {
+        let data = data.on_char_data();
+        if !(data.char().is_ascii_digit() || data.char() == '\u{8}') {
+            data.consume();
+        }
+    }
+    ```
+
+    Only applies to `WM_CHAR`. Consuming a character does not stop the other event handlers bound to the same
+    control from receiving the `OnChar` event.
+
+    The `lParam` of the message is decoded like for `KeyData`: the scan code and the flags are the ones of the key that produced the character.
+*/
+pub struct CharData {
+    pub(crate) ch: char,
+    pub(crate) flags: u32,
+    pub(crate) handled: *mut bool,
+}
+
+impl CharData {
+
+    /// Returns the character entered by the user
+    pub fn char(&self) -> char {
+        self.ch
+    }
+
+    /// Prevent the character from reaching the default control procedure
+    pub fn consume(&self) {
+        unsafe { *self.handled = true; }
+    }
+
+    /// Returns true if the character was consumed by an event handler
+    pub fn consumed(&self) -> bool {
+        unsafe { *self.handled }
+    }
+
+    /// Returns the hardware scan code of the key (bits 16 to 23 of the `lParam` of the message)
+    pub fn scan_code(&self) -> u32 {
+        (self.flags >> 16) & 0xFF
+    }
+
+    /// Returns true for the extended keys (bit 24 of the `lParam`): the right Ctrl and Alt, the numpad Enter and `/`,
+    /// and the Insert, Delete, Home, End, Page Up, Page Down and arrow keys that are not on the numpad
+    pub fn extended(&self) -> bool {
+        self.flags & (1 << 24) != 0
+    }
+
+    /// Returns true if the key was already down before the message (bit 30 of the `lParam`), i.e. the message was generated by the key auto-repeat
+    pub fn repeat(&self) -> bool {
+        self.flags & (1 << 30) != 0
+    }
+
+    /// Returns the number of times the keystroke is repeated in this message because the application did not process the messages fast enough (bits 0 to 15 of the `lParam`)
+    pub fn repeat_count(&self) -> u32 {
+        self.flags & 0xFFFF
+    }
+
+}
+
+impl fmt::Debug for CharData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.ch)
+    }
+}
+
+
+/**
+    The virtual key code of an `OnKeyPress`, `OnKeyRelease`, `OnSysKeyPress` or `OnSysKeyRelease` event.
+
+    Calling `consume` tells NWG that the key was handled by the application and that the message 
+    (`WM_KEYDOWN`, `WM_KEYUP`, `WM_SYSKEYDOWN` or `WM_SYSKEYUP`) must not be passed to the default window procedure of the control.
+    This can be used to disable the default navigation of a control (ex: the arrow keys in a list box).
+
+    Note that `WM_CHAR` messages are generated from `WM_KEYDOWN` in the events loop before the control receives it.
+    Consuming a key press does not stop the matching `OnChar` event; consume the character to filter text input.
+
+    The `lParam` of the key message is also decoded, to tell apart the keys that have the same virtual key code
+    (ex: left and right Ctrl with `extended`, or the Enter of the numpad):
+
+    | Bits  | Value                                    | Method         |
+    |-------|------------------------------------------|----------------|
+    | 0-15  | The repeat count                         | `repeat_count` |
+    | 16-23 | The scan code                            | `scan_code`    |
+    | 24    | The key is an extended key               | `extended`     |
+    | 29    | Alt is down (always 0 for `WM_KEYDOWN`)  |                |
+    | 30    | The key was down before the message      | `repeat`       |
+    | 31    | The key is being released (`WM_KEYUP`)   |                |
+*/
+pub struct KeyData {
+    pub(crate) key: u32,
+    pub(crate) flags: u32,
+    pub(crate) handled: *mut bool,
+}
+
+impl KeyData {
+
+    /// Returns the virtual key code. See the `nwg::keys` module
+    pub fn key(&self) -> u32 {
+        self.key
+    }
+
+    /// Prevent the key message from reaching the default control procedure
+    pub fn consume(&self) {
+        unsafe { *self.handled = true; }
+    }
+
+    /// Returns true if the key was consumed by an event handler
+    pub fn consumed(&self) -> bool {
+        unsafe { *self.handled }
+    }
+
+    /// Returns the hardware scan code of the key (bits 16 to 23 of the `lParam` of the message)
+    pub fn scan_code(&self) -> u32 {
+        (self.flags >> 16) & 0xFF
+    }
+
+    /// Returns true for the extended keys (bit 24 of the `lParam`): the right Ctrl and Alt, the numpad Enter and `/`,
+    /// and the Insert, Delete, Home, End, Page Up, Page Down and arrow keys that are not on the numpad
+    pub fn extended(&self) -> bool {
+        self.flags & (1 << 24) != 0
+    }
+
+    /// Returns true if the key was already down before the message (bit 30 of the `lParam`), i.e. the message was generated by the key auto-repeat
+    pub fn repeat(&self) -> bool {
+        self.flags & (1 << 30) != 0
+    }
+
+    /// Returns the number of times the keystroke is repeated in this message because the application did not process the messages fast enough (bits 0 to 15 of the `lParam`)
+    pub fn repeat_count(&self) -> u32 {
+        self.flags & 0xFFFF
+    }
+
+}
+
+impl fmt::Debug for KeyData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.key)
+    }
+}
+
+
+/**
+    A `WM_NOTIFY` or `WM_COMMAND` notification sent by a control that NWG does not handle.
+
+    This is an escape hatch to handle controls that are not wrapped by NWG. The notification code and the id
+    of the source control are always available. For `WM_NOTIFY`, the raw notification header can also be read.
+    Many notifications pass a larger structure that begins with a `NMHDR` (ex: `NMLISTVIEW`). Interpreting
+    the header as one of these structures is unsafe: the caller must check the source control class and the
+    notification code before casting, and must not keep the reference after the event handler returns.
+*/
+pub struct RawNotificationData {
+    pub(crate) notify: bool,
+    pub(crate) code: u32,
+    pub(crate) id: usize,
+    pub(crate) hwnd: HWND,
+    pub(crate) header: *const NMHDR,
+}
+
+impl RawNotificationData {
+
+    /// Returns true if the notification was sent with `WM_NOTIFY` or false if it was sent with `WM_COMMAND`
+    pub fn is_notify(&self) -> bool {
+        self.notify
+    }
+
+    /// The notification code. `NMHDR.code` for `WM_NOTIFY` or the high word of `wParam` for `WM_COMMAND`
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// The identifier of the control that sent the notification
+    pub fn control_id(&self) -> usize {
+        self.id
+    }
+
+    /// The handle of the control that sent the notification
+    pub fn control(&self) -> crate::ControlHandle {
+        crate::ControlHandle::Hwnd(self.hwnd)
+    }
+
+    /// Returns the raw notification header for `WM_NOTIFY`, or `None` for `WM_COMMAND`.
+    ///
+    /// # Safety
+    ///
+    /// The reference is only valid for the duration of the event handler.
+    pub unsafe fn header(&self) -> Option<&NMHDR> {
+        self.header.as_ref()
+    }
+
+    /// Reinterprets the notification as a structure that begins with a `NMHDR`. Returns `None` for `WM_COMMAND`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the structure that the source control sends with this notification code
+    /// and the reference is only valid for the duration of the event handler.
+    pub unsafe fn cast<T>(&self) -> Option<&T> {
+        (self.header as *const T).as_ref()
+    }
+
+}
+
+impl fmt::Debug for RawNotificationData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RawNotificationData {{ notify: {}, code: {}, control_id: {} }}", self.notify, self.code, self.id)
+    }
+}
+
+
+/**
+    The item of an owner drawn combobox (`ComboBoxFlags::OWNER_DRAW`) that must be painted.
+
+    The combobox sends `WM_DRAWITEM` to its parent every time an item of the dropdown list or the selection field
+    must be painted. NWG translates this message into an `OnComboBoxDraw` event on the combobox. The item must be painted
+    in `hdc()` inside `rect()`. Both are in physical pixels, relative to the client area of the item's window.
+
+    If the handler does not call `consume`, NWG paints the default item (background and text) after the event handler returns.
+    A handler can also call `draw_default` itself, and then paint over it, before consuming the event.
+
+    ```rust
+    use native_windows_gui as nwg;
+    use winapi::um::wingdi::{CreateSolidBrush, DeleteObject, RGB};
+    use winapi::um::winuser::FillRect;
+    use winapi::shared::windef::RECT;
+
+    fn draw_color_item(data: &nwg::ComboBoxDrawData) {
+        data.draw_default();
+
+        let [left, top, _right, bottom] = data.rect();
+        let swatch = RECT { left: left + 2, top: top + 2, right: left + (bottom - top) - 2, bottom: bottom - 2 };
+        unsafe {
+            let brush = CreateSolidBrush(RGB(255, 0, 0));
+            FillRect(data.hdc(), &swatch, brush);
+            DeleteObject(brush as _);
+        }
+
+        data.consume();
+    }
+    ```
+*/
+pub struct ComboBoxDrawData {
+    pub(crate) inner: *const DRAWITEMSTRUCT,
+    pub(crate) handled: *mut bool,
+}
+
+impl ComboBoxDrawData {
+
+    /// The index of the item to paint. `None` if the combobox is empty or if the selection field has no selected value.
+    pub fn index(&self) -> Option<usize> {
+        let id = unsafe { (&*self.inner).itemID };
+        match id == u32::MAX {
+            true => None,
+            false => Some(id as usize)
+        }
+    }
+
+    /// The display text of the item to paint. Empty if `index` is `None`.
+    pub fn text(&self) -> String {
+        unsafe { combobox_item_text(self.inner) }
+    }
+
+    /// Returns true if the item is selected
+    pub fn selected(&self) -> bool {
+        use winapi::um::winuser::ODS_SELECTED;
+        unsafe { (&*self.inner).itemState & ODS_SELECTED == ODS_SELECTED }
+    }
+
+    /// Returns true if the item has the keyboard focus
+    pub fn focused(&self) -> bool {
+        use winapi::um::winuser::ODS_FOCUS;
+        unsafe { (&*self.inner).itemState & ODS_FOCUS == ODS_FOCUS }
+    }
+
+    /// Returns true if the combobox is disabled
+    pub fn disabled(&self) -> bool {
+        use winapi::um::winuser::ODS_DISABLED;
+        unsafe { (&*self.inner).itemState & ODS_DISABLED == ODS_DISABLED }
+    }
+
+    /// Returns true if the item is painted in the selection field of the combobox instead of the dropdown list
+    pub fn selection_field(&self) -> bool {
+        use winapi::um::winuser::ODS_COMBOBOXEDIT;
+        unsafe { (&*self.inner).itemState & ODS_COMBOBOXEDIT == ODS_COMBOBOXEDIT }
+    }
+
+    /// The device context to paint into. It is only valid during the event.
+    pub fn hdc(&self) -> HDC {
+        unsafe { (&*self.inner).hDC }
+    }
+
+    /// The bounds of the item in physical pixels. As `[left, top, right, bottom]`
+    pub fn rect(&self) -> [i32; 4] {
+        let r = unsafe { &(&*self.inner).rcItem };
+        [r.left, r.top, r.right, r.bottom]
+    }
+
+    /// Paints the item the same way NWG does when the event is not consumed
+    pub fn draw_default(&self) {
+        unsafe { draw_combobox_item_default(self.inner); }
+    }
+
+    /// Tells NWG that the item was painted by the application. The default item will not be painted.
+    pub fn consume(&self) {
+        unsafe { *self.handled = true; }
+    }
+
+    /// Returns true if the item was painted by the application
+    pub fn consumed(&self) -> bool {
+        unsafe { *self.handled }
+    }
+
+}
+
+impl fmt::Debug for ComboBoxDrawData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ComboBoxDrawData {{ index: {:?}, selected: {}, rect: {:?} }}", self.index(), self.selected(), self.rect())
+    }
+}
+
+unsafe fn combobox_item_text(draw: *const DRAWITEMSTRUCT) -> String {
+    use winapi::um::winuser::{CB_GETLBTEXTLEN, CB_GETLBTEXT, CB_ERR, SendMessageW};
+    use crate::win32::base_helper::from_utf16;
+
+    let draw = &*draw;
+    if draw.itemID == u32::MAX {
+        return String::new();
+    }
+
+    let index = draw.itemID as usize;
+    let length = SendMessageW(draw.hwndItem, CB_GETLBTEXTLEN, index, 0);
+    if length == CB_ERR {
+        return String::new();
+    }
+
+    let mut buffer: Vec<u16> = vec![0; (length as usize) + 1];
+    SendMessageW(draw.hwndItem, CB_GETLBTEXT, index, buffer.as_mut_ptr() as _);
+
+    from_utf16(&buffer)
+}
+
+/// Default painting of an owner drawn combobox item: the system background and the item text
+pub(crate) unsafe fn draw_combobox_item_default(draw: *const DRAWITEMSTRUCT) {
+    draw_item_text_default(draw, &combobox_item_text(draw));
+}
+
+/// Default painting of an owner drawn item with the text `text`: the system background, the text and the focus rectangle
+pub(crate) unsafe fn draw_item_text_default(draw: *const DRAWITEMSTRUCT, text: &str) {
+    use winapi::um::winuser::{FillRect, DrawTextW, DrawFocusRect, GetSysColor, GetSysColorBrush};
+    use winapi::um::winuser::{COLOR_WINDOW, COLOR_WINDOWTEXT, COLOR_HIGHLIGHT, COLOR_HIGHLIGHTTEXT, COLOR_GRAYTEXT};
+    use winapi::um::winuser::{ODS_SELECTED, ODS_FOCUS, ODS_DISABLED, ODS_COMBOBOXEDIT, DT_SINGLELINE, DT_VCENTER, DT_LEFT, DT_NOPREFIX};
+    use winapi::um::wingdi::{SetTextColor, SetBkMode, TRANSPARENT};
+    use crate::win32::base_helper::to_utf16;
+
+    let draw = &*draw;
+    let state = draw.itemState;
+
+    let (background, foreground) = if state & ODS_DISABLED == ODS_DISABLED {
+        (COLOR_WINDOW, COLOR_GRAYTEXT)
+    } else if state & ODS_SELECTED == ODS_SELECTED {
+        (COLOR_HIGHLIGHT, COLOR_HIGHLIGHTTEXT)
+    } else {
+        (COLOR_WINDOW, COLOR_WINDOWTEXT)
+    };
+
+    FillRect(draw.hDC, &draw.rcItem, GetSysColorBrush(background));
+
+    let text = to_utf16(text);
+    let mut rect = draw.rcItem;
+    rect.left += 2;
+
+    let old_mode = SetBkMode(draw.hDC, TRANSPARENT as i32);
+    let old_color = SetTextColor(draw.hDC, GetSysColor(foreground));
+    DrawTextW(draw.hDC, text.as_ptr(), -1, &mut rect, DT_SINGLELINE | DT_VCENTER | DT_LEFT | DT_NOPREFIX);
+    SetTextColor(draw.hDC, old_color);
+    SetBkMode(draw.hDC, old_mode);
+
+    if state & ODS_FOCUS == ODS_FOCUS && state & ODS_COMBOBOXEDIT == 0 {
+        DrawFocusRect(draw.hDC, &draw.rcItem);
+    }
+}
+
+
+/// The data of an `OnListBoxGetText` event. The row is painted with the text set by the handler, or left blank.
+pub struct ListBoxGetTextData {
+    pub(crate) index: usize,
+    pub(crate) text: *mut String,
+}
+
+impl ListBoxGetTextData {
+
+    /// The index of the row to paint
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Sets the text of the row
+    pub fn set_text(&self, text: &str) {
+        unsafe {
+            let current = &mut *self.text;
+            current.clear();
+            current.push_str(text);
+        }
+    }
+
+    /// Returns the text set by the handler. Empty if the text was not set yet
+    pub fn text(&self) -> String {
+        unsafe { (&*self.text).clone() }
+    }
+
+}
+
+impl fmt::Debug for ListBoxGetTextData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListBoxGetTextData").field("index", &self.index).finish()
+    }
+}
+
+
+/// Opaque type over a paint event's data
+#[derive(Debug)]
+pub struct PaintData {
+    pub(crate) hwnd: HWND
+}
+
+impl PaintData {
+
+    /// Returns the smallest rectangle that encloses the region that must be repainted, as `[left, top, right, bottom]`.
+    /// The rectangle is in client coordinates, in physical pixels (the same space as `PAINTSTRUCT.rcPaint`).
+    /// Returns `[0, 0, 0, 0]` if nothing needs to be repainted.
+    ///
+    /// This must be called before `begin_paint`, because `BeginPaint` validates the update region.
+    pub fn update_rect(&self) -> [i32; 4] {
+        use winapi::um::winuser::GetUpdateRect;
+        use winapi::shared::windef::RECT;
+
+        let mut r = RECT { left: 0, top: 0, right: 0, bottom: 0 };
+        unsafe { GetUpdateRect(self.hwnd, &mut r, 0); }
+        [r.left, r.top, r.right, r.bottom]
+    }
+
+    /// Wrapper over BeginPaint
+    pub fn begin_paint(&self) -> PAINTSTRUCT {
+        unsafe {
+            let mut paint: PAINTSTRUCT = ::std::mem::zeroed();
+            BeginPaint(self.hwnd, &mut paint);
+            paint
+        }
+    }
+
+    /// Wrapper over EndPaint
+    pub fn end_paint(&self, p: &PAINTSTRUCT) {
+        unsafe {
+            EndPaint(self.hwnd, p);
+        }
+    }
+
+    /// Begins a flicker free paint. Calls `BeginPaint` and returns a guard over an off-screen memory DC.
+    /// See `DoubleBufferedPaint`. Do not call `begin_paint` or `end_paint` when using this method.
+    pub fn double_buffered(&self) -> DoubleBufferedPaint {
+        DoubleBufferedPaint::new(self.hwnd)
+    }
+
+}
+
+/**
+    A paint guard that draws into an off-screen bitmap and copies it to the window with a single `BitBlt` when dropped.
+    Returned by `PaintData::double_buffered`.
+
+    The memory DC is sized to the dirty rectangle (`PAINTSTRUCT.rcPaint`) and its origin is moved so that the drawing code
+    can use the client coordinates of the window. The bitmap content is undefined, so the handler must paint the whole 
+    dirty rectangle, including the background.
+
+    Painting in a memory DC does not remove the flicker caused by the background erase. `WM_ERASEBKGND` must be suppressed
+    by returning a non-zero value from a raw event handler, otherwise the window is cleared before every paint.
+
+    ```rust
+    use native_windows_gui as nwg;
+    use winapi::um::winuser::{FillRect, COLOR_WINDOW, WM_ERASEBKGND};
+    use winapi::shared::windef::{RECT, HBRUSH};
+
+    fn suppress_erase(window: &nwg::Window) {
+        nwg::bind_raw_event_handler(&window.handle, 0x10000, |_hwnd, msg, _w, _l| {
+            match msg {
+                WM_ERASEBKGND => Some(1),
+                _ => None
+            }
+        }).unwrap();
+    }
+
+    fn paint(data: &nwg::EventData) {
+        let paint = data.on_paint().double_buffered();
+        let [left, top, right, bottom] = paint.rect();
+        let r = RECT { left, top, right, bottom };
+        unsafe { FillRect(paint.hdc(), &r, (COLOR_WINDOW + 1) as HBRUSH); }
+
+        // Draw the rest of the content in `paint.hdc()`...
+
+    } // The buffer is copied to the window and `EndPaint` is called here
+    ```
+*/
+pub struct DoubleBufferedPaint {
+    hwnd: HWND,
+    paint: PAINTSTRUCT,
+    memory_dc: HDC,
+    bitmap: HBITMAP,
+    old_bitmap: HGDIOBJ,
+}
+
+impl DoubleBufferedPaint {
+
+    fn new(hwnd: HWND) -> DoubleBufferedPaint {
+        use winapi::um::wingdi::{CreateCompatibleDC, CreateCompatibleBitmap, SelectObject, SetViewportOrgEx};
+        use std::ptr;
+
+        unsafe {
+            let mut paint: PAINTSTRUCT = ::std::mem::zeroed();
+            BeginPaint(hwnd, &mut paint);
+
+            let r = paint.rcPaint;
+            let (width, height) = (r.right - r.left, r.bottom - r.top);
+            if width <= 0 || height <= 0 {
+                // Nothing to paint. Draw directly in the window DC
+                return DoubleBufferedPaint { hwnd, paint, memory_dc: ptr::null_mut(), bitmap: ptr::null_mut(), old_bitmap: ptr::null_mut() };
+            }
+
+            let memory_dc = CreateCompatibleDC(paint.hdc);
+            let bitmap = CreateCompatibleBitmap(paint.hdc, width, height);
+            let old_bitmap = SelectObject(memory_dc, bitmap as HGDIOBJ);
+            SetViewportOrgEx(memory_dc, -r.left, -r.top, ptr::null_mut());
+
+            DoubleBufferedPaint { hwnd, paint, memory_dc, bitmap, old_bitmap }
+        }
+    }
+
+    /// Returns the memory DC to draw into. If the dirty rectangle is empty, this is the window DC.
+    pub fn hdc(&self) -> HDC {
+        match self.memory_dc.is_null() {
+            true => self.paint.hdc,
+            false => self.memory_dc
+        }
+    }
+
+    /// Returns the dirty rectangle as `[left, top, right, bottom]`, in client coordinates (physical pixels)
+    pub fn rect(&self) -> [i32; 4] {
+        let r = self.paint.rcPaint;
+        [r.left, r.top, r.right, r.bottom]
+    }
+
+    /// Returns the `PAINTSTRUCT` returned by `BeginPaint`
+    pub fn paint_struct(&self) -> &PAINTSTRUCT {
+        &self.paint
+    }
+
+}
+
+impl Drop for DoubleBufferedPaint {
+    fn drop(&mut self) {
+        use winapi::um::wingdi::{BitBlt, SelectObject, DeleteObject, DeleteDC, SRCCOPY};
+
+        unsafe {
+            if !self.memory_dc.is_null() {
+                let r = self.paint.rcPaint;
+                BitBlt(self.paint.hdc, r.left, r.top, r.right - r.left, r.bottom - r.top, self.memory_dc, r.left, r.top, SRCCOPY);
+
+                SelectObject(self.memory_dc, self.old_bitmap);
+                DeleteObject(self.bitmap as HGDIOBJ);
+                DeleteDC(self.memory_dc);
+            }
+
+            EndPaint(self.hwnd, &self.paint);
+        }
+    }
+}
+
+impl fmt::Debug for DoubleBufferedPaint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DoubleBufferedPaint {{ rect: {:?} }}", self.rect())
+    }
+}
+
+
+/// Opaque type over one or more dragged files.
+pub struct DropFiles {
+    pub(crate) drop: HDROP,
+}
+
+impl DropFiles {
 
     /// Retrieves the position of the mouse pointer at the time a file was dropped during a drag-and-drop operation.
     /// The coordinates are local to the control. Ex: (0, 0) is the top left corner of the control.
@@ -702,3 +2034,368 @@ impl fmt::Debug for MinMaxInfo {
     }
 }
 
+
+/// The drag rectangle of a window that is being resized by the user (`WM_SIZING`).
+/// The rectangle is the window rectangle in screen coordinates, in physical pixels. 
+/// Changing the rectangle changes the size of the window, the edge tells which side should be adjusted.
+/// 
+/// ```rust
+/// use native_windows_gui as nwg;
+/// fn lock_aspect_ratio(data: &nwg::EventData) {
+///     let sizing = data.on_sizing();
+///     let [left, top, right, bottom] = sizing.rect();
+///     let height = (right - left) * 9 / 16;
+///     match sizing.edge() {
+///         nwg::SizingEdge::Top | nwg::SizingEdge::TopLeft | nwg::SizingEdge::TopRight => sizing.set_rect([left, bottom - height, right, bottom]),
+///         _ => sizing.set_rect([left, top, right, top + height]),
+///     }
+/// }
+/// ```
+pub struct SizingData {
+    pub(crate) inner: *mut RECT,
+    pub(crate) edge: SizingEdge,
+}
+
+impl SizingData {
+
+    /// Returns the edge of the window being dragged
+    pub fn edge(&self) -> SizingEdge {
+        self.edge
+    }
+
+    /// Returns the drag rectangle as `[left, top, right, bottom]` in screen coordinates
+    pub fn rect(&self) -> [i32; 4] {
+        let r = unsafe { &*self.inner };
+        [r.left, r.top, r.right, r.bottom]
+    }
+
+    /// Sets the drag rectangle as `[left, top, right, bottom]` in screen coordinates
+    pub fn set_rect(&self, rect: [i32; 4]) {
+        let r = unsafe { &mut *self.inner };
+        r.left = rect[0];
+        r.top = rect[1];
+        r.right = rect[2];
+        r.bottom = rect[3];
+    }
+}
+
+impl fmt::Debug for SizingData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SizingData {{ rect: {:?}, edge: {:?} }}", self.rect(), self.edge)
+    }
+}
+
+
+/// The data of a drop target event (`OnDragEnter`, `OnDragOver`, `OnDragLeave`, `OnDragDrop`).
+///
+/// Unlike `OnFileDrop`, the drop target can look at the dragged data before it is dropped and refuse it,
+/// and it also receives text. The cursor shown to the user reflects the effect set by the handler.
+///
+/// ```rust
+/// use native_windows_gui as nwg;
+///
+/// fn drag_enter(data: &nwg::DropTargetData) {
+///     // Only accept images
+///     let accept = match data.data() {
+///         Some(nwg::DragData::Files(files)) => files.iter().all(|f| f.ends_with(".png")),
+///         _ => false
+///     };
+///
+///     match accept {
+///         true => data.set_effect(nwg::DropEffect::Copy),
+///         false => data.set_effect(nwg::DropEffect::None),
+///     }
+/// }
+/// ```
+#[cfg(feature="drag-drop")]
+pub struct DropTargetData {
+    pub(crate) data: *const crate::DragData,
+    pub(crate) position: (i32, i32),
+    pub(crate) key_state: u32,
+    pub(crate) allowed: u32,
+    pub(crate) effect: *mut crate::DropEffect,
+}
+
+#[cfg(feature="drag-drop")]
+impl DropTargetData {
+
+    /// Returns the dragged data, or `None` if the source does not offer text or files
+    pub fn data(&self) -> Option<&crate::DragData> {
+        unsafe { self.data.as_ref() }
+    }
+
+    /// Returns the position of the mouse in screen coordinates, in physical pixels.
+    /// Use `screen_to_client` to get the position in the control. Always `(0, 0)` in `OnDragLeave`.
+    pub fn position(&self) -> (i32, i32) {
+        self.position
+    }
+
+    /// Returns the state of the mouse buttons and of the modifier keys as `MK_*` flags.
+    /// By convention, `Ctrl` asks for a copy and `Shift` asks for a move.
+    pub fn key_state(&self) -> u32 {
+        self.key_state
+    }
+
+    /// Returns `true` if the source of the operation allows `effect`. `DropEffect::None` is always allowed.
+    pub fn allowed(&self, effect: crate::DropEffect) -> bool {
+        effect.to_raw() & self.allowed == effect.to_raw()
+    }
+
+    /// Returns the current drop effect
+    pub fn effect(&self) -> crate::DropEffect {
+        unsafe { *self.effect }
+    }
+
+    /// Sets the drop effect. `DropEffect::None` refuses the drop.
+    /// If the source does not allow the effect, the drop is refused.
+    pub fn set_effect(&self, effect: crate::DropEffect) {
+        unsafe { *self.effect = effect; }
+    }
+
+}
+
+#[cfg(feature="drag-drop")]
+impl fmt::Debug for DropTargetData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DropTargetData {{ data: {:?}, position: {:?}, effect: {:?} }}", self.data(), self.position, self.effect())
+    }
+}
+
+
+/// The data of an `OnTreeItemExpanding` event. The handler can cancel the operation with `allow(false)`.
+#[cfg(feature="tree-view")]
+pub struct TreeItemExpandingData {
+    pub(crate) item: crate::TreeItem,
+    pub(crate) expanding: bool,
+    pub(crate) allow: *mut bool,
+}
+
+#[cfg(feature="tree-view")]
+impl TreeItemExpandingData {
+
+    /// Returns the item that is about to be expanded or collapsed
+    pub fn item(&self) -> &crate::TreeItem {
+        &self.item
+    }
+
+    /// Returns `true` if the item is about to be expanded and `false` if it is about to be collapsed
+    pub fn expanding(&self) -> bool {
+        self.expanding
+    }
+
+    /// Sets if the item should be expanded or collapsed after the event
+    pub fn allow(&self, value: bool) {
+        unsafe { *self.allow = value; }
+    }
+
+    /// Returns `true` if the operation will proceed after the event
+    pub fn allowed(&self) -> bool {
+        unsafe { *self.allow }
+    }
+
+}
+
+#[cfg(feature="tree-view")]
+impl fmt::Debug for TreeItemExpandingData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TreeItemExpandingData {{ item: {:?}, expanding: {}, allowed: {} }}", self.item, self.expanding, self.allowed())
+    }
+}
+
+
+/// The data of a pointer event (`OnPointerDown`, `OnPointerUp`, `OnPointerUpdate`).
+/// Each contact keeps the same id from `OnPointerDown` to `OnPointerUp`, which can be used to track multiple contacts.
+#[derive(Copy, Clone, Debug)]
+pub struct PointerData {
+    pub(crate) id: u32,
+    pub(crate) position: (i32, i32),
+    pub(crate) flags: u32,
+}
+
+impl PointerData {
+
+    /// Returns the id of the pointer
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the position of the pointer in screen coordinates, in physical pixels.
+    /// Use `screen_to_client` to get the position in a control.
+    pub fn position(&self) -> (i32, i32) {
+        self.position
+    }
+
+    /// Returns `true` if this is the primary pointer (ex: the first finger touching the screen)
+    pub fn primary(&self) -> bool {
+        self.flags & 0x2000 != 0
+    }
+
+    /// Returns `true` if the pointer is touching the screen (or if a mouse button is pressed)
+    pub fn in_contact(&self) -> bool {
+        self.flags & 0x4 != 0
+    }
+
+}
+
+
+/// The data of an `OnImeComposition` event
+#[derive(Clone, Debug)]
+pub struct ImeCompositionData {
+    pub(crate) composition: Option<String>,
+    pub(crate) result: Option<String>,
+    pub(crate) cursor: usize,
+}
+
+impl ImeCompositionData {
+
+    /// Returns the string being composed, or `None` if the composition string did not change.
+    /// An empty string means the composition was cleared.
+    pub fn composition(&self) -> Option<&str> {
+        self.composition.as_ref().map(|s| s as &str)
+    }
+
+    /// Returns the string committed by the user, or `None` if nothing was committed by this message
+    pub fn result(&self) -> Option<&str> {
+        self.result.as_ref().map(|s| s as &str)
+    }
+
+    /// Returns the position of the caret in the composition string, in UTF-16 code units
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+}
+
+
+/**
+    The menu of an `OnMenuOpen` event. The event is raised while the window handles `WM_INITMENUPOPUP`: the items added
+    by the handler are measured and displayed when the menu opens, so a menu can be built lazily.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn fill_recent_files(data: &nwg::EventData, files: &[String]) {
+        let menu = data.on_menu_open();
+        menu.clear();
+        for file in files {
+            menu.append(file);
+        }
+        if files.is_empty() {
+            nwg::set_enabled(&menu.append("(Empty)"), false);
+        }
+    }
+    ```
+
+    The items appended here raise `OnMenuItemSelected` like the other menu items. Their handle is the value returned by `append`,
+    so keep it to recognize the item, or compare the text. The typed builders also work: `MenuItem::builder().parent(menu.menu())`.
+
+    Timing constraints:
+      * The menu waits for the handler, so only add the items that are already known. Do not show a dialog or run a nested events loop
+      * The event is raised each time the menu opens. Call `clear` first, or check `is_empty`, to not add the items twice
+      * The size of the menu is computed after the handler. Changing the items of a menu that is already open does not resize it,
+        so data that is loaded in the background must use a placeholder item (ex: "Loading...") and be displayed the next time the menu opens
+      * `clear` destroys the submenus of the menu. Only clear the menus that are entirely built in this event,
+        not the menus that also contain items owned by `Menu` or `MenuItem` structs
+*/
+#[derive(Copy, Clone, Debug)]
+pub struct MenuOpenData {
+    pub(crate) menu: HMENU,
+    pub(crate) index: u32,
+    pub(crate) system_menu: bool,
+}
+
+impl MenuOpenData {
+
+    /// Returns the handle of the menu that is about to open
+    pub fn menu(&self) -> crate::ControlHandle {
+        crate::ControlHandle::Menu(std::ptr::null_mut(), self.menu)
+    }
+
+    /// Returns the position of the menu in its parent menu (or in the menu bar)
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns `true` if the menu is the system menu of the window (see `Window::system_menu`)
+    pub fn system_menu(&self) -> bool {
+        self.system_menu
+    }
+
+    /// Returns the number of items in the menu, separators and submenus included
+    pub fn len(&self) -> usize {
+        use winapi::um::winuser::GetMenuItemCount;
+        unsafe { GetMenuItemCount(self.menu).max(0) as usize }
+    }
+
+    /// Returns `true` if the menu has no items
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every item of the menu. The submenus are destroyed.
+    #[cfg(feature = "menu")]
+    pub fn clear(&self) {
+        unsafe { crate::win32::menu::clear_menu(self.menu); }
+    }
+
+    /// Appends an item at the end of the menu. Returns the handle of the item (a `ControlHandle::MenuItem`)
+    #[cfg(feature = "menu")]
+    pub fn append(&self, text: &str) -> crate::ControlHandle {
+        unsafe { crate::win32::menu::build_hmenu_control(Some(text.to_string()), true, false, false, Some(self.menu), None).unwrap() }
+    }
+
+    /// Appends an empty submenu at the end of the menu. Returns the handle of the submenu (a `ControlHandle::Menu`).
+    /// The submenu raises its own `OnMenuOpen` event, so it can be filled lazily too.
+    #[cfg(feature = "menu")]
+    pub fn append_submenu(&self, text: &str) -> crate::ControlHandle {
+        unsafe { crate::win32::menu::build_hmenu_control(Some(text.to_string()), false, false, false, Some(self.menu), None).unwrap() }
+    }
+
+    /// Appends a separator at the end of the menu
+    #[cfg(feature = "menu")]
+    pub fn append_separator(&self) -> crate::ControlHandle {
+        unsafe { crate::win32::menu::build_hmenu_control(None, false, true, false, Some(self.menu), None).unwrap() }
+    }
+
+}
+
+/**
+    The data of an `OnHelp` event. Maps the fields of the `HELPINFO` structure:
+
+    * `menu`: `iContextType` is `HELPINFO_MENUITEM`. The help was requested while a menu was open
+    * `control_id`: `iCtrlId`. The id of the control or of the menu item
+    * `context_id`: `dwContextId`. The help context id set on the control with `SetWindowContextHelpId`, or 0
+    * `position`: `MousePos`. The position of the mouse when the help was requested, in screen coordinates
+*/
+#[derive(Copy, Clone, Debug)]
+pub struct HelpData {
+    pub(crate) menu: bool,
+    pub(crate) control_id: i32,
+    pub(crate) context_id: u32,
+    pub(crate) position: (i32, i32),
+}
+
+impl HelpData {
+
+    /// Returns `true` if the help was requested for a menu item instead of a control
+    pub fn menu(&self) -> bool {
+        self.menu
+    }
+
+    /// Returns the id of the control or of the menu item
+    pub fn control_id(&self) -> i32 {
+        self.control_id
+    }
+
+    /// Returns the help context id of the control or of the menu item. 0 if none was set
+    pub fn context_id(&self) -> u32 {
+        self.context_id
+    }
+
+    /// Returns the position of the mouse when the help was requested, in screen coordinates and in physical pixels.
+    /// Use `screen_to_client` to get the position in a control.
+    pub fn position(&self) -> (i32, i32) {
+        self.position
+    }
+
+}
@@ -1,10 +1,10 @@
 use crate::controls::ControlHandle;
 use crate::win32::window::bind_raw_event_handler_inner;
 use crate::win32::window_helper as wh;
-use crate::NwgError;
+use crate::{NwgError, LayoutCause};
 use winapi::shared::windef::{HWND};
 use std::rc::Rc;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::ptr;
 
 
@@ -41,6 +41,9 @@ pub struct DynLayoutInner {
 
     /// The children of the control that fit in the layout
     children: Vec<DynLayoutItem>,
+
+    /// If the layout updates are suspended. See `DynLayout::suspend`
+    suspended: bool,
 }
 
 #[derive(Clone)]
@@ -54,6 +57,7 @@ impl DynLayout {
         let layout = DynLayoutInner {
             base: ptr::null_mut(),
             children: Vec::new(),
+            suspended: false,
         };
 
         DynLayoutBuilder { layout }
@@ -127,7 +131,7 @@ impl DynLayout {
         };
 
         let (w, h) = unsafe { wh::get_window_size(base) };
-        self.update_layout(w as u32, h as u32);
+        self.update_layout(w, h, LayoutCause::Manual);
     }
 
     /**
@@ -157,7 +161,7 @@ impl DynLayout {
         };
 
         let (w, h) = unsafe { wh::get_window_size(base) };
-        self.update_layout(w as u32, h as u32);
+        self.update_layout(w, h, LayoutCause::Manual);
     }
 
     /**
@@ -190,7 +194,7 @@ impl DynLayout {
         if inner.base.is_null() {
             panic!("Layout is not bound to a parent control.")
         }
-        self.update_layout(w, h);
+        self.update_layout(w, h, LayoutCause::Manual);
     }
 
     /// Resize the layout to fit the parent window size
@@ -204,16 +208,44 @@ impl DynLayout {
         }
 
         let (w, h) = unsafe { wh::get_window_size(inner.base) };
-        self.update_layout(w, h);
+        self.update_layout(w, h, LayoutCause::Manual);
+    }
+
+    /**
+        Suspends the updates of the layout. Adding or removing many children, or resizing the parent, does not move
+        the children anymore until `resume` is called. `resume` then computes the layout a single time.
+
+        The position and the size of the children are undefined between `suspend` and `resume`.
+        Calling `suspend` when the layout is already suspended does nothing. The calls are not counted.
+        This only sets a flag, so it can also be called before the layout is built.
+    */
+    pub fn suspend(&self) {
+        self.inner.borrow_mut().suspended = true;
     }
 
-    fn update_layout(&self, width: u32, height: u32) -> () {
+    /**
+        Resumes the updates of the layout suspended with `suspend` and resizes the layout to fit the parent window size.
+
+        Panic:
+          - The layout must have been successfully built otherwise this function will panic.
+    */
+    pub fn resume(&self) {
+        self.inner.borrow_mut().suspended = false;
+        self.fit();
+    }
+
+    /// Returns `true` if the updates of the layout are suspended. See `suspend`
+    pub fn suspended(&self) -> bool {
+        self.inner.borrow().suspended
+    }
+
+    fn update_layout(&self, width: u32, height: u32, cause: LayoutCause) {
         use winapi::um::winuser::{BeginDeferWindowPos, DeferWindowPos, EndDeferWindowPos};
         use winapi::um::winuser::{HWND_TOP, SWP_NOZORDER, SWP_NOREPOSITION, SWP_NOACTIVATE, SWP_NOCOPYBITS};
         use winapi::ctypes::c_int;
 
         let inner = self.inner.borrow();
-        if inner.base.is_null() || inner.children.len() == 0 {
+        if inner.base.is_null() || inner.children.is_empty() || inner.suspended {
             return;
         }
 
@@ -245,6 +277,8 @@ impl DynLayout {
 
             EndDeferWindowPos(hdwp);
         }
+
+        wh::post_layout_event(inner.base, cause);
     }
 }
 
@@ -254,6 +288,7 @@ impl Default for DynLayout {
         let inner = DynLayoutInner {
             base: ptr::null_mut(),
             children: Vec::new(),
+            suspended: false,
         };
 
         DynLayout {
@@ -306,7 +341,8 @@ impl DynLayoutBuilder {
     /// Build the layout object and bind the callback.
     /// Children must only contains window object otherwise this method will panic.
     pub fn build(self, layout: &DynLayout) -> Result<(), NwgError> {
-        use winapi::um::winuser::WM_SIZE;
+        use winapi::um::winuser::{WM_SIZE, WM_DPICHANGED};
+        use winapi::um::commctrl::DefSubclassProc;
         use winapi::shared::minwindef::{HIWORD, LOWORD};
 
         if self.layout.base.is_null() {
@@ -323,19 +359,34 @@ impl DynLayoutBuilder {
         }
 
         // Initial layout update
-        layout.update_layout(w, h);
+        layout.update_layout(w, h, LayoutCause::Manual);
 
         // Bind the event handler
         let event_layout = layout.clone();
-        let cb = move |_h, msg, _w, l| {
-            if msg == WM_SIZE {
-                let size = l as u32;
-                let width = LOWORD(size) as i32;
-                let height = HIWORD(size) as i32;
-                let (w, h) = unsafe { crate::win32::high_dpi::physical_to_logical(width, height) };
-                DynLayout::update_layout(&event_layout, w as u32, h as u32);
+        let dpi_change = Cell::new(false);
+        let cb = move |hwnd, msg, wparam, l| {
+            match msg {
+                WM_SIZE => {
+                    let size = l as u32;
+                    let width = LOWORD(size) as i32;
+                    let height = HIWORD(size) as i32;
+                    let (w, h) = unsafe { crate::win32::high_dpi::physical_to_logical(width, height) };
+                    let cause = match dpi_change.get() {
+                        true => LayoutCause::Dpi,
+                        false => LayoutCause::Resize
+                    };
+                    DynLayout::update_layout(&event_layout, w as u32, h as u32, cause);
+                    None
+                },
+                WM_DPICHANGED => {
+                    // The window is resized to the suggested rectangle while the message is handled
+                    dpi_change.set(true);
+                    let result = unsafe { DefSubclassProc(hwnd, msg, wparam, l) };
+                    dpi_change.set(false);
+                    Some(result)
+                },
+                _ => None
             }
-            None
         };
 
         /// Keep generating ids so that multiple layouts can be applied to the same parent
@@ -1,9 +1,9 @@
 use crate::controls::ControlHandle;
 use crate::win32::window_helper as wh;
 use crate::win32::window::{RawEventHandler, unbind_raw_event_handler, bind_raw_event_handler_inner};
-use crate::NwgError;
+use crate::{NwgError, LayoutCause};
 use winapi::shared::windef::HWND;
-use std::{ptr, rc::Rc, cell::{RefCell, RefMut, Ref} };
+use std::{ptr, rc::Rc, cell::{Cell, RefCell, RefMut, Ref} };
 
 use stretch::{
     number::Number,
@@ -41,6 +41,7 @@ struct FlexboxLayoutInner {
     style: Style,
     children: Vec<FlexboxLayoutChild>,
     parent_layout: Option<FlexboxLayout>,
+    suspended: bool,
 }
 
 
@@ -64,6 +65,7 @@ impl FlexboxLayout {
             style: Default::default(),
             children: Vec::new(),
             parent_layout: None,
+            suspended: false,
         };
 
         FlexboxLayoutBuilder { layout, current_index: None, auto_size: true, auto_spacing: Some(5) }
@@ -221,10 +223,49 @@ impl FlexboxLayout {
         } 
         else {
             let (w, h) = unsafe { wh::get_window_size(inner.base) };
-            self.update_layout(w, h, (0, 0))
+            self.update_layout(w, h, (0, 0), LayoutCause::Manual)
         }
     }
 
+    /**
+        Suspends the updates of the layout. Adding many children, or resizing the parent, does not compute the layout
+        anymore until `resume` is called. `resume` then computes the layout a single time.
+
+        The position and the size of the children are undefined between `suspend` and `resume`.
+        Calling `suspend` when the layout is already suspended does nothing. The calls are not counted.
+
+        This only sets a flag, so it can also be called before the layout is built.
+
+        A child layout (see `FlexboxLayoutBuilder::child_layout`) is computed by its top layout, so suspend the top layout instead.
+    */
+    pub fn suspend(&self) {
+        self.inner.borrow_mut().suspended = true;
+    }
+
+    /**
+        Resumes the updates of the layout suspended with `suspend` and resizes the layout to fit the parent window size.
+
+        Panic:
+          - The layout must have been successfully built otherwise this function will panic.
+    */
+    pub fn resume(&self) -> Result<(), stretch::Error> {
+        {
+            let mut inner = self.inner.borrow_mut();
+            if inner.base.is_null() {
+                panic!("FlexboxLayout is not bound to a parent control.")
+            }
+
+            inner.suspended = false;
+        }
+
+        self.fit()
+    }
+
+    /// Returns `true` if the updates of the layout are suspended. See `suspend`
+    pub fn suspended(&self) -> bool {
+        self.inner.borrow().suspended
+    }
+
     // Utility function to compile tree of children nodes for layout purposes
     // Also returns the total number of children items to allow cleaner deferred positioning
     fn build_child_nodes(children: &Vec<FlexboxLayoutChild>, stretch: &mut Stretch) -> Result<(usize, Vec<Node>), stretch::Error> {
@@ -302,9 +343,9 @@ impl FlexboxLayout {
         Ok(())
     }
 
-    fn update_layout(&self, width: u32, height: u32, offset: (i32, i32)) -> Result<(), stretch::Error> {
+    fn update_layout(&self, width: u32, height: u32, offset: (i32, i32), cause: LayoutCause) -> Result<(), stretch::Error> {
         let inner = self.inner.borrow();
-        if inner.base.is_null() || inner.children.len() == 0 {
+        if inner.base.is_null() || inner.children.is_empty() || inner.suspended {
             return Ok(());
         }
 
@@ -318,7 +359,7 @@ impl FlexboxLayout {
         stretch.compute_layout(node, Size::undefined())?;
 
         // Keep a fallback case to prevent panics if the layout is too large to be deferred
-        if let Ok(mut positioner) = wh::DeferredWindowPositioner::new(item_count as i32) {
+        let layout_result = if let Ok(mut positioner) = wh::DeferredWindowPositioner::new(item_count as i32) {
             let layout_result = FlexboxLayout::apply_layout_deferred(&mut positioner, &mut stretch, nodes, self.children().children(), &mut None, offset);
             positioner.end();
     
@@ -326,7 +367,13 @@ impl FlexboxLayout {
         }
         else {
             FlexboxLayout::apply_layout_immediate(&mut stretch, nodes, self.children().children(), &mut None, offset)
+        };
+
+        if layout_result.is_ok() {
+            wh::post_layout_event(inner.base, cause);
         }
+
+        layout_result
     }
 }
 
@@ -539,7 +586,8 @@ impl FlexboxLayoutBuilder {
 
     /// Build the layout object and bind the callback.
     pub fn build(mut self, layout: &FlexboxLayout) -> Result<(), NwgError> {
-        use winapi::um::winuser::WM_SIZE;
+        use winapi::um::winuser::{WM_SIZE, WM_DPICHANGED};
+        use winapi::um::commctrl::DefSubclassProc;
         use winapi::shared::minwindef::{HIWORD, LOWORD};
 
         if self.layout.base.is_null() {
@@ -598,7 +646,7 @@ impl FlexboxLayoutBuilder {
         }
 
         // Initial layout update
-        layout.update_layout(w, h, (0, 0)).expect("Failed to compute layout");
+        layout.update_layout(w, h, (0, 0), LayoutCause::Manual).expect("Failed to compute layout");
 
         // Fetch a new ID for the layout handler
         use std::sync::atomic::{AtomicUsize, Ordering};
@@ -607,15 +655,30 @@ impl FlexboxLayoutBuilder {
  
         // Bind the event handler
         let event_layout = layout.clone();
-        let cb = move |_h, msg, _w, l| {
-            if msg == WM_SIZE {
-                let size = l as u32;
-                let width = LOWORD(size) as i32;
-                let height = HIWORD(size) as i32;
-                let (w, h) = unsafe { crate::win32::high_dpi::physical_to_logical(width, height) };
-                FlexboxLayout::update_layout(&event_layout, w as u32, h as u32, (0, 0)).expect("Failed to compute layout!");
+        let dpi_change = Cell::new(false);
+        let cb = move |hwnd, msg, wparam, l| {
+            match msg {
+                WM_SIZE => {
+                    let size = l as u32;
+                    let width = LOWORD(size) as i32;
+                    let height = HIWORD(size) as i32;
+                    let (w, h) = unsafe { crate::win32::high_dpi::physical_to_logical(width, height) };
+                    let cause = match dpi_change.get() {
+                        true => LayoutCause::Dpi,
+                        false => LayoutCause::Resize
+                    };
+                    FlexboxLayout::update_layout(&event_layout, w as u32, h as u32, (0, 0), cause).expect("Failed to compute layout!");
+                    None
+                },
+                WM_DPICHANGED => {
+                    // The window is resized to the suggested rectangle while the message is handled
+                    dpi_change.set(true);
+                    let result = unsafe { DefSubclassProc(hwnd, msg, wparam, l) };
+                    dpi_change.set(false);
+                    Some(result)
+                },
+                _ => None
             }
-            None
         };
 
         {
@@ -683,6 +746,7 @@ impl Default for FlexboxLayout {
             children: Vec::new(),
             style: Default::default(),
             parent_layout: None,
+            suspended: false,
         };
 
         FlexboxLayout {
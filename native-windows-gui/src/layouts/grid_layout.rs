@@ -1,10 +1,10 @@
 use crate::controls::ControlHandle;
 use crate::win32::window::bind_raw_event_handler_inner;
 use crate::win32::window_helper as wh;
-use crate::NwgError;
+use crate::{NwgError, LayoutCause};
 use winapi::shared::windef::{HWND};
 use std::rc::Rc;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::ptr;
 
 
@@ -70,7 +70,10 @@ pub struct GridLayoutInner {
     row_count: Option<u32>, 
 
     /// The spacing between controls
-    spacing: u32
+    spacing: u32,
+
+    /// If the layout updates are suspended. See `GridLayout::suspend`
+    suspended: bool,
 }
 
 /** 
@@ -115,7 +118,8 @@ impl GridLayout {
             min_size: [0, 0],
             max_size: [u32::max_value(), u32::max_value()],
             column_count: None,
-            row_count: None
+            row_count: None,
+            suspended: false,
         };
 
         GridLayoutBuilder { layout }
@@ -164,7 +168,7 @@ impl GridLayout {
         
 
         let (w, h) = unsafe { wh::get_window_size(base) };
-        self.update_layout(w as u32, h as u32);
+        self.update_layout(w, h, LayoutCause::Manual);
     }
 
     /**
@@ -195,7 +199,7 @@ impl GridLayout {
         
 
         let (w, h) = unsafe { wh::get_window_size(base) };
-        self.update_layout(w as u32, h as u32);
+        self.update_layout(w, h, LayoutCause::Manual);
     }
 
     /**
@@ -225,7 +229,7 @@ impl GridLayout {
         
 
         let (w, h) = unsafe { wh::get_window_size(base) };
-        self.update_layout(w as u32, h as u32);
+        self.update_layout(w, h, LayoutCause::Manual);
     }
 
 
@@ -263,7 +267,7 @@ impl GridLayout {
         
 
         let (w, h) = unsafe { wh::get_window_size(base) };
-        self.update_layout(w as u32, h as u32);
+        self.update_layout(w, h, LayoutCause::Manual);
     }
 
     /**
@@ -299,7 +303,7 @@ impl GridLayout {
         
 
         let (w, h) = unsafe { wh::get_window_size(base) };
-        self.update_layout(w as u32, h as u32);
+        self.update_layout(w, h, LayoutCause::Manual);
     }
 
     /**
@@ -332,7 +336,7 @@ impl GridLayout {
         if inner.base.is_null() {
             panic!("Grid layout is not bound to a parent control.")
         }
-        self.update_layout(w, h);
+        self.update_layout(w, h, LayoutCause::Manual);
     }
 
     /// Resize the layout to fit the parent window size
@@ -346,7 +350,37 @@ impl GridLayout {
         }
 
         let (w, h) = unsafe { wh::get_window_size(inner.base) };
-        self.update_layout(w, h);
+        self.update_layout(w, h, LayoutCause::Manual);
+    }
+
+    /**
+        Suspends the updates of the layout. Adding, removing or moving many children, or resizing the parent, does not move
+        the children anymore until `resume` is called. `resume` then computes the layout a single time.
+
+        The position and the size of the children are undefined between `suspend` and `resume`:
+        they can be anywhere, including over each other.
+
+        Calling `suspend` when the layout is already suspended does nothing. The calls are not counted.
+        This only sets a flag, so it can also be called before the layout is built.
+    */
+    pub fn suspend(&self) {
+        self.inner.borrow_mut().suspended = true;
+    }
+
+    /**
+        Resumes the updates of the layout suspended with `suspend` and resizes the layout to fit the parent window size.
+
+        Panic:
+          - The layout must have been successfully built otherwise this function will panic.
+    */
+    pub fn resume(&self) {
+        self.inner.borrow_mut().suspended = false;
+        self.fit();
+    }
+
+    /// Returns `true` if the updates of the layout are suspended. See `suspend`
+    pub fn suspended(&self) -> bool {
+        self.inner.borrow().suspended
     }
 
     /// Set the margins of the layout. The four values are in this order: top, right, bottom, left.
@@ -385,9 +419,9 @@ impl GridLayout {
         inner.row_count = count;
     }
 
-    fn update_layout(&self, mut width: u32, mut height: u32) -> () {
+    fn update_layout(&self, mut width: u32, mut height: u32, cause: LayoutCause) {
         let inner = self.inner.borrow();
-        if inner.base.is_null() || inner.children.len() == 0 {
+        if inner.base.is_null() || inner.children.is_empty() || inner.suspended {
             return;
         }
 
@@ -465,6 +499,8 @@ impl GridLayout {
 
             last_handle = Some(item.control);
         }
+
+        wh::post_layout_event(inner.base, cause);
     }
 }
 
@@ -480,6 +516,7 @@ impl Default for GridLayout {
             column_count: None,
             row_count: None,
             spacing: 5,
+            suspended: false,
         };
 
         GridLayout {
@@ -565,7 +602,8 @@ impl GridLayoutBuilder {
     /// Build the layout object and bind the callback.
     /// Children must only contains window object otherwise this method will panic.
     pub fn build(self, layout: &GridLayout) -> Result<(), NwgError> {
-        use winapi::um::winuser::WM_SIZE;
+        use winapi::um::winuser::{WM_SIZE, WM_DPICHANGED};
+        use winapi::um::commctrl::DefSubclassProc;
         use winapi::shared::minwindef::{HIWORD, LOWORD};
 
         if self.layout.base.is_null() {
@@ -595,19 +633,34 @@ impl GridLayoutBuilder {
         }
 
         // Initial layout update
-        layout.update_layout(w, h);
+        layout.update_layout(w, h, LayoutCause::Manual);
        
         // Bind the event handler
         let event_layout = layout.clone();
-        let cb = move |_h, msg, _w, l| {
-            if msg == WM_SIZE {
-                let size = l as u32;
-                let width = LOWORD(size) as i32;
-                let height = HIWORD(size) as i32;
-                let (w, h) = unsafe { crate::win32::high_dpi::physical_to_logical(width, height) };
-                GridLayout::update_layout(&event_layout, w as u32, h as u32);
+        let dpi_change = Cell::new(false);
+        let cb = move |hwnd, msg, wparam, l| {
+            match msg {
+                WM_SIZE => {
+                    let size = l as u32;
+                    let width = LOWORD(size) as i32;
+                    let height = HIWORD(size) as i32;
+                    let (w, h) = unsafe { crate::win32::high_dpi::physical_to_logical(width, height) };
+                    let cause = match dpi_change.get() {
+                        true => LayoutCause::Dpi,
+                        false => LayoutCause::Resize
+                    };
+                    GridLayout::update_layout(&event_layout, w as u32, h as u32, cause);
+                    None
+                },
+                WM_DPICHANGED => {
+                    // The window is resized to the suggested rectangle while the message is handled
+                    dpi_change.set(true);
+                    let result = unsafe { DefSubclassProc(hwnd, msg, wparam, l) };
+                    dpi_change.set(false);
+                    Some(result)
+                },
+                _ => None
             }
-            None
         };
 
         /// Keep generating ids so that multiple layouts can be applied to the same parent
@@ -24,28 +24,47 @@ pub use common_types::*;
 
 pub(crate) mod win32;
 pub use win32::{
- dispatch_thread_events, dispatch_thread_events_with_callback, stop_thread_dispatch, enable_visual_styles, init_common_controls, 
+ dispatch_thread_events, dispatch_thread_events_with_callback, stop_thread_dispatch, enable_visual_styles, init_common_controls, enable_mouse_in_pointer,
  window::{
      EventHandler, RawEventHandler,
      full_bind_event_handler, bind_event_handler, unbind_event_handler,
      bind_raw_event_handler, has_raw_handler, unbind_raw_event_handler
  },
- message_box::*
+ window_helper::{register_message, broadcast_message, screen_to_client, client_to_screen, window_from_point, set_enabled, is_enabled, set_visible, is_visible, set_focus_visuals, focus_visuals,
+     focused_control, set_control_focus as set_focus},
+ message_box::*,
+ task_dialog::*,
+ dispatch::dispatch,
+ rate_limit::{debounce, throttle}
 };
 
-pub(crate) use win32::window::bind_raw_event_handler_inner;
+pub(crate) use win32::window::{bind_raw_event_handler_inner, bind_raw_event_handler_until_destroyed, unbind_raw_event_handler_inner};
 
 #[allow(deprecated)]
 pub use win32::high_dpi::{set_dpi_awareness, scale_factor, dpi};
 
 pub use win32::monitor::Monitor;
 
+pub use win32::animation::Easing;
+
+pub use win32::validation::ValidationDisplay;
+
+pub use win32::keyboard;
+
+pub use win32::system;
+
 #[cfg(feature="cursor")]
 pub use win32::cursor::GlobalCursor;
 
 #[cfg(feature="clipboard")]
 pub use win32::clipboard::{Clipboard, ClipboardFormat, ClipboardData};
 
+#[cfg(feature="drag-drop")]
+pub use win32::drag_drop::{DragData, DropEffect};
+
+#[cfg(feature="single-instance")]
+pub use win32::single_instance::{single_instance, SingleInstanceGuard};
+
 mod resources;
 pub use resources::*;
 
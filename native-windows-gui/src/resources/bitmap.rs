@@ -1,4 +1,5 @@
 use winapi::um::winnt::HANDLE;
+use winapi::shared::windef::HDC;
 use winapi::um::winuser::IMAGE_BITMAP;
 use crate::win32::resources_helper as rh;
 use crate::{OemBitmap, OemImage, NwgError};
@@ -20,6 +21,8 @@ from any file type supported natively by Windows: `JPEG, PNG, BMP, ICO, DDS, TIF
 
 Bitmaps can be converted to icons using the "copy_as_icon" function.
 
+A blank bitmap that can be drawn on (an off-screen surface) is created with `Bitmap::new`. See `Bitmap::dc`.
+
 
 **Builder parameters:**
   * `source_file`:      The source of the bitmap if it is a file.
@@ -58,7 +61,8 @@ fn load_bitmap_builder() -> nwg::Bitmap {
 #[allow(unused)]
 pub struct Bitmap {
     pub handle: HANDLE,
-    pub(crate) owned: bool
+    pub(crate) owned: bool,
+    pub(crate) dc: HDC,
 }
 
 impl Bitmap {
@@ -83,6 +87,73 @@ impl Bitmap {
         }
     }
 
+    /**
+        Creates a blank bitmap of `width` x `height` pixels that can be drawn on with GDI, to render an image outside of `OnPaint`
+        (ex: an off-screen buffer that is copied to a control with `BitBlt` when it is painted). The bitmap is selected in
+        a memory device context returned by `dc`.
+
+        The bitmap is a DIB section with a 32 bits BGRA pixel format (one byte per channel, blue first) and a top-down orientation:
+        the first row in memory is the top row of the image. All the pixels are initialized to 0 (transparent black).
+        GDI functions do not write the alpha channel.
+
+        The device context and the bitmap are freed when the `Bitmap` is dropped.
+    */
+    pub fn new(width: u32, height: u32) -> Result<Bitmap, NwgError> {
+        use winapi::um::wingdi::{CreateCompatibleDC, CreateDIBSection, DeleteDC, SelectObject, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS};
+        use std::mem;
+
+        if width == 0 || height == 0 {
+            return Err(NwgError::resource_create("A bitmap requires a width and a height greater than 0"));
+        }
+
+        unsafe {
+            let dc = CreateCompatibleDC(ptr::null_mut());
+            if dc.is_null() {
+                return Err(NwgError::resource_create("Failed to create the bitmap device context"));
+            }
+
+            let mut info: BITMAPINFO = mem::zeroed();
+            info.bmiHeader = BITMAPINFOHEADER {
+                biSize: mem::size_of::<BITMAPINFOHEADER>() as u32,
+                biWidth: width as i32,
+                biHeight: -(height as i32),  // Negative height for a top-down bitmap
+                biPlanes: 1,
+                biBitCount: 32,
+                biCompression: BI_RGB,
+                biSizeImage: 0,
+                biXPelsPerMeter: 0,
+                biYPelsPerMeter: 0,
+                biClrUsed: 0,
+                biClrImportant: 0,
+            };
+
+            let mut bits = ptr::null_mut();
+            let handle = CreateDIBSection(dc, &info, DIB_RGB_COLORS, &mut bits, ptr::null_mut(), 0);
+            if handle.is_null() {
+                DeleteDC(dc);
+                return Err(NwgError::resource_create("Failed to create the bitmap"));
+            }
+
+            SelectObject(dc, handle as _);
+
+            Ok(Bitmap { handle: handle as HANDLE, owned: true, dc })
+        }
+    }
+
+    /**
+        Returns the memory device context of a bitmap created with `Bitmap::new`, or `None` for the other bitmaps.
+        Draw on the bitmap with the GDI functions and this device context, and copy it to another device context with `BitBlt`.
+
+        The bitmap stays selected in this device context for its whole life. Windows does not allow a bitmap to be selected in
+        two device contexts at once, so the functions that select the bitmap themselves may fail on it. Copy the pixels with `BitBlt` instead.
+    */
+    pub fn dc(&self) -> Option<HDC> {
+        match self.dc.is_null() {
+            true => None,
+            false => Some(self.dc)
+        }
+    }
+
     /**
         Single line helper function over the bitmap builder api.
 
@@ -257,14 +328,14 @@ impl<'a> BitmapBuilder<'a> {
                 handle?
             };
 
-            *b = Bitmap { handle, owned: true };
+            *b = Bitmap { handle, owned: true, dc: ptr::null_mut() };
         } else if let Some(src) = self.source_system {
             let handle = unsafe { rh::build_oem_image(OemImage::Bitmap(src), self.size)? };
-            *b = Bitmap { handle, owned: true };
+            *b = Bitmap { handle, owned: true, dc: ptr::null_mut() };
         } else if let Some(src) = self.source_bin { 
             let handle = unsafe { rh::bitmap_from_memory(src)? };
 
-            *b = Bitmap { handle, owned: true };
+            *b = Bitmap { handle, owned: true, dc: ptr::null_mut() };
         } else {
             #[cfg(all(feature = "embed-resource", feature="image-decoder"))]
             fn build_embed(builder: BitmapBuilder) -> Result<Bitmap, NwgError> {
@@ -316,7 +387,8 @@ impl Default for Bitmap {
     fn default() -> Bitmap {
         Bitmap {
             handle: ptr::null_mut(),
-            owned: false
+            owned: false,
+            dc: ptr::null_mut(),
         }
     }
 
@@ -333,6 +405,11 @@ impl PartialEq for Bitmap {
 impl Drop for Bitmap {
 
     fn drop(&mut self) {
+        // The bitmap cannot be deleted while it is selected in the device context
+        if !self.dc.is_null() {
+            unsafe { winapi::um::wingdi::DeleteDC(self.dc); }
+        }
+
         if self.owned && !self.handle.is_null() {
             rh::destroy_obj(self.handle);
         }
@@ -187,7 +187,7 @@ impl EmbedResource {
             if bitmap.is_null() {
                 None
             } else {
-                Some(Bitmap { handle: bitmap as _, owned: true } )
+                Some(Bitmap { handle: bitmap as _, owned: true, dc: ptr::null_mut() } )
             }
         }
     }
@@ -211,7 +211,7 @@ impl EmbedResource {
                 let src = unsafe { raw.as_mut_slice() };
                 let handle = unsafe { rh::build_image_decoder_from_memory(src, size) };
                 match handle {
-                    Ok(handle) => Some(Bitmap { handle, owned: true }),
+                    Ok(handle) => Some(Bitmap { handle, owned: true, dc: ptr::null_mut() }),
                     Err(e) => {
                         println!("{:?}", e);
                         None
@@ -6,6 +6,7 @@ use crate::NwgError;
 use std::ptr;
 
 use std::sync::Mutex;
+use std::collections::HashMap;
 
 
 lazy_static! {
@@ -13,6 +14,11 @@ lazy_static! {
     static ref DEFAULT_FONT: Mutex<Option<Font>> = {
         Mutex::new(None)
     };
+
+    /// DPI of the fonts created with `Font::from_point_size_for_dpi` or `Font::rescale`. Used to rescale them again.
+    static ref FONT_DPI: Mutex<HashMap<usize, u32>> = {
+        Mutex::new(HashMap::new())
+    };
 }
 
 pub struct MemFont(pub HANDLE);
@@ -123,6 +129,107 @@ impl Font {
             .map(|f| Font { handle: f.handle } )
     }
 
+    /**
+        Creates a font of `points` points for a monitor with a resolution of `dpi` dots per inch.
+        Unlike the sizes of `FontBuilder`, the height is not scaled with the system DPI.
+
+        Use this with the DPI sent by `OnDpiChanged` so that the text keeps the same physical size
+        when a window moves between monitors. See also `Font::rescale`.
+    */
+    pub fn from_point_size_for_dpi(family: &str, points: u32, dpi: u32) -> Result<Font, NwgError> {
+        use winapi::um::wingdi::{LOGFONTW, CreateFontIndirectW};
+        use std::mem;
+
+        let mut info: LOGFONTW = unsafe { mem::zeroed() };
+        info.lfHeight = -point_size_to_height(points, dpi);
+
+        let family = to_utf16(family);
+        let len = family.len().min(info.lfFaceName.len() - 1);
+        info.lfFaceName[..len].copy_from_slice(&family[..len]);
+        Font::init_logfont(&mut info);
+
+        let handle = unsafe { CreateFontIndirectW(&info) };
+        if handle.is_null() {
+            return Err(NwgError::resource_create("Failed to create font"));
+        }
+
+        FONT_DPI.lock().unwrap().insert(handle as usize, dpi);
+
+        Ok(Font { handle })
+    }
+
+    /**
+        Creates a copy of the font scaled for a monitor with a resolution of `new_dpi` dots per inch.
+        The family, weight and style of the font are kept and the logical height is scaled so that the text keeps the same physical size.
+
+        Fonts created with `Font::from_point_size_for_dpi` (or by a previous call to `rescale`) are scaled from the DPI used to create them.
+        Other fonts are assumed to be created for the system DPI. The DPI of a font is forgotten when a `Font` with the same handle is dropped,
+        including the copies returned by the `font` method of the controls, so keep and rescale the `Font` returned by these functions.
+
+        The old font is not freed because it may still be used by controls.
+    */
+    pub fn rescale(&self, new_dpi: u32) -> Result<Font, NwgError> {
+        use winapi::um::wingdi::{LOGFONTW, GetObjectW, CreateFontIndirectW};
+        use std::mem;
+
+        if self.handle.is_null() {
+            panic!("Font is not yet bound to a winapi object");
+        }
+
+        let mut info: LOGFONTW = unsafe { mem::zeroed() };
+        let size = mem::size_of::<LOGFONTW>() as i32;
+        if unsafe { GetObjectW(self.handle as _, size, &mut info as *mut LOGFONTW as _) } == 0 {
+            return Err(NwgError::resource_create("Failed to read the font parameters"));
+        }
+
+        let old_dpi = FONT_DPI.lock().unwrap()
+            .get(&(self.handle as usize))
+            .cloned()
+            .unwrap_or_else(|| unsafe { crate::win32::high_dpi::dpi() as u32 });
+
+        if old_dpi != 0 {
+            info.lfHeight = ((info.lfHeight as i64 * new_dpi as i64) / old_dpi as i64) as i32;
+        }
+
+        let handle = unsafe { CreateFontIndirectW(&info) };
+        if handle.is_null() {
+            return Err(NwgError::resource_create("Failed to create font"));
+        }
+
+        FONT_DPI.lock().unwrap().insert(handle as usize, new_dpi);
+
+        Ok(Font { handle })
+    }
+
+    /// Returns the height of the font in logical units (the absolute value of `lfHeight`).
+    /// Returns 0 if the font is not bound or if its parameters could not be read.
+    pub fn logical_height(&self) -> i32 {
+        use winapi::um::wingdi::{LOGFONTW, GetObjectW};
+        use std::mem;
+
+        if self.handle.is_null() {
+            return 0;
+        }
+
+        let mut info: LOGFONTW = unsafe { mem::zeroed() };
+        let size = mem::size_of::<LOGFONTW>() as i32;
+        match unsafe { GetObjectW(self.handle as _, size, &mut info as *mut LOGFONTW as _) } {
+            0 => 0,
+            _ => info.lfHeight.abs()
+        }
+    }
+
+    /// Fills the parameters of a font created by `from_point_size_for_dpi` with the values used by `FontBuilder`
+    fn init_logfont(info: &mut winapi::um::wingdi::LOGFONTW) {
+        use winapi::um::wingdi::{DEFAULT_CHARSET, OUT_DEFAULT_PRECIS, CLIP_DEFAULT_PRECIS, CLEARTYPE_QUALITY, VARIABLE_PITCH};
+
+        info.lfCharSet = DEFAULT_CHARSET as u8;
+        info.lfOutPrecision = OUT_DEFAULT_PRECIS as u8;
+        info.lfClipPrecision = CLIP_DEFAULT_PRECIS as u8;
+        info.lfQuality = CLEARTYPE_QUALITY as u8;
+        info.lfPitchAndFamily = VARIABLE_PITCH as u8;
+    }
+
     /** 
         Add a font to the system font table. Don't forget to call `Font::remove_font(path)` once you're done.
         Returns `false` if the font could not be added. Windows won't tell you why though. 
@@ -290,6 +397,22 @@ impl<'a> FontBuilder<'a> {
 
 }
 
+/// Converts a size in points to a size in pixels for `dpi`. Rounded to the nearest pixel.
+fn point_size_to_height(points: u32, dpi: u32) -> i32 {
+    ((points as i64 * dpi as i64 + 36) / 72) as i32
+}
+
+impl Drop for Font {
+
+    /// The font handle is not freed because it may still be used by controls. Only the DPI saved by `from_point_size_for_dpi` is removed.
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            FONT_DPI.lock().unwrap().remove(&(self.handle as usize));
+        }
+    }
+
+}
+
 unsafe impl Send for Font {}
 unsafe impl Sync for Font {}
 
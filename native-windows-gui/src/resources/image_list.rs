@@ -13,11 +13,20 @@ Image lists are used in controls such as tabs container and tree view in order t
 There are two kinds of image list in Winapi: masked. This is a wrapper over the masked type.
 
 Image list and the method that use them in controls are behind the "image-list" feature. 
+The controls that accept an image list are: `TabsContainer`, `TreeView`, `ListView` and `Button`.
+
+Ownership:
+  * An image list created with the builder is owned and is destroyed when the `ImageList` is dropped.
+  * The controls never copy or destroy an image list. The image list must outlive every control it was set on, or
+    be removed from them (`set_image_list(None)`) before it is dropped.
+  * The image lists returned by the controls (ex: `TreeView::image_list`) are not owned and dropping them does nothing.
+  * The same image list can be shared by many controls.
 
 **Builder parameters:**
-  * `size`:    The size size of the images in the image list. Default `(32, 32)`
-  * `initial`: The initial size (in images) of the image list. Default `5`
-  * `grow`:    The number of images by which the image list can grow when the system needs to make room for new images. Default `5`
+  * `size`:       The size size of the images in the image list. Default `(32, 32)`
+  * `initial`:    The initial size (in images) of the image list. Default `5`
+  * `grow`:       The number of images by which the image list can grow when the system needs to make room for new images. Default `5`
+  * `dpi_scaled`: If `true`, `size` is in logical pixels and is scaled to the current dpi. Default `false`
 
 ```rust
 use native_windows_gui as nwg;
@@ -42,7 +51,8 @@ impl ImageList {
         ImageListBuilder {
             size: (32, 32),
             initial: 5,
-            grow: 5
+            grow: 5,
+            dpi_scaled: false,
         }
     }
 
@@ -213,6 +223,7 @@ pub struct ImageListBuilder {
     size: (i32, i32),
     initial: i32,
     grow: i32,
+    dpi_scaled: bool,
 }
 
 impl ImageListBuilder {
@@ -232,11 +243,21 @@ impl ImageListBuilder {
         self
     }
 
+    pub fn dpi_scaled(mut self, dpi_scaled: bool) -> ImageListBuilder {
+        self.dpi_scaled = dpi_scaled;
+        self
+    }
+
     pub fn build(self, list: &mut ImageList) -> Result<(), NwgError> {
         use winapi::um::commctrl::{ImageList_Create, ILC_COLOR32, ILC_MASK};
+        use crate::win32::high_dpi::logical_to_physical;
 
         unsafe {
-            let (w, h) = self.size;
+            let (w, h) = match self.dpi_scaled {
+                true => logical_to_physical(self.size.0, self.size.1),
+                false => self.size
+            };
+
             let handle = ImageList_Create(w, h, ILC_COLOR32 | ILC_MASK, self.initial, self.grow);
             if handle.is_null() {
                 return Err(NwgError::resource_create("Failed to create image list"));
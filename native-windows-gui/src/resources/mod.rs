@@ -3,6 +3,7 @@ mod system_images;
 mod icon;
 mod cursor;
 mod bitmap;
+mod region;
 
 #[cfg(feature = "image-decoder")]
 mod image_decoder;
@@ -27,6 +28,7 @@ pub use system_images::*;
 pub use icon::{Icon, IconBuilder};
 pub use cursor::{Cursor, CursorBuilder};
 pub use bitmap::{Bitmap, BitmapBuilder};
+pub use region::{Region, RegionBuilder, RegionShape, RegionCombine};
 
 #[cfg(feature = "image-decoder")]
 pub use image_decoder::{ImageDecoder, ImageSource, ImageData, ImageDecoderBuilder, ContainerFormat};
@@ -0,0 +1,223 @@
+use winapi::shared::minwindef::HRGN;
+use crate::win32::resources_helper as rh;
+use crate::NwgError;
+use std::ptr;
+
+
+/**
+    A basic shape of a region. The positions and the sizes are in logical pixels.
+
+    * `Rect`: A rectangle
+    * `RoundedRect`: A rectangle with rounded corners. `radius` is the radius of the ellipse used for the corners
+    * `Ellipse`: The ellipse that fits in the rectangle
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RegionShape {
+    Rect { position: (i32, i32), size: (u32, u32) },
+    RoundedRect { position: (i32, i32), size: (u32, u32), radius: u32 },
+    Ellipse { position: (i32, i32), size: (u32, u32) },
+}
+
+/**
+    How a shape is combined with the shapes added before it in a `RegionBuilder`. Each value maps to a `CombineRgn` mode:
+
+    * `Union`: `RGN_OR`. The area of the shape is added to the region
+    * `Intersect`: `RGN_AND`. Only the area that is both in the region and in the shape is kept
+    * `Difference`: `RGN_DIFF`. The area of the shape is removed from the region
+    * `Xor`: `RGN_XOR`. The area covered by the region or by the shape, but not by both, is kept
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RegionCombine {
+    Union,
+    Intersect,
+    Difference,
+    Xor,
+}
+
+/**
+A region is an area made of rectangles, rounded rectangles and ellipses.
+Regions are used to give a non rectangular shape to a window with `Window::set_region`.
+
+A region is built from a list of shapes. The first shape defines the initial area and
+each following shape is combined with the area using a `RegionCombine` mode.
+
+The region coordinates are relative to the upper left corner of the window (not of its client area)
+and are in logical pixels.
+
+**Builder parameters:**
+  * `shape`:   Adds a shape to the region (the first shape defines the initial area)
+  * `combine`: Combines a shape with the area defined by the previous shapes
+
+Example:
+
+```rust
+use native_windows_gui as nwg;
+
+fn build_region() -> nwg::Region {
+    let mut region = nwg::Region::default();
+
+    nwg::Region::builder()
+        .shape(nwg::RegionShape::RoundedRect { position: (0, 0), size: (300, 200), radius: 20 })
+        .combine(nwg::RegionShape::Ellipse { position: (130, 80), size: (40, 40) }, nwg::RegionCombine::Difference)
+        .build(&mut region)
+        .unwrap();
+
+    region
+}
+```
+*/
+#[derive(PartialEq, Eq, Debug)]
+pub struct Region {
+    pub handle: HRGN
+}
+
+impl Region {
+
+    pub fn builder() -> RegionBuilder {
+        RegionBuilder {
+            shapes: Vec::new()
+        }
+    }
+
+    /**
+        Single line helper function over the region builder api.
+        Creates a region with a single shape.
+    */
+    pub fn from_shape(shape: RegionShape) -> Result<Region, NwgError> {
+        let mut region = Region::default();
+
+        Region::builder()
+            .shape(shape)
+            .build(&mut region)?;
+
+        Ok(region)
+    }
+
+    /// Returns `true` if the point (in logical pixels, relative to the region origin) is inside the region
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        use winapi::um::wingdi::PtInRegion;
+
+        if self.handle.is_null() {
+            return false;
+        }
+
+        unsafe {
+            let (x, y) = crate::win32::high_dpi::logical_to_physical(x, y);
+            PtInRegion(self.handle, x, y) != 0
+        }
+    }
+
+    /// Gives the region handle to the caller. The region will not be freed when the `Region` is dropped.
+    pub(crate) fn take(mut self) -> HRGN {
+        let handle = self.handle;
+        self.handle = ptr::null_mut();
+        handle
+    }
+
+}
+
+impl Default for Region {
+
+    fn default() -> Region {
+        Region { handle: ptr::null_mut() }
+    }
+
+}
+
+impl Drop for Region {
+
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            rh::destroy_obj(self.handle as _);
+        }
+    }
+
+}
+
+/// Builds a region. See `Region`
+pub struct RegionBuilder {
+    shapes: Vec<(RegionShape, RegionCombine)>
+}
+
+impl RegionBuilder {
+
+    pub fn shape(mut self, shape: RegionShape) -> RegionBuilder {
+        self.shapes.push((shape, RegionCombine::Union));
+        self
+    }
+
+    pub fn combine(mut self, shape: RegionShape, mode: RegionCombine) -> RegionBuilder {
+        self.shapes.push((shape, mode));
+        self
+    }
+
+    pub fn build(self, region: &mut Region) -> Result<(), NwgError> {
+        use winapi::um::wingdi::{CreateRectRgn, CombineRgn, RGN_OR, RGN_AND, RGN_DIFF, RGN_XOR, ERROR};
+
+        if self.shapes.is_empty() {
+            return Err(NwgError::resource_create("A region requires at least one shape"));
+        }
+
+        unsafe {
+            let handle = CreateRectRgn(0, 0, 0, 0);
+            if handle.is_null() {
+                return Err(NwgError::resource_create("Failed to create region"));
+            }
+
+            for (shape, mode) in self.shapes {
+                let shape_handle = create_shape(shape);
+                if shape_handle.is_null() {
+                    rh::destroy_obj(handle as _);
+                    return Err(NwgError::resource_create("Failed to create region"));
+                }
+
+                let mode = match mode {
+                    RegionCombine::Union => RGN_OR,
+                    RegionCombine::Intersect => RGN_AND,
+                    RegionCombine::Difference => RGN_DIFF,
+                    RegionCombine::Xor => RGN_XOR,
+                };
+
+                let result = CombineRgn(handle, handle, shape_handle, mode);
+                rh::destroy_obj(shape_handle as _);
+
+                if result == ERROR {
+                    rh::destroy_obj(handle as _);
+                    return Err(NwgError::resource_create("Failed to combine region"));
+                }
+            }
+
+            *region = Region { handle };
+        }
+
+        Ok(())
+    }
+
+}
+
+unsafe fn create_shape(shape: RegionShape) -> HRGN {
+    use winapi::um::wingdi::{CreateRectRgn, CreateRoundRectRgn, CreateEllipticRgn};
+    use crate::win32::high_dpi::logical_to_physical;
+
+    let rect = |position: (i32, i32), size: (u32, u32)| {
+        let (left, top) = logical_to_physical(position.0, position.1);
+        let (right, bottom) = logical_to_physical(position.0 + size.0 as i32, position.1 + size.1 as i32);
+        (left, top, right, bottom)
+    };
+
+    match shape {
+        RegionShape::Rect { position, size } => {
+            let (left, top, right, bottom) = rect(position, size);
+            CreateRectRgn(left, top, right, bottom)
+        },
+        RegionShape::RoundedRect { position, size, radius } => {
+            let (left, top, right, bottom) = rect(position, size);
+            let (radius, _) = logical_to_physical(radius as i32, 0);
+            CreateRoundRectRgn(left, top, right + 1, bottom + 1, radius * 2, radius * 2)
+        },
+        RegionShape::Ellipse { position, size } => {
+            let (left, top, right, bottom) = rect(position, size);
+            CreateEllipticRgn(left, top, right + 1, bottom + 1)
+        },
+    }
+}
@@ -28,6 +28,24 @@ pub struct TestRun {
 pub struct ControlsTest {
     // data
     runs: RefCell<TestRun>,
+    copy_data: RefCell<Option<(usize, Vec<u8>)>>,
+    list_select: RefCell<Option<(Option<usize>, Option<usize>)>>,
+    list_focus: RefCell<Option<bool>>,
+    input_language: RefCell<Option<u16>>,
+    ime_composition: RefCell<Option<ImeCompositionData>>,
+    help: RefCell<Option<(ControlHandle, HelpData)>>,
+    button_click: RefCell<Option<(bool, bool)>>,
+    button_dropdown: RefCell<Option<[i32; 4]>>,
+    window_active: RefCell<Option<bool>>,
+    size_move: RefCell<Option<bool>>,
+    setting_change: RefCell<Option<(String, u32)>>,
+    custom_message: RefCell<Option<(u32, usize, isize)>>,
+    mouse_wheel: RefCell<Option<(i32, bool)>>,
+    text_input: RefCell<Option<bool>>,
+    context_menu: RefCell<Option<Option<(i32, i32)>>>,
+    system_menu_item: RefCell<Option<ControlHandle>>,
+    layout: RefCell<Option<LayoutCause>>,
+    sys_command: RefCell<Option<u32>>,
 
     // Resources
     window_icon: Icon,
@@ -130,6 +148,7 @@ pub struct ControlsTest {
     // Menu
     window_menu: Menu,
     window_submenu1: Menu,
+    window_lazy_menu: Menu,
     window_menu_sep: MenuSeparator,
     window_menu_item1: MenuItem,
     window_menu_item2: MenuItem,
@@ -665,6 +684,11 @@ mod partial_controls_test_ui {
                 .parent(&data.window_menu)
                 .build(&mut data.window_submenu1)?;
             
+            Menu::builder()
+                .text("&Recent files")
+                .parent(&data.window_menu)
+                .build(&mut data.window_lazy_menu)?;
+
             MenuSeparator::builder()
                 .parent(&data.window_menu)
                 .build(&mut data.window_menu_sep)?;
@@ -877,8 +901,85 @@ mod partial_controls_test_ui {
                     if &handle == &self.window {
                         self.panel.set_visible(false);
                     },
+                E::OnListBoxSelect =>
+                    if &handle == &self.test_list_box1 {
+                        *self.list_select.borrow_mut() = Some(_evt_data.on_list_box_select());
+                    },
+                E::OnListBoxFocus | E::OnListBoxFocusLost =>
+                    if &handle == &self.test_list_box1 {
+                        *self.list_focus.borrow_mut() = Some(evt == E::OnListBoxFocus);
+                    },
+                E::OnInputLanguageChanged =>
+                    if &handle == &self.window {
+                        *self.input_language.borrow_mut() = Some(_evt_data.on_input_language_changed());
+                    },
+                E::OnImeComposition =>
+                    if &handle == &self.window {
+                        *self.ime_composition.borrow_mut() = Some(_evt_data.on_ime_composition().clone());
+                    },
+                E::OnWindowActivate =>
+                    if &handle == &self.window {
+                        *self.window_active.borrow_mut() = Some(true);
+                    },
+                E::OnWindowDeactivate =>
+                    if &handle == &self.window {
+                        *self.window_active.borrow_mut() = Some(false);
+                    },
+                E::OnSettingChange =>
+                    if &handle == &self.window {
+                        let (area, action) = _evt_data.on_setting_change();
+                        *self.setting_change.borrow_mut() = Some((area.to_string(), action));
+                    },
+                E::OnCustomMessage =>
+                    if &handle == &self.window {
+                        *self.custom_message.borrow_mut() = Some(_evt_data.on_custom_message());
+                    },
+                E::OnEnterSizeMove | E::OnExitSizeMove =>
+                    if &handle == &self.window {
+                        *self.size_move.borrow_mut() = Some(evt == E::OnEnterSizeMove);
+                    },
+                E::OnTextInput =>
+                    if &handle == &self.test_text_input {
+                        *self.text_input.borrow_mut() = Some(_evt_data.on_text_input());
+                    },
+                E::OnMouseWheel =>
+                    if &handle == &self.window {
+                        *self.mouse_wheel.borrow_mut() = Some((_evt_data.on_mouse_wheel(), false));
+                    },
+                E::OnMouseHWheel =>
+                    if &handle == &self.window {
+                        *self.mouse_wheel.borrow_mut() = Some((_evt_data.on_mouse_hwheel(), true));
+                    },
+                E::OnHelp => {
+                    *self.help.borrow_mut() = Some((handle, *_evt_data.on_help()));
+                },
+                E::OnCopyData =>
+                    if &handle == &self.window {
+                        let (id, data) = _evt_data.on_copy_data();
+                        *self.copy_data.borrow_mut() = Some((id, data.to_vec()));
+                    },
+                E::OnSysCommand =>
+                    if &handle == &self.window {
+                        use winapi::um::winuser::SC_MAXIMIZE;
+
+                        let data = _evt_data.on_sys_command();
+                        *self.sys_command.borrow_mut() = Some(data.command());
+                        if data.command() == SC_MAXIMIZE as u32 && self.runs.borrow().window {
+                            data.consume();
+                        }
+                    },
+                E::OnLayout =>
+                    if &handle == &self.panel {
+                        *self.layout.borrow_mut() = Some(_evt_data.on_layout());
+                    },
+                E::OnButtonDropdown =>
+                    if &handle == &self.test_button {
+                        *self.button_dropdown.borrow_mut() = Some(_evt_data.on_button_dropdown());
+                    },
                 E::OnButtonClick =>
-                    if &handle == &self.run_window_test {
+                    if &handle == &self.test_button {
+                        *self.button_click.borrow_mut() = Some(_evt_data.on_button_click());
+                    } else if &handle == &self.run_window_test {
                         run_window_tests(self, evt);
                     } else if &handle == &self.run_button_test {
                         run_button_tests(self, evt);
@@ -936,6 +1037,10 @@ mod partial_controls_test_ui {
                         show_pop_menu(self, evt);
                     } else if &handle == &self.tray_icon_2 {
                         show_pop_menu(self, evt);
+                    } else if &handle == &self.test_text_input {
+                        let data = _evt_data.on_context_menu();
+                        data.suppress_default();
+                        *self.context_menu.borrow_mut() = Some(data.position());
                     },
                 E::OnTooltipText => 
                     if &handle == &self.window {
@@ -943,11 +1048,22 @@ mod partial_controls_test_ui {
                     } else if &handle == &self.test_text_input {
                         set_tooltip_dynamic(self, &self.test_text_input.handle, _evt_data.on_tooltip_text());
                     },
+                E::OnMenuOpen =>
+                    if &handle == &self.window_lazy_menu {
+                        let menu = _evt_data.on_menu_open();
+                        menu.clear();
+                        menu.append("first_file.txt");
+                        menu.append("second_file.txt");
+                        menu.append_separator();
+                        menu.append_submenu("More");
+                    },
                 E::OnMenuItemSelected => 
                     if &handle == &self.window_menu_item1 {
                         item_hello("menu item");
                     } else if &handle == &self.pop_menu_item1 {
                         item_hello("popup menu item");
+                    } else {
+                        *self.system_menu_item.borrow_mut() = Some(handle);
                     },
                 E::OnChar => {
                     if &handle == &self.test_rich {
@@ -995,7 +1111,16 @@ fn init_tree(app: &ControlsTest) {
 
     let other = tree.insert_item("Another root children", Some(&item), TreeInsert::Last);
     tree.insert_item("Banana", Some(&other), TreeInsert::First);
-    tree.insert_item("Pinapple", Some(&other), TreeInsert::First);
+    let deep = tree.insert_item("Pinapple", Some(&other), TreeInsert::First);
+
+    tree.select_item(&deep);
+    assert_eq!(tree.caret_item().map(|i| i.handle), Some(deep.handle));
+    assert_eq!(tree.selected_item().map(|i| i.handle), Some(deep.handle));
+    tree.ensure_visible(&deep);
+    tree.set_first_visible(&item);
+
+    tree.select_item(&item);
+    assert_eq!(tree.caret_item().map(|i| i.handle), Some(item.handle));
 }
 
 fn init_list_view(app: &ControlsTest) {
@@ -1050,6 +1175,383 @@ fn run_window_tests(app: &ControlsTest, _evt: Event) {
         // The actual size return here might be less because it does not take account of the menubar
         // assert_eq!(app.window.size(), (500, 400));
 
+        #[cfg(feature = "splitter")]
+        {
+            let mut panel = Frame::default();
+            Frame::builder()
+                .size((300, 200))
+                .parent(&app.window)
+                .build(&mut panel)
+                .unwrap();
+
+            let mut splitter = Splitter::default();
+            Splitter::builder()
+                .size((5, 200))
+                .min_first(50)
+                .min_second(50)
+                .parent(&panel)
+                .build(&mut splitter)
+                .unwrap();
+
+            // The initial position is snapped to the minimum size of the first panel
+            assert_eq!(splitter.split_position(), 50);
+
+            splitter.set_split_position(120);
+            assert_eq!(splitter.split_position(), 120);
+
+            splitter.set_split_position(-10);
+            assert_eq!(splitter.split_position(), 50);
+
+            splitter.set_split_position(1000);
+            assert_eq!(splitter.split_position(), 300 - 5 - 50);
+
+            splitter.set_max_sizes(Some(100), None);
+            splitter.set_split_position(200);
+            assert_eq!(splitter.split_position(), 100);
+
+            splitter.set_max_sizes(None, Some(100));
+            splitter.set_split_position(60);
+            assert_eq!(splitter.split_position(), 300 - 5 - 100);
+        }
+
+        assert_eq!(app.window.resize_mode(), ResizeMode::Live);
+        app.window.set_resize_mode(ResizeMode::Deferred);
+        assert_eq!(app.window.resize_mode(), ResizeMode::Deferred);
+        app.window.set_resize_mode(ResizeMode::DeferredPlaceholder);
+        assert_eq!(app.window.resize_mode(), ResizeMode::DeferredPlaceholder);
+        app.window.set_resize_mode(ResizeMode::Live);
+        assert_eq!(app.window.resize_mode(), ResizeMode::Live);
+
+        {
+            use std::rc::Rc;
+
+            let name = "nwg.tests.single-instance";
+            let guard = single_instance(name).unwrap().expect("The first instance must receive the guard");
+
+            let received: Rc<RefCell<Option<Vec<String>>>> = Default::default();
+            let received_handler = received.clone();
+            let handler = bind_event_handler(&app.window.handle, &app.window.handle, move |evt, data, _handle| {
+                if evt == Event::OnSecondInstance {
+                    *received_handler.borrow_mut() = Some(data.on_second_instance().to_vec());
+                }
+            });
+
+            // The same name is already held, so the arguments of this "instance" are forwarded to the listening window
+            guard.listen(&app.window.handle);
+            assert!(single_instance(name).unwrap().is_none());
+
+            let expected: Vec<String> = std::env::args().collect();
+            assert_eq!(received.borrow().as_ref(), Some(&expected));
+
+            unbind_event_handler(&handler);
+
+            // Dropping the guard releases the name
+            drop(guard);
+            assert!(single_instance(name).unwrap().is_some());
+
+            assert!(single_instance("nwg\\invalid\\name").is_err());
+        }
+
+        assert!(app.panel.send_copydata(&app.window.handle, 42, b"Hello"));
+        assert_eq!(app.copy_data.borrow_mut().take(), Some((42, b"Hello".to_vec())));
+
+        assert!(std::thread::spawn(|| dispatch(|| {})).join().unwrap());
+
+        {
+            use winapi::um::wingdi::{SetPixel, GetPixel, RGB};
+
+            let surface = Bitmap::new(10, 10).unwrap();
+            let dc = surface.dc().expect("Blank bitmaps have a device context");
+            unsafe {
+                assert_eq!(GetPixel(dc, 3, 4), RGB(0, 0, 0));
+                SetPixel(dc, 3, 4, RGB(255, 100, 0));
+                assert_eq!(GetPixel(dc, 3, 4), RGB(255, 100, 0));
+            }
+
+            assert!(app.ball.dc().is_none());
+            assert!(Bitmap::new(0, 10).is_err());
+        }
+
+        {
+            use winapi::um::winuser::{SendMessageW, WM_SYSCOMMAND};
+
+            let mut menu = app.window.system_menu();
+            assert!(menu.contains(SystemMenuItem::Close));
+            assert!(menu.enabled(SystemMenuItem::Close));
+            menu.set_enabled(SystemMenuItem::Close, false);
+            assert!(!menu.enabled(SystemMenuItem::Close));
+            menu.set_enabled(SystemMenuItem::Close, true);
+            menu.remove(SystemMenuItem::Size);
+            assert!(!menu.contains(SystemMenuItem::Size));
+
+            menu.append_separator();
+            let about = menu.append("&About");
+            let (_, id) = about.hmenu_item().unwrap();
+            assert_eq!(id & 0xF, 0);
+
+            unsafe { SendMessageW(app.window.handle.hwnd().unwrap(), WM_SYSCOMMAND, id as _, 0); }
+            assert_eq!(app.system_menu_item.borrow_mut().take(), Some(about));
+
+            menu.reset();
+            assert!(menu.contains(SystemMenuItem::Close));
+        }
+
+        {
+            // Right Ctrl auto-repeat: repeat count 1, scan code 0x1D, extended, previous key state down
+            let mut handled = false;
+            let key = KeyData { key: keys::CONTROL, flags: 0x411D_0001, handled: &mut handled as *mut bool };
+            assert_eq!(key.key(), keys::CONTROL);
+            assert_eq!(key.scan_code(), 0x1D);
+            assert_eq!(key.extended(), true);
+            assert_eq!(key.repeat(), true);
+            assert_eq!(key.repeat_count(), 1);
+
+            let data = EventData::OnKey(key);
+            assert_eq!(data.on_key(), keys::CONTROL);
+
+            // Left Ctrl first press
+            let key = KeyData { key: keys::CONTROL, flags: 0x001D_0001, handled: &mut handled as *mut bool };
+            assert_eq!(key.scan_code(), 0x1D);
+            assert_eq!(key.extended(), false);
+            assert_eq!(key.repeat(), false);
+        }
+
+        {
+            let normal = app.window.normal_rect();
+            let (w, h) = app.window.size();
+            assert_eq!((normal[2] - normal[0], normal[3] - normal[1]), (w as i32, h as i32));
+
+            app.window.maximize();
+            let (max_w, max_h) = app.window.size();
+            assert!((max_w, max_h) != (w, h));
+            assert_eq!(app.window.normal_rect(), normal);
+
+            let moved = [normal[0] + 10, normal[1] + 10, normal[2] + 30, normal[3] + 20];
+            app.window.set_normal_rect(moved);
+            assert_eq!(app.window.size(), (max_w, max_h));
+
+            app.window.restore();
+            assert_eq!(app.window.normal_rect(), moved);
+            app.window.set_normal_rect(normal);
+            assert_eq!(app.window.size(), (w, h));
+        }
+
+        {
+            use winapi::um::winuser::{PeekMessageW, DispatchMessageW, MSG, PM_REMOVE};
+            use crate::win32::window_helper::NWG_LAYOUT;
+
+            let panel = app.panel.handle.hwnd().unwrap();
+            let dispatch_layout = || unsafe {
+                let mut msg: MSG = ::std::mem::zeroed();
+                while PeekMessageW(&mut msg, panel, NWG_LAYOUT, NWG_LAYOUT, PM_REMOVE) != 0 {
+                    DispatchMessageW(&msg);
+                }
+            };
+
+            // The event is posted
+            app.panel_layout.fit();
+            assert!(app.layout.borrow_mut().is_none());
+            dispatch_layout();
+            assert_eq!(app.layout.borrow_mut().take(), Some(LayoutCause::Manual));
+
+            let (w, h) = app.panel.size();
+            app.panel.set_size(w + 20, h);
+            dispatch_layout();
+            assert_eq!(app.layout.borrow_mut().take(), Some(LayoutCause::Resize));
+
+            app.panel.set_size(w, h);
+            dispatch_layout();
+            app.layout.borrow_mut().take();
+        }
+
+        {
+            use winapi::um::winuser::{SendMessageW, IsZoomed, WM_SYSCOMMAND, SC_MAXIMIZE, SC_MINIMIZE};
+
+            // The four low bits are masked. The handler consumes `SC_MAXIMIZE` once the window tests ran
+            let window = app.window.handle.hwnd().unwrap();
+            app.runs.borrow_mut().window = true;
+            unsafe { SendMessageW(window, WM_SYSCOMMAND, SC_MAXIMIZE | 2, 0); }
+            assert_eq!(app.sys_command.borrow_mut().take(), Some(SC_MAXIMIZE as u32));
+            assert_eq!(unsafe { IsZoomed(window) }, 0);
+            app.runs.borrow_mut().window = false;
+
+            app.window.minimize();
+            assert!(app.sys_command.borrow_mut().take().is_none());
+            app.window.restore();
+
+            unsafe { SendMessageW(window, WM_SYSCOMMAND, SC_MINIMIZE, 0); }
+            assert_eq!(app.sys_command.borrow_mut().take(), Some(SC_MINIMIZE as u32));
+            app.window.restore();
+        }
+
+        assert!(!app.window.remember_focus());
+        app.window.set_remember_focus(true);
+        assert!(app.window.remember_focus());
+        app.window.set_remember_focus(false);
+        assert!(!app.window.remember_focus());
+
+        {
+            use winapi::um::winuser::{WM_INPUTLANGCHANGE, GetKeyboardLayout, SendMessageW};
+            let layout = keyboard::current_layout();
+            assert!(layout != 0);
+            unsafe { SendMessageW(app.window.handle.hwnd().unwrap(), WM_INPUTLANGCHANGE, 0, GetKeyboardLayout(0) as _); }
+            assert_eq!(app.input_language.borrow_mut().take(), Some(layout));
+        }
+
+        {
+            use winapi::um::winuser::{WM_IME_COMPOSITION, SendMessageW};
+            unsafe { SendMessageW(app.window.handle.hwnd().unwrap(), WM_IME_COMPOSITION, 0, 0); }
+            let data = app.ime_composition.borrow_mut().take().expect("OnImeComposition was not raised");
+            assert_eq!(data.composition(), None);
+            assert_eq!(data.result(), None);
+            app.window.handle.set_ime_position(10, 10);
+        }
+
+        app.window.set_help_button(true);
+        assert_eq!(app.window.help_button(), true);
+        app.window.set_help_button(false);
+        assert_eq!(app.window.help_button(), false);
+
+        {
+            use winapi::um::winuser::{WM_HELP, HELPINFO, HELPINFO_WINDOW, SendMessageW};
+            let button = app.test_button.handle.hwnd().unwrap();
+            let mut info: HELPINFO = unsafe { std::mem::zeroed() };
+            info.cbSize = std::mem::size_of::<HELPINFO>() as u32;
+            info.iContextType = HELPINFO_WINDOW as i32;
+            info.iCtrlId = 7;
+            info.hItemHandle = button as _;
+            info.MousePos.x = 30;
+            info.MousePos.y = 40;
+
+            unsafe { SendMessageW(button, WM_HELP, 0, &mut info as *mut HELPINFO as _); }
+            let (source, data) = app.help.borrow_mut().take().expect("OnHelp was not raised");
+            assert_eq!(source, app.test_button.handle);
+            assert_eq!(data.menu(), false);
+            assert_eq!(data.control_id(), 7);
+            assert_eq!(data.position(), (30, 40));
+        }
+
+        {
+            use winapi::um::winuser::{WM_ACTIVATE, WA_INACTIVE, WA_CLICKACTIVE, SendMessageW};
+            let hwnd = app.window.handle.hwnd().unwrap();
+            unsafe { SendMessageW(hwnd, WM_ACTIVATE, WA_INACTIVE as _, 0); }
+            assert_eq!(app.window_active.borrow_mut().take(), Some(false));
+            unsafe { SendMessageW(hwnd, WM_ACTIVATE, WA_CLICKACTIVE as _, 0); }
+            assert_eq!(app.window_active.borrow_mut().take(), Some(true));
+        }
+
+        {
+            use winapi::um::winuser::{WM_ENTERSIZEMOVE, WM_EXITSIZEMOVE, SendMessageW};
+            let hwnd = app.window.handle.hwnd().unwrap();
+            unsafe { SendMessageW(hwnd, WM_ENTERSIZEMOVE, 0, 0); }
+            assert_eq!(app.size_move.borrow_mut().take(), Some(true));
+            unsafe { SendMessageW(hwnd, WM_EXITSIZEMOVE, 0, 0); }
+            assert_eq!(app.size_move.borrow_mut().take(), Some(false));
+        }
+
+        {
+            use winapi::um::winuser::{WM_SETTINGCHANGE, SPI_SETMOUSESPEED, SendMessageW};
+            use crate::win32::base_helper::to_utf16;
+            let hwnd = app.window.handle.hwnd().unwrap();
+            let area = to_utf16("ImmersiveColorSet");
+            unsafe { SendMessageW(hwnd, WM_SETTINGCHANGE, 0, area.as_ptr() as _); }
+            assert_eq!(app.setting_change.borrow_mut().take(), Some(("ImmersiveColorSet".to_string(), 0)));
+            unsafe { SendMessageW(hwnd, WM_SETTINGCHANGE, SPI_SETMOUSESPEED as _, 0); }
+            assert_eq!(app.setting_change.borrow_mut().take(), Some((String::new(), SPI_SETMOUSESPEED)));
+        }
+
+        {
+            let msg = register_message("NWG_TEST_SETTINGS_CHANGED_6F1C2A");
+            assert!(msg >= 0xC000);
+            assert_eq!(register_message("NWG_TEST_SETTINGS_CHANGED_6F1C2A"), msg);
+
+            assert!(broadcast_message(msg, 1, 2));
+            assert_eq!(app.custom_message.borrow_mut().take(), None);
+
+            app.window.subscribe_message(msg);
+            assert!(broadcast_message(msg, 1, 2));
+            assert_eq!(app.custom_message.borrow_mut().take(), Some((msg, 1, 2)));
+
+            app.window.unsubscribe_message(msg);
+            assert!(broadcast_message(msg, 3, 4));
+            assert_eq!(app.custom_message.borrow_mut().take(), None);
+        }
+
+        {
+            use winapi::um::winuser::{WM_MOUSEWHEEL, WM_MOUSEHWHEEL, SendMessageW};
+            let hwnd = app.window.handle.hwnd().unwrap();
+            unsafe { SendMessageW(hwnd, WM_MOUSEHWHEEL, (120 << 16) as _, 0); }
+            assert_eq!(app.mouse_wheel.borrow_mut().take(), Some((120, true)));
+            unsafe { SendMessageW(hwnd, WM_MOUSEWHEEL, ((-120i16 as u16 as usize) << 16) as _, 0); }
+            assert_eq!(app.mouse_wheel.borrow_mut().take(), Some((-120, false)));
+        }
+
+        assert_eq!(app.window.transparency_key(), None);
+        app.window.set_transparency_key(Some([255, 0, 255]));
+        assert_eq!(app.window.transparency_key(), Some([255, 0, 255]));
+        app.window.set_transparency_key(None);
+        assert_eq!(app.window.transparency_key(), None);
+
+        assert_eq!(app.window.taskbar_visible(), true);
+        app.window.set_taskbar_visible(false);
+        assert_eq!(app.window.taskbar_visible(), false);
+        assert_eq!(app.window.visible(), true);
+        app.window.set_taskbar_visible(true);
+        assert_eq!(app.window.taskbar_visible(), true);
+
+        let region = Region::from_shape(RegionShape::RoundedRect { position: (0, 0), size: (500, 420), radius: 16 }).unwrap();
+        assert!(region.contains(250, 200));
+        assert!(!region.contains(0, 0));
+        assert!(app.window.set_region(region));
+        assert!(app.window.set_region(Region::default()));
+
+        assert_eq!(app.window.display_affinity(), DisplayAffinity::None);
+        assert!(app.window.set_display_affinity(DisplayAffinity::Monitor));
+        assert_eq!(app.window.display_affinity(), DisplayAffinity::Monitor);
+        app.window.set_display_affinity(DisplayAffinity::None);
+
+        {
+            use std::rc::Rc;
+            use winapi::um::winuser::{WM_CLOSE, MSG, IsWindow, PeekMessageW, DispatchMessageW, SendMessageW, PM_REMOVE};
+
+            let mut close_window = Window::default();
+            Window::builder()
+                .flags(WindowFlags::WINDOW)
+                .size((100, 100))
+                .title("Close")
+                .build(&mut close_window)
+                .unwrap();
+
+            let hwnd = close_window.handle.hwnd().unwrap();
+            let closes: Rc<RefCell<Vec<bool>>> = Default::default();
+            let closes_handler = closes.clone();
+            let _handler = bind_event_handler(&close_window.handle, &close_window.handle, move |evt, data, _handle| {
+                if let (Event::OnWindowClose, EventData::OnWindowClose(data)) = (evt, data) {
+                    closes_handler.borrow_mut().push(data.confirmed());
+                    data.close(false);
+                }
+            });
+
+            // A regular close is vetoed by the handler
+            unsafe { SendMessageW(hwnd, WM_CLOSE, 0, 0); }
+            assert_eq!(*closes.borrow(), vec![false]);
+            assert!(unsafe { IsWindow(hwnd) } != 0);
+
+            // The confirmed close is posted and goes through even if the handler vetoes it again
+            close_window.request_close();
+            assert_eq!(*closes.borrow(), vec![false]);
+            unsafe {
+                let mut msg: MSG = std::mem::zeroed();
+                while PeekMessageW(&mut msg, hwnd, WM_CLOSE, WM_CLOSE, PM_REMOVE) != 0 {
+                    DispatchMessageW(&msg);
+                }
+            }
+            assert_eq!(*closes.borrow(), vec![false, true]);
+            assert_eq!(unsafe { IsWindow(hwnd) }, 0);
+
+            // The window was destroyed, so the handler cannot be unbound anymore
+        }
+
         app.runs.borrow_mut().window = true;
     } else {
         app.window.set_text("Controls");
@@ -1072,6 +1574,159 @@ fn run_button_tests(app: &ControlsTest, _evt: Event) {
         app.test_button.set_size(120, 35);
         assert_eq!(app.test_button.size(), (120, 35));
 
+        app.test_button.click();
+        assert_eq!(app.button_click.borrow_mut().take(), Some((false, true)));
+
+        {
+            use winapi::um::winuser::{WM_LBUTTONDOWN, WM_LBUTTONUP, MK_LBUTTON, SendMessageW};
+            let button = app.test_button.handle.hwnd().unwrap();
+            let point = (5 | (5 << 16)) as _;
+            unsafe {
+                SendMessageW(button, WM_LBUTTONDOWN, MK_LBUTTON, point);
+                SendMessageW(button, WM_LBUTTONUP, 0, point);
+            }
+            assert_eq!(app.button_click.borrow_mut().take(), Some((false, false)));
+
+            // A disabled button ignores `click`, and the next mouse click is not reported as programmatic
+            app.test_button.set_enabled(false);
+            app.test_button.click();
+            assert_eq!(app.button_click.borrow_mut().take(), None);
+            app.test_button.set_enabled(true);
+
+            unsafe {
+                SendMessageW(button, WM_LBUTTONDOWN, MK_LBUTTON, point);
+                SendMessageW(button, WM_LBUTTONUP, 0, point);
+            }
+            assert_eq!(app.button_click.borrow_mut().take(), Some((false, false)));
+        }
+
+        {
+            use winapi::um::winuser::{PeekMessageW, DispatchMessageW, GetParent, MSG, PM_REMOVE, WM_COMMAND, BN_CLICKED};
+            assert!(app.test_button.handle.post_command(BN_CLICKED as u16));
+            assert!(app.button_click.borrow_mut().is_none());
+
+            unsafe {
+                let parent = GetParent(app.test_button.handle.hwnd().unwrap());
+                let mut msg: MSG = ::std::mem::zeroed();
+                while PeekMessageW(&mut msg, parent, WM_COMMAND, WM_COMMAND, PM_REMOVE) != 0 {
+                    DispatchMessageW(&msg);
+                }
+            }
+            assert!(app.button_click.borrow_mut().take().is_some());
+        }
+
+        {
+            use winapi::um::winuser::{WM_COMMAND, IDOK, IDCANCEL, BS_DEFPUSHBUTTON, SendMessageW};
+            let window = app.window.handle.hwnd().unwrap();
+
+            app.window.set_default_button(Some(&app.test_button.handle));
+            assert_eq!(app.window.default_button(), Some(app.test_button.handle));
+            assert_eq!(app.test_button.handle.style() & 0xF, BS_DEFPUSHBUTTON);
+            unsafe { SendMessageW(window, WM_COMMAND, IDOK as _, 0); }
+            assert_eq!(app.button_click.borrow_mut().take(), Some((false, true)));
+
+            app.window.set_default_button(None);
+            assert_eq!(app.test_button.handle.style() & 0xF, 0);
+
+            app.window.set_cancel_button(Some(&app.test_button.handle));
+            unsafe { SendMessageW(window, WM_COMMAND, IDCANCEL as _, 0); }
+            assert_eq!(app.button_click.borrow_mut().take(), Some((false, true)));
+            app.window.set_cancel_button(None);
+            assert_eq!(app.window.cancel_button(), None);
+        }
+
+        {
+            use winapi::um::winuser::{SendMessageW, ClientToScreen, WM_NOTIFY};
+            use winapi::um::commctrl::{NMBCDROPDOWN, BCN_DROPDOWN};
+            use winapi::shared::windef::{RECT, POINT};
+            use winapi::um::winuser::NMHDR;
+
+            let button = app.test_button.handle.hwnd().unwrap();
+            let window = app.window.handle.hwnd().unwrap();
+            let mut notif = NMBCDROPDOWN {
+                hdr: NMHDR { hwndFrom: button, idFrom: 0, code: BCN_DROPDOWN },
+                rcButton: RECT { left: 0, top: 0, right: 20, bottom: 10 },
+            };
+
+            let mut origin = POINT { x: 0, y: 0 };
+            unsafe {
+                ClientToScreen(button, &mut origin);
+                SendMessageW(window, WM_NOTIFY, 0, &mut notif as *mut NMBCDROPDOWN as _);
+            }
+
+            assert_eq!(app.button_dropdown.borrow_mut().take(), Some([origin.x, origin.y, origin.x + 20, origin.y + 10]));
+            assert!(app.button_click.borrow_mut().is_none());
+        }
+
+        let (x, y) = app.test_button.position();
+        let (w, h) = app.test_button.size();
+        app.test_button.handle.begin_update().position((x + 10, y + 5)).size((w + 20, h)).enabled(false).apply();
+        assert_eq!(app.test_button.position(), (x + 10, y + 5));
+        assert_eq!(app.test_button.size(), (w + 20, h));
+        assert_eq!(app.test_button.enabled(), false);
+        app.test_button.handle.begin_update().enabled(true).apply();
+        assert_eq!(app.test_button.position(), (x + 10, y + 5));
+        assert_eq!(app.test_button.enabled(), true);
+        app.test_button.handle.begin_update().position((x, y)).size((w, h)).apply();
+
+        let [sl, st, sr, sb] = app.test_button.handle.rect_screen();
+        let [pl, pt, pr, pb] = app.test_button.handle.rect_parent();
+        let [cl, ct, cr, cb] = app.test_button.handle.rect_client();
+        assert_eq!((sr - sl, sb - st), (pr - pl, pb - pt));
+        assert_eq!((cl, ct), (0, 0));
+        assert!(cr <= sr - sl && cb <= sb - st);
+        assert_eq!(app.window.handle.rect_parent(), app.window.handle.rect_screen());
+
+        let dpi = app.test_button.handle.dpi();
+        assert!(dpi >= 96, "Unexpected DPI: {}", dpi);
+        assert_eq!(app.window.handle.dpi(), dpi);
+
+        let _ = system::is_rtl_locale();
+        assert!(!app.test_button.handle.rtl());
+        app.test_button.handle.set_rtl(true);
+        assert!(app.test_button.handle.rtl());
+        app.test_button.handle.set_rtl(false);
+        assert!(!app.test_button.handle.rtl());
+
+        app.test_button.set_text("Ok");
+        app.test_button.fit_to_content(5);
+        let (short_width, _) = app.test_button.size();
+        app.test_button.set_text("A much longer button caption");
+        let preferred = app.test_button.preferred_size();
+        assert!(preferred.0 > short_width);
+        app.test_button.fit_to_content(5);
+        assert!(app.test_button.size().0 > short_width);
+        assert_eq!(app.test_button.handle.preferred_size(), app.test_button.size());
+
+        let (list_width, list_height) = app.test_list_box1.preferred_size();
+        assert!(list_width > 0 && list_height > 0);
+        let (input_width, _) = app.test_text_input.preferred_size();
+        assert!(input_width >= 100);
+
+        if app.basics_control_tab.visible() {
+            let (x, y) = app.test_button.position();
+            let (w, h) = app.test_button.size();
+            let center = client_to_screen(&app.basics_control_tab, (x + (w / 2) as i32, y + (h / 2) as i32));
+            assert_eq!(window_from_point(center.0, center.1), Some(app.test_button.handle));
+        }
+
+        app.test_button.handle.highlight(std::time::Duration::from_secs(5), [255, 0, 0]).unwrap();
+        assert_eq!(app.test_button.handle.is_highlighted(), true);
+        app.test_button.handle.cancel_highlight();
+        assert_eq!(app.test_button.handle.is_highlighted(), false);
+
+        let (x, y) = app.test_button.position();
+        let (w, h) = app.test_button.size();
+        app.test_button.handle.animate_to((x + 20, y), (w, h), std::time::Duration::from_millis(200), Easing::EaseOut, || {});
+        assert_eq!(app.test_button.handle.is_animating(), true);
+        app.test_button.handle.stop_animation();
+        assert_eq!(app.test_button.handle.is_animating(), false);
+        app.test_button.set_position(x, y);
+
+        assert_eq!(Easing::EaseInOut.apply(0.0), 0.0);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert_eq!(Easing::EaseIn.apply(1.0), 1.0);
+
         if app.basics_control_tab.visible() {
             assert_eq!(app.test_button.visible(), true);
             app.test_button.set_visible(false);
@@ -1081,13 +1736,57 @@ fn run_button_tests(app: &ControlsTest, _evt: Event) {
 
         app.test_button.set_focus();
         assert_eq!(app.test_button.focus(), true);
+        assert_eq!(focused_control(), Some(app.test_button.handle));
         app.window.set_focus();
         assert_eq!(app.test_button.focus(), false);
+        assert_eq!(focused_control(), Some(app.window.handle));
+
+        set_focus(&app.test_button.handle);
+        assert_eq!(app.test_button.focus(), true);
+        app.window.set_focus();
+
+        {
+            use winapi::um::winuser::{SendMessageW, IsDialogMessageW, WM_NEXTDLGCTL, WM_SYSCHAR, MSG};
+            use winapi::shared::windef::POINT;
+
+            let text = app.test_button.text();
+            app.test_button.set_text("Fish && &Chips");
+            assert_eq!(app.test_button.handle.mnemonic(), Some('c'));
+            app.test_button.set_text("Fish && Chips");
+            assert_eq!(app.test_button.handle.mnemonic(), None);
+
+            let window = app.window.handle.hwnd().unwrap();
+            let button = app.test_button.handle.hwnd().unwrap();
+            unsafe { SendMessageW(window, WM_NEXTDLGCTL, button as _, 1); }
+            assert_eq!(app.test_button.focus(), true);
+            app.window.set_focus();
+
+            app.test_button.set_text("&Save");
+            let mut msg = MSG { hwnd: window, message: WM_SYSCHAR, wParam: 's' as _, lParam: 1 << 29, time: 0, pt: POINT { x: 0, y: 0 } };
+            unsafe { IsDialogMessageW(window, &mut msg); }
+            assert!(app.button_click.borrow_mut().take().is_some());
+
+            app.test_button.set_text(&text);
+        }
 
         assert_eq!(app.test_button.enabled(), true);
         app.test_button.set_enabled(false);
         assert_eq!(app.test_button.enabled(), false);
 
+        let controls = [app.test_button.handle, app.test_checkbox1.handle, app.test_list_box1.handle];
+        for handle in controls.iter() { set_enabled(handle, false); }
+        assert!(controls.iter().all(|h| !is_enabled(h)));
+        for handle in controls.iter().skip(1) { set_enabled(handle, true); }
+        assert_eq!(is_enabled(&app.test_checkbox1.handle), true);
+
+        set_focus_visuals(&app.test_button.handle, true);
+        assert_eq!(focus_visuals(&app.window.handle), true);
+        assert_eq!(focus_visuals(&app.test_button.handle), true);
+        set_focus_visuals(&app.window.handle, false);
+        assert_eq!(focus_visuals(&app.test_button.handle), false);
+        set_focus_visuals(&app.window.handle, true);
+        assert_eq!(is_visible(&app.window.handle), true);
+
 
         let mut icon = None;
         let mut bitmap = None;
@@ -1102,6 +1801,141 @@ fn run_button_tests(app: &ControlsTest, _evt: Event) {
         app.test_image_button2.image(&mut bitmap, &mut icon);
         assert!(icon.is_none() && bitmap.is_none());
 
+        let mut button_images = ImageList::default();
+        ImageList::builder().size((16, 16)).dpi_scaled(true).build(&mut button_images).unwrap();
+        assert_eq!(button_images.size(), unsafe { crate::win32::high_dpi::logical_to_physical(16, 16) });
+        let index = button_images.add_bitmap(&app.popcorn_small);
+        assert_eq!(button_images.len(), 1);
+
+        app.test_button.set_image_list(Some(&button_images));
+        assert_eq!(app.test_button.image_list().map(|l| l.handle), Some(button_images.handle));
+        app.test_button.set_image_list(None);
+        assert!(app.test_button.image_list().is_none());
+
+        button_images.remove(index);
+        assert_eq!(button_images.len(), 0);
+
+        let mut frame = Frame::default();
+        let mut frame_buttons: [Button; 3] = Default::default();
+        Frame::builder().parent(&app.window).size((300, 40)).build(&mut frame).unwrap();
+        for (i, button) in frame_buttons.iter_mut().enumerate() {
+            Button::builder().parent(&frame).position((i as i32 * 100, 0)).size((90, 30)).build(button).unwrap();
+        }
+
+        frame.focus_next();
+        assert_eq!(frame_buttons[0].focus(), true);
+        frame.focus_next();
+        frame.focus_next();
+        assert_eq!(frame_buttons[2].focus(), true);
+        frame.focus_next();
+        assert_eq!(frame_buttons[0].focus(), true);
+        frame.focus_prev();
+        assert_eq!(frame_buttons[2].focus(), true);
+
+        frame_buttons[1].set_enabled(false);
+        frame.focus_prev();
+        assert_eq!(frame_buttons[0].focus(), true);
+        app.window.set_focus();
+
+        assert!(frame.control_parent());
+        frame.set_control_parent(false);
+        assert!(!frame.control_parent());
+        frame.set_control_parent(true);
+        assert!(frame.control_parent());
+
+        {
+            use winapi::um::winuser::{SetScrollInfo, GetScrollInfo, SCROLLINFO, SIF_RANGE, SIF_PAGE, SIF_POS, SB_VERT, WS_VSCROLL};
+
+            let mut scrolled_frame = Frame::default();
+            let mut far_button = Button::default();
+            Frame::builder().parent(&app.window).size((200, 100)).build(&mut scrolled_frame).unwrap();
+            Button::builder().parent(&scrolled_frame).position((0, 300)).size((100, 30)).build(&mut far_button).unwrap();
+
+            // Nothing to scroll without a scroll bar
+            assert!(!far_button.handle.scroll_into_view());
+
+            let frame_handle = scrolled_frame.handle.hwnd().unwrap();
+            scrolled_frame.handle.modify_style(WS_VSCROLL, 0);
+            let view = scrolled_frame.handle.rect_client()[3];
+            unsafe {
+                let mut si: SCROLLINFO = ::std::mem::zeroed();
+                si.cbSize = ::std::mem::size_of::<SCROLLINFO>() as u32;
+                si.fMask = SIF_RANGE | SIF_PAGE | SIF_POS;
+                si.nMax = 1000;
+                si.nPage = view as u32;
+                SetScrollInfo(frame_handle, SB_VERT as i32, &si, 0);
+            }
+
+            let [_, old_top, _, old_bottom] = far_button.handle.rect_parent();
+            assert!(far_button.handle.scroll_into_view());
+            assert!(!far_button.handle.scroll_into_view());
+
+            // The bottom of the button is aligned with the bottom of the view
+            let position = unsafe {
+                let mut si: SCROLLINFO = ::std::mem::zeroed();
+                si.cbSize = ::std::mem::size_of::<SCROLLINFO>() as u32;
+                si.fMask = SIF_POS;
+                GetScrollInfo(frame_handle, SB_VERT as i32, &mut si);
+                si.nPos
+            };
+            assert_eq!(position, old_bottom - view);
+            assert_eq!(far_button.handle.rect_parent()[1], old_top - position);
+        }
+
+        {
+            // A suspended batch gives the same geometry as the children added one by one
+            let mut frames: [Frame; 2] = Default::default();
+            let mut buttons: [[Button; 6]; 2] = Default::default();
+            let layouts: [GridLayout; 2] = Default::default();
+
+            for i in 0..2 {
+                Frame::builder().parent(&app.window).size((300, 200)).build(&mut frames[i]).unwrap();
+                for button in buttons[i].iter_mut() {
+                    Button::builder().parent(&frames[i]).build(button).unwrap();
+                }
+
+                GridLayout::builder().parent(&frames[i]).max_column(Some(3)).build(&layouts[i]).unwrap();
+            }
+
+            for (j, button) in buttons[0].iter().enumerate() {
+                layouts[0].add_child(j as u32 % 3, j as u32 / 3, button);
+            }
+
+            layouts[1].suspend();
+            assert!(layouts[1].suspended());
+            for (j, button) in buttons[1].iter().enumerate() {
+                layouts[1].add_child(j as u32 % 3, j as u32 / 3, button);
+            }
+            layouts[1].resume();
+            assert!(!layouts[1].suspended());
+
+            for j in 0..6 {
+                assert_eq!(buttons[0][j].position(), buttons[1][j].position());
+                assert_eq!(buttons[0][j].size(), buttons[1][j].size());
+            }
+        }
+
+        {
+            // `suspend` only sets a flag, so it can be called before the layouts are built
+            let grid = GridLayout::default();
+            grid.suspend();
+            assert!(grid.suspended());
+
+            #[cfg(feature = "dynamic_layout")]
+            {
+                let dynamic = DynLayout::default();
+                dynamic.suspend();
+                assert!(dynamic.suspended());
+            }
+
+            #[cfg(feature = "flexbox")]
+            {
+                let flexbox = FlexboxLayout::default();
+                flexbox.suspend();
+                assert!(flexbox.suspended());
+            }
+        }
+
         app.runs.borrow_mut().button = true;
     } else {
         app.test_button.set_text("A simple button");
@@ -1176,6 +2010,90 @@ fn run_combo_tests(app: &ControlsTest, _evt: Event) {
 
         app.test_combo.remove(0);
 
+        app.test_combo.clear_selection();
+        assert_eq!(app.test_combo.selection(), None);
+
+        app.test_combo.set_selection(Some(0));
+        app.test_combo.clear();
+        assert_eq!(app.test_combo.len(), 0);
+        assert_eq!(app.test_combo.collection().len(), 0);
+        assert_eq!(app.test_combo.selection(), None);
+        app.test_combo.set_collection(vec!["Chocolate", "Strawberry", "Blueberry"]);
+
+        assert_eq!(app.test_combo.editable(), false);
+        app.test_combo.set_placeholder(Some("Ignored"));
+
+        let mut editable_combo: ComboBox<&'static str> = ComboBox::default();
+        ComboBox::builder()
+            .editable(true)
+            .collection(vec!["Vanilla", "Mint"])
+            .parent(&app.window)
+            .build(&mut editable_combo)
+            .unwrap();
+
+        assert_eq!(editable_combo.editable(), true);
+        editable_combo.set_placeholder(Some("Pick a flavor"));
+        editable_combo.set_placeholder(None);
+        editable_combo.set_selection(Some(1));
+        assert_eq!(editable_combo.selection_string(), Some("Mint".to_string()));
+
+        // The focus is on the edit field of the combobox
+        set_focus(&editable_combo.handle);
+        assert_eq!(focused_control(), Some(editable_combo.handle));
+        app.window.set_focus();
+
+        {
+            use winapi::um::winuser::{SendMessageW, WM_COMMAND, CBN_SELCHANGE};
+            use std::rc::Rc;
+
+            let build = |combo: &mut ComboBox<&'static str>, collection: Vec<&'static str>| {
+                ComboBox::builder().collection(collection).parent(&app.window).build(combo).unwrap();
+            };
+
+            let mut country: ComboBox<&'static str> = ComboBox::default();
+            let mut state: ComboBox<&'static str> = ComboBox::default();
+            let mut city: ComboBox<&'static str> = ComboBox::default();
+            build(&mut country, vec!["Canada", "France"]);
+            build(&mut state, Vec::new());
+            build(&mut city, Vec::new());
+            let (state, city) = (Rc::new(state), Rc::new(city));
+
+            let state_handler = country.on_change_repopulate(&state, |country| match country {
+                Some("Canada") => vec!["Ontario", "Quebec"],
+                Some(_) => vec!["Bretagne"],
+                None => Vec::new()
+            }).unwrap();
+
+            city.push("Stale");
+            let _city_handler = state.on_change_repopulate(&city, |state| match state {
+                Some("Quebec") => vec!["Montreal", "Quebec City"],
+                _ => Vec::new()
+            }).unwrap();
+
+            // Simulates a selection by the user
+            let select = |combo: &ComboBox<&'static str>, index: usize| unsafe {
+                combo.set_selection(Some(index));
+                let window = app.window.handle.hwnd().unwrap();
+                SendMessageW(window, WM_COMMAND, (CBN_SELCHANGE as usize) << 16, combo.handle.hwnd().unwrap() as _);
+            };
+
+            select(&country, 0);
+            assert_eq!(&state.collection() as &[&'static str], &["Ontario", "Quebec"]);
+            assert_eq!(state.selection(), None);
+            assert_eq!(city.len(), 0);
+
+            select(&state, 1);
+            assert_eq!(&city.collection() as &[&'static str], &["Montreal", "Quebec City"]);
+
+            select(&country, 1);
+            assert_eq!(&state.collection() as &[&'static str], &["Bretagne"]);
+            assert_eq!(city.len(), 0);
+
+            unbind_raw_event_handler(&state_handler).unwrap();
+            select(&country, 0);
+            assert_eq!(&state.collection() as &[&'static str], &["Bretagne"]);
+        }
+
         app.test_combo.dropdown(true);
 
         app.runs.borrow_mut().combo = true;
@@ -1233,6 +2151,11 @@ fn run_font_tests(app: &ControlsTest, _evt: Event) {
 
         assert_eq!(app.test_label.font().as_ref(), Some(&app.arial_font));
 
+        let font_96 = Font::from_point_size_for_dpi("Arial", 12, 96).unwrap();
+        let font_192 = Font::from_point_size_for_dpi("Arial", 12, 192).unwrap();
+        assert!(font_192.logical_height() > font_96.logical_height());
+        assert_eq!(font_96.rescale(192).unwrap().logical_height(), font_192.logical_height());
+
         app.runs.borrow_mut().font = true;
     } else {
         app.test_label.set_font(None);
@@ -1257,9 +2180,151 @@ fn run_list_tests(app: &ControlsTest, _evt: Event) {
     if !app.runs.borrow().list {
         app.test_list_box2.unselect_all();
 
+        {
+            use winapi::um::winuser::{WM_KEYDOWN, VK_DOWN, SendMessageW};
+
+            let handle = app.test_list_box1.handle.hwnd().unwrap();
+            app.test_list_box1.set_selection(Some(0));
+
+            unsafe { SendMessageW(handle, WM_KEYDOWN, VK_DOWN as _, 0); }
+            assert_eq!(app.list_select.borrow_mut().take(), Some((Some(0), Some(1))));
+
+            unsafe { SendMessageW(handle, WM_KEYDOWN, VK_DOWN as _, 0); }
+            assert_eq!(app.list_select.borrow_mut().take(), Some((Some(1), Some(2))));
+        }
+
+        {
+            use winapi::um::winuser::{WM_COMMAND, LBN_SETFOCUS, LBN_KILLFOCUS, SendMessageW, GetParent, GetDlgCtrlID};
+            use winapi::shared::minwindef::MAKELONG;
+
+            let handle = app.test_list_box1.handle.hwnd().unwrap();
+            unsafe {
+                let parent = GetParent(handle);
+                let id = GetDlgCtrlID(handle) as u16;
+
+                SendMessageW(parent, WM_COMMAND, MAKELONG(id, LBN_SETFOCUS) as _, handle as _);
+                assert_eq!(app.list_focus.borrow_mut().take(), Some(true));
+
+                SendMessageW(parent, WM_COMMAND, MAKELONG(id, LBN_KILLFOCUS) as _, handle as _);
+                assert_eq!(app.list_focus.borrow_mut().take(), Some(false));
+            }
+        }
+
+        {
+            use winapi::um::winuser::{WM_COMMAND, WM_KEYDOWN, LBN_DBLCLK, VK_RETURN, VK_ESCAPE, WM_CLOSE, MSG, PM_REMOVE,
+                SendMessageW, PeekMessageW, DispatchMessageW, FindWindowExW, IsWindow, GetDlgCtrlID};
+            use winapi::shared::minwindef::MAKELONG;
+            use crate::win32::base_helper::to_utf16;
+            use crate::win32::window_helper as wh;
+            use std::{mem, ptr, rc::Rc};
+
+            let mut names: ListBox<String> = ListBox::default();
+            ListBox::builder()
+                .collection(vec!["Alice".to_string(), "Bob".to_string()])
+                .parent(&app.window)
+                .build(&mut names)
+                .unwrap();
+
+            let names = Rc::new(names);
+            let handler = names.enable_inline_edit(|_index, text| match text.trim() {
+                "" => None,
+                name => Some(name.to_string())
+            }).unwrap();
+
+            let handle = names.handle.hwnd().unwrap();
+            let rect = names.item_rect(1).unwrap();
+            assert!(rect[3] > rect[1]);
+            assert_eq!(names.item_rect(2), None);
+
+            // Simulates a double click on a row and returns the input over the row
+            let edit = |index: usize| unsafe {
+                names.set_selection(Some(index));
+                let id = GetDlgCtrlID(handle) as u16;
+                SendMessageW(app.window.handle.hwnd().unwrap(), WM_COMMAND, MAKELONG(id, LBN_DBLCLK) as _, handle as _);
+
+                let class = to_utf16("Edit");
+                let input = FindWindowExW(handle, ptr::null_mut(), class.as_ptr(), ptr::null());
+                assert!(!input.is_null());
+                assert_eq!(wh::get_window_text(input), names.collection()[index]);
+                input
+            };
+
+            let input = edit(1);
+            unsafe {
+                wh::set_window_text(input, "Carol");
+                SendMessageW(input, WM_KEYDOWN, VK_RETURN as _, 0);
+            }
+            assert_eq!(&names.collection() as &[String], &["Alice".to_string(), "Carol".to_string()]);
+            assert_eq!(names.selection_string(), Some("Carol".to_string()));
+            assert_eq!(names.selection(), Some(1));
+
+            let input = edit(0);
+            unsafe {
+                wh::set_window_text(input, "Dave");
+                SendMessageW(input, WM_KEYDOWN, VK_ESCAPE as _, 0);
+            }
+            assert_eq!(names.collection()[0], "Alice");
+
+            // Rejected by the closure
+            let input = edit(0);
+            unsafe {
+                wh::set_window_text(input, "  ");
+                SendMessageW(input, WM_KEYDOWN, VK_RETURN as _, 0);
+            }
+            assert_eq!(names.collection()[0], "Alice");
+
+            // The inputs are destroyed with the posted `WM_CLOSE`
+            unsafe {
+                let mut msg: MSG = mem::zeroed();
+                while PeekMessageW(&mut msg, ptr::null_mut(), WM_CLOSE, WM_CLOSE, PM_REMOVE) != 0 {
+                    DispatchMessageW(&msg);
+                }
+
+                assert_eq!(IsWindow(input), 0);
+            }
+
+            unbind_raw_event_handler(&handler).unwrap();
+        }
+
         {
             let col = app.test_list_box1.collection();
             assert_eq!(&col as &[&'static str], &["Red", "White", "Green", "Yellow"]);
+            let expected: Vec<String> = col.iter().map(|c| format!("{}", c)).collect();
+            drop(col);
+            assert_eq!(app.test_list_box1.to_string_list(), expected);
+        }
+
+        {
+            app.test_list_box1.set_selection(Some(2));
+            let items: Vec<(usize, &'static str, bool)> = app.test_list_box1.iter_with_state().map(|(i, item, selected)| (i, *item, selected)).collect();
+            assert_eq!(items, vec![(0, "Red", false), (1, "White", false), (2, "Green", true), (3, "Yellow", false)]);
+
+            app.test_list_box2.multi_add_selection(1);
+            app.test_list_box2.multi_add_selection(4);
+            let selected: Vec<&'static str> = app.test_list_box2.iter_with_state().filter(|(_, _, selected)| *selected).map(|(_, item, _)| *item).collect();
+            assert_eq!(selected, vec!["Dog", "Ogre"]);
+            assert_eq!(app.test_list_box2.iter_with_state().count(), 5);
+            app.test_list_box2.unselect_all();
+        }
+
+        {
+            assert_eq!(app.test_list_box1.export(','), "Red\r\nWhite\r\nGreen\r\nYellow");
+
+            let csv = app.test_list_box1.export_with(',', |item| vec![item.to_string(), format!("a \"{}\", b", item.len())]);
+            assert!(csv.starts_with("Red,\"a \"\"3\"\", b\"\r\nWhite,"));
+
+            let tsv = app.test_list_box1.export_with('\t', |item| vec![item.to_string(), "x, y".to_string()]);
+            assert!(tsv.starts_with("Red\tx, y\r\nWhite\tx, y"));
+        }
+
+        {
+            app.test_list_box1.set_selection(Some(1));
+            assert!(app.test_list_box1.copy_selection_to_clipboard());
+            assert_eq!(Clipboard::data_text(&app.window).as_deref(), Some("White"));
+
+            app.test_list_box1.set_selection(None);
+            assert!(!app.test_list_box1.copy_selection_to_clipboard());
+            assert_eq!(Clipboard::data_text(&app.window).as_deref(), Some("White"));
         }
 
         {
@@ -1291,6 +2356,86 @@ fn run_list_tests(app: &ControlsTest, _evt: Event) {
 
         app.test_list_box1.remove(0);
 
+        assert_eq!(app.test_list_box1.len(), 7);
+        assert_eq!(app.test_list_box1.multi_column(), false);
+
+        {
+            use winapi::shared::windef::RECT;
+            use winapi::shared::minwindef::{WPARAM, LPARAM};
+            use winapi::um::winuser::{GetClientRect, LB_GETITEMHEIGHT, LB_GETITEMRECT};
+            use crate::win32::window_helper as wh;
+            use crate::win32::high_dpi::logical_to_physical;
+            use std::mem;
+
+            let item_rect = |handle, index: usize| {
+                let mut r: RECT = unsafe { mem::zeroed() };
+                wh::send_message(handle, LB_GETITEMRECT, index as WPARAM, &mut r as *mut RECT as LPARAM);
+                (r.left, r.top, r.right)
+            };
+
+            let mut grid: ListBox<String> = ListBox::default();
+            ListBox::builder()
+                .flags(ListBoxFlags::VISIBLE | ListBoxFlags::MULTI_COLUMN)
+                .collection((0..40).map(|i| format!("Item {}", i)).collect())
+                .size((200, 100))
+                .parent(&app.window)
+                .build(&mut grid)
+                .unwrap();
+
+            assert!(grid.multi_column());
+            grid.set_column_width(60);
+
+            let (width, _) = unsafe { logical_to_physical(60, 0) };
+            let handle = grid.handle.hwnd().unwrap();
+            let (left, _, right) = item_rect(handle, 0);
+            assert_eq!((left, right), (0, width));
+
+            // The items that do not fit in the height of the control flow into the second column
+            let item_height = wh::send_message(handle, LB_GETITEMHEIGHT, 0, 0) as i32;
+            let mut client: RECT = unsafe { mem::zeroed() };
+            unsafe { GetClientRect(handle, &mut client); }
+            let rows = client.bottom / item_height;
+            assert_eq!(item_rect(handle, rows as usize), (width, 0, width * 2));
+        }
+
+        {
+            struct Fruit(&'static str, u32);
+
+            impl ListViewRow for Fruit {
+                fn columns(&self) -> Vec<String> {
+                    vec![self.0.to_string(), self.1.to_string()]
+                }
+            }
+
+            let mut fruits: DataListView<Fruit> = DataListView::default();
+            DataListView::builder()
+                .columns(&["Name", "Quantity"])
+                .collection(vec![Fruit("Banana", 1000), Fruit("Apple", 345)])
+                .parent(&app.window)
+                .build(&mut fruits)
+                .unwrap();
+
+            assert_eq!(fruits.len(), 2);
+            assert_eq!(fruits.list.len(), 2);
+            assert_eq!(fruits.list.column_len(), 2);
+
+            fruits.push(Fruit("Kiwi", 194));
+            assert_eq!(fruits.list.item(2, 1, 10).map(|i| i.text), Some("194".to_string()));
+
+            assert_eq!(fruits.update(0, Fruit("Banana", 999)).1, 1000);
+            assert_eq!(fruits.list.item(0, 1, 10).map(|i| i.text), Some("999".to_string()));
+
+            fruits.list.select_item(1, true);
+            assert_eq!(fruits.map_selection(|selected| selected.iter().map(|f| f.0).collect::<Vec<_>>()), vec!["Apple"]);
+
+            assert_eq!(fruits.remove(1).0, "Apple");
+            assert_eq!(fruits.list.item(1, 0, 10).map(|i| i.text), Some("Kiwi".to_string()));
+
+            fruits.clear();
+            assert!(fruits.is_empty());
+            assert_eq!(fruits.list.len(), 0);
+        }
+
         
         app.test_list_box2.multi_add_selection(0);
         app.test_list_box2.multi_add_selection(2);
@@ -1319,6 +2464,133 @@ fn run_list_tests(app: &ControlsTest, _evt: Event) {
         assert_eq!(app.test_list_box2.multi_selection_len(), 1);
         assert_eq!(app.test_list_box2.multi_selection(), vec![2]);
 
+        app.test_list_box2.multi_add_selection(4);
+        app.test_list_box2.collection_mut().pop();
+        app.test_list_box2.sync_preserving_view();
+        assert_eq!(app.test_list_box2.len(), 4);
+        assert_eq!(app.test_list_box2.multi_selection(), vec![2]);
+        app.test_list_box2.collection_mut().push("Ogre");
+        app.test_list_box2.sync_preserving_view();
+        assert_eq!(app.test_list_box2.multi_selection(), vec![2]);
+
+        app.test_list_box1.set_collection((0..50).map(|_| "Item").collect());
+        app.test_list_box1.set_selection(Some(30));
+        app.test_list_box1.set_top_index(25);
+        let top = app.test_list_box1.top_index();
+        app.test_list_box1.collection_mut()[0] = "First";
+        app.test_list_box1.sync_preserving_view();
+        assert_eq!(app.test_list_box1.selection(), Some(30));
+        assert_eq!(app.test_list_box1.top_index(), top);
+
+        {
+            use winapi::um::winuser::{WM_NOTIFY, GetMessagePos, ScreenToClient, SendMessageW};
+            use winapi::um::commctrl::{NMITEMACTIVATE, NM_RCLICK};
+            use winapi::shared::windef::POINT;
+            use std::rc::Rc;
+            use std::mem;
+
+            let clicks: Rc<RefCell<Vec<EventData>>> = Default::default();
+            let clicks_handler = clicks.clone();
+            let list_handler = bind_event_handler(&app.test_list_view.handle, &app.list_view_tab.handle, move |evt, data, _handle| {
+                if evt == Event::OnListViewRightClick {
+                    clicks_handler.borrow_mut().push(data);
+                }
+            });
+
+            let clicks_handler = clicks.clone();
+            let tree_handler = bind_event_handler(&app.test_tree.handle, &app.tree_tab.handle, move |evt, data, _handle| {
+                if evt == Event::OnTreeViewRightClick {
+                    clicks_handler.borrow_mut().push(data);
+                }
+            });
+
+            // The list view right clicks keep sending the index of the clicked item
+            let list = app.test_list_view.handle.hwnd().unwrap();
+            let mut notif: NMITEMACTIVATE = unsafe { mem::zeroed() };
+            notif.hdr.hwndFrom = list;
+            notif.hdr.code = NM_RCLICK as _;
+            notif.iItem = 1;
+            notif.iSubItem = 2;
+            unsafe { SendMessageW(app.list_view_tab.handle.hwnd().unwrap(), WM_NOTIFY, 0, &mut notif as *mut NMITEMACTIVATE as _); }
+            assert_eq!(clicks.borrow_mut().remove(0).on_list_view_item_index(), (1, 2));
+
+            // The tree view right clicks send the position of the message that raised the notification
+            let tree = app.test_tree.handle.hwnd().unwrap();
+            let mut notif: NMITEMACTIVATE = unsafe { mem::zeroed() };
+            notif.hdr.hwndFrom = tree;
+            notif.hdr.code = NM_RCLICK as _;
+            unsafe { SendMessageW(app.tree_tab.handle.hwnd().unwrap(), WM_NOTIFY, 0, &mut notif as *mut NMITEMACTIVATE as _); }
+
+            let pos = unsafe { GetMessagePos() };
+            let mut expected = POINT { x: (pos & 0xFFFF) as i16 as i32, y: ((pos >> 16) & 0xFFFF) as i16 as i32 };
+            unsafe { ScreenToClient(tree, &mut expected); }
+            assert_eq!(clicks.borrow_mut().remove(0).on_tree_view_right_click(), (expected.x, expected.y));
+
+            unbind_event_handler(&list_handler);
+            unbind_event_handler(&tree_handler);
+        }
+
+        app.test_list_box1.set_collection(vec!["Red", "White", "Green", "Yellow"]);
+        app.test_list_box1.set_selection(Some(0));
+        app.test_list_box1.sort_by(|a, b| b.len().cmp(&a.len()));
+        assert_eq!(&app.test_list_box1.collection() as &[&'static str], &["Yellow", "White", "Green", "Red"]);
+        assert_eq!(app.test_list_box1.selection(), Some(3));
+        assert_eq!(app.test_list_box1.selection_string(), Some("Red".to_string()));
+        app.test_list_box1.set_selection(Some(0));
+        assert_eq!(app.test_list_box1.selection_string(), Some("Yellow".to_string()));
+
+        app.test_list_box1.sort();
+        assert_eq!(&app.test_list_box1.collection() as &[&'static str], &["Green", "Red", "White", "Yellow"]);
+        assert_eq!(app.test_list_box1.selection(), Some(3));
+
+        assert!(app.test_list_box1.set_item_key(0, 1000));
+        assert!(app.test_list_box1.set_item_key(3, 4000));
+        assert!(!app.test_list_box1.set_item_key(10, 1));
+        app.test_list_box1.sort_by(|a, b| b.cmp(a));
+        assert_eq!(app.test_list_box1.index_of_key(1000), Some(3));
+        assert_eq!(app.test_list_box1.index_of_key(4000), Some(0));
+        assert_eq!(app.test_list_box1.item_key(1), Some(0));
+        assert_eq!(app.test_list_box1.item_key(10), None);
+        assert_eq!(app.test_list_box1.index_of_key(5), None);
+        app.test_list_box1.sort();
+
+        let mut virtual_list: ListBox<String> = ListBox::default();
+        ListBox::builder()
+            .flags(ListBoxFlags::VIRTUAL)
+            .item_count(200_000)
+            .parent(&app.window)
+            .build(&mut virtual_list)
+            .unwrap();
+
+        assert_eq!(virtual_list.is_virtual(), true);
+        assert_eq!(app.test_list_box1.is_virtual(), false);
+        assert_eq!(virtual_list.len(), 200_000);
+        assert_eq!(virtual_list.collection().len(), 0);
+        virtual_list.set_selection(Some(150_000));
+        assert_eq!(virtual_list.selection(), Some(150_000));
+        assert!(virtual_list.set_item_count(10));
+        assert_eq!(virtual_list.len(), 10);
+        assert_eq!(app.test_list_box1.set_item_count(10), false);
+
+        assert_eq!(app.test_list_box1.hwnd(), app.test_list_box1.handle.hwnd());
+        assert!(unsafe { ListBox::<String>::from_handle(app.window.hwnd().unwrap()) }.is_err());
+
+        {
+            use winapi::um::winuser::{CreateWindowExW, WS_CHILD, LBS_HASSTRINGS, LBS_NOTIFY};
+            use crate::win32::base_helper::to_utf16;
+
+            let class_name = to_utf16("LISTBOX");
+            let raw = unsafe {
+                CreateWindowExW(0, class_name.as_ptr(), ::std::ptr::null(), WS_CHILD | LBS_HASSTRINGS | LBS_NOTIFY,
+                    0, 0, 100, 100, app.window.hwnd().unwrap(), ::std::ptr::null_mut(), ::std::ptr::null_mut(), ::std::ptr::null_mut())
+            };
+
+            let adopted: ListBox<String> = unsafe { ListBox::from_handle(raw) }.unwrap();
+            adopted.push("Adopted".to_string());
+            assert_eq!(adopted.len(), 1);
+            assert_eq!(adopted.hwnd(), Some(raw));
+        }
+
         app.runs.borrow_mut().list = true;
     } else {
         app.test_list_box2.unselect_all();
@@ -1341,9 +2613,33 @@ fn run_menu_tests(app: &ControlsTest, _evt: Event) {
 
         app.pop_menu.set_enabled(false);
 
+        assert!(!app.window_menu_item1.has_bitmap());
+        assert!(app.window_menu_item1.set_bitmap(Some(&app.ball)));
+        assert!(app.window_menu_item1.has_bitmap());
+        assert!(app.pop_menu_item1.set_check_bitmaps(None, Some(&app.ball)));
+
+        {
+            use winapi::um::winuser::{SendMessageW, GetMenuItemCount, WM_INITMENUPOPUP};
+
+            let window = app.window.handle.hwnd().unwrap();
+            let (_, lazy_menu) = app.window_lazy_menu.handle.hmenu().unwrap();
+            unsafe {
+                assert_eq!(GetMenuItemCount(lazy_menu), 0);
+
+                // The items are rebuilt each time the menu opens
+                SendMessageW(window, WM_INITMENUPOPUP, lazy_menu as _, 1);
+                assert_eq!(GetMenuItemCount(lazy_menu), 4);
+                SendMessageW(window, WM_INITMENUPOPUP, lazy_menu as _, 1);
+                assert_eq!(GetMenuItemCount(lazy_menu), 4);
+            }
+        }
+
         app.runs.borrow_mut().menu = true;
     } else {
         app.pop_menu_item1.set_enabled(true);
+        app.pop_menu_item1.set_check_bitmaps(None, None);
+        app.window_menu_item1.set_bitmap(None);
+        assert!(!app.window_menu_item1.has_bitmap());
         app.window_submenu1.set_enabled(true);
         app.window_menu_item1.set_enabled(true);
         app.runs.borrow_mut().menu = false;
@@ -1361,6 +2657,45 @@ fn run_radio_tests(app: &ControlsTest, _evt: Event) {
         app.test_radio2.set_check_state(RadioButtonState::Unchecked);
         assert_eq!(app.test_radio2.check_state(), RadioButtonState::Unchecked);
 
+        {
+            use winapi::um::winuser::{IsDialogMessageW, GetFocus, WM_KEYDOWN, VK_DOWN, VK_UP, MSG};
+            use winapi::shared::windef::POINT;
+
+            let window = app.window.handle.hwnd().unwrap();
+            let arrow = |key: i32| unsafe {
+                let mut msg = MSG { hwnd: GetFocus(), message: WM_KEYDOWN, wParam: key as _, lParam: 1, time: 0, pt: POINT { x: 0, y: 0 } };
+                IsDialogMessageW(window, &mut msg);
+            };
+
+            assert!(app.test_radio1.group());
+            assert!(!app.test_radio2.group());
+
+            app.test_radio1.set_check_state(RadioButtonState::Checked);
+            app.test_radio1.set_focus();
+
+            arrow(VK_DOWN);
+            assert!(app.test_radio2.focus());
+            assert_eq!(app.test_radio2.check_state(), RadioButtonState::Checked);
+            assert_eq!(app.test_radio1.check_state(), RadioButtonState::Unchecked);
+
+            // The group ends before `test_radio3`, so the focus wraps around
+            arrow(VK_DOWN);
+            assert!(app.test_radio1.focus());
+            arrow(VK_UP);
+            assert!(app.test_radio2.focus());
+
+            app.test_radio3.set_group(false);
+            assert!(!app.test_radio3.group());
+            arrow(VK_DOWN);
+            assert!(app.test_radio3.focus());
+            assert_eq!(app.test_radio2.check_state(), RadioButtonState::Unchecked);
+
+            app.test_radio3.set_group(true);
+            app.test_radio3.set_check_state(RadioButtonState::Unchecked);
+            app.test_radio2.set_check_state(RadioButtonState::Unchecked);
+            app.window.set_focus();
+        }
+
         app.runs.borrow_mut().radio = true;
     } else {
         app.runs.borrow_mut().radio = false;
@@ -1371,14 +2706,171 @@ fn run_text_tests(app: &ControlsTest, _evt: Event) {
     if !app.runs.borrow().text {
         app.test_text_input.set_text("New Text");
         assert_eq!(&app.test_text_input.text(), "New Text");
+        assert_eq!(app.text_input.borrow_mut().take(), Some(true));
+
+        {
+            use winapi::um::winuser::{WM_CHAR, SendMessageW};
+            unsafe { SendMessageW(app.test_text_input.hwnd().unwrap(), WM_CHAR, 'a' as _, 0); }
+            assert_eq!(app.text_input.borrow_mut().take(), Some(false));
+            app.test_text_input.set_text("New Text");
+            app.text_input.borrow_mut().take();
+        }
+
+        {
+            use winapi::um::winuser::{PeekMessageW, DispatchMessageW, MSG, PM_REMOVE, WM_TIMER};
+            use std::{cell::Cell, rc::Rc, mem, ptr};
+            use std::time::{Duration, Instant};
+
+            // Runs the timers of the thread for `duration`
+            let wait = |duration: Duration| unsafe {
+                let end = Instant::now() + duration;
+                let mut msg: MSG = mem::zeroed();
+                while Instant::now() < end {
+                    while PeekMessageW(&mut msg, ptr::null_mut(), WM_TIMER, WM_TIMER, PM_REMOVE) != 0 {
+                        DispatchMessageW(&msg);
+                    }
+                    std::thread::sleep(Duration::from_millis(5));
+                }
+            };
+
+            let calls = Rc::new(Cell::new(0));
+            let last = Rc::new(Cell::new(None));
+            let counter = || {
+                let (calls, last) = (calls.clone(), last.clone());
+                move |_evt: Event, _evt_data: EventData, handle: ControlHandle| {
+                    calls.set(calls.get() + 1);
+                    last.set(Some(handle));
+                }
+            };
+
+            // Simulates fast typing: only the last event is handled, after the delay
+            let input = app.test_text_input.handle;
+            let search = debounce(Duration::from_millis(50), counter());
+            for _ in 0..5 {
+                search(Event::OnTextInput, EventData::NoData, app.window.handle);
+            }
+            search(Event::OnTextInput, EventData::NoData, input);
+            assert_eq!(calls.get(), 0);
+
+            wait(Duration::from_millis(150));
+            assert_eq!(calls.get(), 1);
+            assert_eq!(last.get(), Some(input));
+
+            // The first event is handled immediately and the last one at the end of the interval
+            calls.set(0);
+            let preview = throttle(Duration::from_millis(50), counter());
+            preview(Event::OnMouseMove, EventData::NoData, app.window.handle);
+            assert_eq!(calls.get(), 1);
+            for _ in 0..5 {
+                preview(Event::OnMouseMove, EventData::NoData, app.window.handle);
+            }
+            preview(Event::OnMouseMove, EventData::NoData, input);
+            assert_eq!(calls.get(), 1);
+
+            wait(Duration::from_millis(150));
+            assert_eq!(calls.get(), 2);
+            assert_eq!(last.get(), Some(input));
+
+            // A dropped adapter never calls its handler
+            search(Event::OnTextInput, EventData::NoData, input);
+            drop(search);
+            wait(Duration::from_millis(100));
+            assert_eq!(calls.get(), 2);
+        }
 
         app.test_text_input.set_limit(32);
         assert_eq!(app.test_text_input.limit(), 32);
 
+        {
+            use winapi::um::imm::{ImmGetContext, ImmReleaseContext};
+            let input = app.test_text_input.hwnd().unwrap();
+
+            assert!(app.test_text_input.ime_enabled());
+            let has_context = unsafe { ImmGetContext(input) };
+            unsafe { ImmReleaseContext(input, has_context); }
+
+            app.test_text_input.set_ime_enabled(false);
+            app.test_text_input.set_ime_enabled(false);
+            assert!(!app.test_text_input.ime_enabled());
+            assert!(unsafe { ImmGetContext(input) }.is_null());
+
+            app.test_text_input.set_ime_enabled(true);
+            assert!(app.test_text_input.ime_enabled());
+            let context = unsafe { ImmGetContext(input) };
+            assert_eq!(context, has_context);
+            unsafe { ImmReleaseContext(input, context); }
+        }
+
+        {
+            let text = app.test_text_input.text();
+            app.test_text_input.handle.set_validator(|text| match text.parse::<u32>() {
+                Ok(_) => Ok(()),
+                Err(_) => Err("Not a number".to_string())
+            });
+            app.test_text_input.handle.set_validation_display(ValidationDisplay::Silent);
+
+            app.test_text_input.set_text("abc");
+            assert!(!app.test_text_input.handle.validate());
+            assert_eq!(app.test_text_input.handle.validation_error(), Some("Not a number".to_string()));
+            assert!(!app.window.validate_all());
+            assert!(app.test_text_input.focus());
+
+            app.test_text_input.set_text("42");
+            assert!(app.window.validate_all());
+            assert_eq!(app.test_text_input.handle.validation_error(), None);
+
+            app.test_text_input.handle.remove_validator();
+            app.test_text_input.set_text("abc");
+            assert!(app.test_text_input.handle.validate());
+            assert!(app.window.validate_all());
+
+            app.test_text_input.set_text(&text);
+            app.text_input.borrow_mut().take();
+            app.window.set_focus();
+        }
+
+        {
+            // The default menu of the edit is suppressed, otherwise `SendMessageW` would not return before the menu is closed
+            use winapi::um::winuser::{WM_CONTEXTMENU, SendMessageW};
+            let input = app.test_text_input.hwnd().unwrap();
+
+            unsafe { SendMessageW(input, WM_CONTEXTMENU, input as _, 0xFFFF_FFFF); }
+            assert_eq!(app.context_menu.borrow_mut().take(), Some(None));
+
+            unsafe { SendMessageW(input, WM_CONTEXTMENU, input as _, (20 << 16) | 10); }
+            assert_eq!(app.context_menu.borrow_mut().take(), Some(Some((10, 20))));
+        }
+
+        {
+            use winapi::um::winuser::{EM_GETSEL, EM_SETREADONLY, SendMessageW};
+            let text_box = app.test_text_box.hwnd().unwrap();
+            let start = app.test_text_box.text().find("Line").unwrap() as u32;
+
+            unsafe { SendMessageW(text_box, EM_SETREADONLY as u32, 1, 0); }
+            app.test_text_box.highlight_range(start, start + 4);
+
+            let (mut sel_start, mut sel_end) = (0u32, 0u32);
+            unsafe { SendMessageW(text_box, EM_GETSEL as u32, &mut sel_start as *mut u32 as _, &mut sel_end as *mut u32 as _); }
+            assert_eq!((sel_start, sel_end), (start, start + 4));
+
+            app.test_text_box.clear_highlight();
+            let selection = app.test_text_box.selection();
+            assert_eq!(selection.start, selection.end);
+            unsafe { SendMessageW(text_box, EM_SETREADONLY as u32, 0, 0); }
+        }
+
         assert_eq!(app.test_text_input.password_char(), None);
         app.test_text_input.set_password_char(Some('X'));
         assert_eq!(app.test_text_input.password_char(), Some('X'));
 
+        assert_eq!(app.test_text_input.number(), false);
+        app.test_text_input.set_number(true);
+        assert_eq!(app.test_text_input.number(), true);
+
+        assert_eq!(app.test_text_input.case(), TextInputCase::Normal);
+        app.test_text_input.set_case(TextInputCase::Upper);
+        assert_eq!(app.test_text_input.case(), TextInputCase::Upper);
+
         app.test_text_input.set_modified(true);
         assert_eq!(app.test_text_input.modified(), true);
 
@@ -1413,6 +2905,8 @@ fn run_text_tests(app: &ControlsTest, _evt: Event) {
         app.test_text_input.set_enabled(true);
         app.test_text_input.set_readonly(false);
         app.test_text_input.set_password_char(None);
+        app.test_text_input.set_number(false);
+        app.test_text_input.set_case(TextInputCase::Normal);
         app.runs.borrow_mut().text = false;
     }
 }
@@ -1439,6 +2933,19 @@ fn run_progress_tests(app: &ControlsTest, _evt: Event) {
         app.test_progress1.advance_delta(50);
         assert!(app.test_progress1.pos() == 650);
 
+        app.test_progress1.set_pos_smooth(900);
+        assert_eq!(app.test_progress1.pos(), 900);
+        app.test_progress1.set_pos_smooth(200);
+        assert_eq!(app.test_progress1.pos(), 200);
+        assert!(app.test_progress1.handle.style() & ProgressBarFlags::SMOOTH_REVERSE.bits() != 0);
+        app.test_progress1.remove_flags(ProgressBarFlags::SMOOTH_REVERSE);
+
+        app.test_progress1.set_pos_immediate(1000);
+        assert_eq!(app.test_progress1.pos(), 1000);
+        assert_eq!(app.test_progress1.range(), 0..1000);
+        app.test_progress1.set_pos_immediate(650);
+        assert_eq!(app.test_progress1.pos(), 650);
+
         app.runs.borrow_mut().progress = true;
     } else {
         app.test_progress1.set_pos(0);
@@ -1464,6 +2971,13 @@ fn run_track_tests(app: &ControlsTest, _evt: Event) {
         app.test_track2.set_selection_range_pos(0..3);
         assert_eq!(app.test_track2.selection_range_pos(), 0..3);
 
+        let label_position = app.test_label.position();
+        app.test_track1.set_buddy(Some(&app.test_label.handle), None);
+        assert_eq!(app.test_track1.buddy(), (Some(app.test_label.handle), None));
+        app.test_track1.set_buddy(None, None);
+        assert_eq!(app.test_track1.buddy().0, Some(app.test_label.handle));
+        app.test_label.set_position(label_position.0, label_position.1);
+
         app.runs.borrow_mut().track = true;
     } else {
         app.runs.borrow_mut().track = false;
@@ -1478,6 +2992,11 @@ fn run_tooltip_tests(app: &ControlsTest, _evt: Event) {
         app.test_ttp1.set_delay_time(Some(100));
         assert_eq!(app.test_ttp1.delay_time(), 100);
 
+        app.test_ttp2.set_delay(0, 8000, 50);
+        assert_eq!(app.test_ttp2.delay(), (0, 8000, 50));
+        app.test_ttp2.reset_delay();
+        assert!(app.test_ttp2.delay() != (0, 8000, 50));
+
         app.test_ttp1.register(&app.test_checkbox1, "A simple checkbox");
         app.test_ttp1.register(&app.test_checkbox2, "A checkbox with 3 states!");
 
@@ -1488,10 +3007,21 @@ fn run_tooltip_tests(app: &ControlsTest, _evt: Event) {
 
         app.test_ttp1.unregister(&app.test_button);
 
+        let long_tip = "A tooltip set directly on the label, with a text that is longer than the 79 characters of a callback";
+        assert_eq!(app.test_label.tooltip(), None);
+        app.test_label.set_tooltip(Some(long_tip));
+        assert_eq!(app.test_label.tooltip().as_deref(), Some(long_tip));
+        app.test_radio1.set_tooltip(Some("Shares the tooltip of the label"));
+        app.test_label.set_tooltip(Some("Updated"));
+        assert_eq!(app.test_label.tooltip().as_deref(), Some("Updated"));
+
         app.runs.borrow_mut().tooltip = true;
     } else {
         app.test_ttp1.register(&app.test_button, "A button");
         app.test_ttp2.set_enabled(true);
+        app.test_label.set_tooltip(None);
+        app.test_radio1.set_tooltip(None);
+        assert_eq!(app.test_label.tooltip(), None);
         app.runs.borrow_mut().tooltip = false;
     }
 }
@@ -1528,6 +3058,23 @@ fn run_tray_tests(app: &ControlsTest) {
     app.tray_icon_2.show("OH NO!", Some("Just a title"), flags, icon);
     app.tray_icon_2.show("I'm spamming the system tray popup!", Some("Just a title"), flags, icon);
     app.tray_icon_2.show("You can't stop me!!!!!", Some("Just a title (really)"), flags, Some(&app.window_icon));
+
+    {
+        use winapi::um::winuser::{WM_SYSCOMMAND, SC_MINIMIZE, WM_LBUTTONDBLCLK, SendMessageW};
+        use crate::win32::window_helper::NWG_TRAY;
+
+        let window = app.window.handle.hwnd().unwrap();
+        let tray = app.tray_icon_2.handle.tray().unwrap();
+
+        app.window.set_minimize_to_tray(Some(&app.tray_icon_2));
+        unsafe { SendMessageW(window, WM_SYSCOMMAND, SC_MINIMIZE, 0); }
+        assert_eq!(app.window.visible(), false);
+
+        unsafe { SendMessageW(tray, NWG_TRAY, 0, WM_LBUTTONDBLCLK as _); }
+        assert_eq!(app.window.visible(), true);
+
+        app.window.set_minimize_to_tray(None);
+    }
 }
 
 fn set_tooltip_dynamic<'a>(app: &ControlsTest, handle: &ControlHandle, data: &ToolTipTextData) {
@@ -1543,6 +3090,9 @@ fn tree_tests(app: &ControlsTest, handle: &ControlHandle) {
     let remove = &app.test_tree_remove == handle;
 
     if add {
+        tree_expand_tests(app);
+        tree_item_style_tests(app);
+
         let text = app.test_tree_input.text();
         match app.test_tree.root() {
             Some(root) => match app.test_tree.selected_item() {
@@ -1563,6 +3113,73 @@ fn tree_tests(app: &ControlsTest, handle: &ControlHandle) {
     app.test_tree.set_focus();
 }
 
+fn tree_expand_tests(app: &ControlsTest) {
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use winapi::um::winuser::{WM_KEYDOWN, VK_ADD, VK_SUBTRACT, SendMessageW};
+
+    let tree = &app.test_tree;
+    let root = tree.root().unwrap();
+    tree.set_expand_state(&root, ExpandState::Collapse);
+    tree.select_item(&root);
+
+    let events: Rc<RefCell<Vec<(Event, bool)>>> = Default::default();
+    let veto = Rc::new(Cell::new(false));
+
+    let events_handler = events.clone();
+    let veto_handler = veto.clone();
+    let handler = bind_event_handler(&tree.handle, &app.tree_tab.handle, move |evt, data, _handle| {
+        match evt {
+            Event::OnTreeItemExpanding => {
+                let expanding = data.on_tree_item_expanding();
+                expanding.allow(!veto_handler.get());
+                events_handler.borrow_mut().push((evt, expanding.expanding()));
+            },
+            Event::OnTreeItemExpanded => {
+                events_handler.borrow_mut().push((evt, data.on_tree_item_expand().1));
+            },
+            _ => {}
+        }
+    });
+
+    // `set_expand_state` does not raise the expand events, only the user input does
+    let press = |key: i32| unsafe { SendMessageW(tree.handle.hwnd().unwrap(), WM_KEYDOWN, key as _, 0); };
+    let expanded = || tree.item_state(&root).unwrap().contains(TreeItemState::EXPANDED);
+
+    press(VK_ADD);
+    assert!(expanded());
+    assert_eq!(events.borrow_mut().drain(..).collect::<Vec<_>>(), vec![(Event::OnTreeItemExpanding, true), (Event::OnTreeItemExpanded, true)]);
+
+    press(VK_SUBTRACT);
+    assert!(!expanded());
+    assert_eq!(events.borrow_mut().drain(..).collect::<Vec<_>>(), vec![(Event::OnTreeItemExpanding, false), (Event::OnTreeItemExpanded, false)]);
+
+    veto.set(true);
+    press(VK_ADD);
+    assert!(!expanded());
+    assert_eq!(events.borrow_mut().drain(..).collect::<Vec<_>>(), vec![(Event::OnTreeItemExpanding, true)]);
+
+    unbind_event_handler(&handler);
+    tree.set_expand_state(&root, ExpandState::Expand);
+}
+
+fn tree_item_style_tests(app: &ControlsTest) {
+    let tree = &app.test_tree;
+    let root = tree.root().unwrap();
+
+    assert_eq!(tree.item_color(&root), None);
+    tree.set_item_color(&root, Some([200, 0, 0]));
+    assert_eq!(tree.item_color(&root), Some([200, 0, 0]));
+    tree.set_item_color(&root, None);
+    assert_eq!(tree.item_color(&root), None);
+
+    assert_eq!(tree.item_bold(&root), false);
+    tree.set_item_bold(&root, true);
+    assert_eq!(tree.item_bold(&root), true);
+    tree.set_item_bold(&root, false);
+    assert_eq!(tree.item_bold(&root), false);
+}
+
 #[cfg(feature = "file-dialog")]
 fn open_file(app: &ControlsTest, _evt: Event) {
     if app.open_file_dialog.run(Some(&app.window)) {
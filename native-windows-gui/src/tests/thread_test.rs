@@ -20,11 +20,13 @@ pub struct ThreadTest {
 
     counter: TextInput,
     timer_start_btn: Button,
+    high_resolution_timer_start_btn: Button,
     timer_stop_btn: Button,
     sleep_btn: Button,
     thread_sleep_btn: Button,
 
     timer: AnimationTimer,
+    high_resolution_timer: AnimationTimer,
     notice: Notice
 }
 
@@ -38,8 +40,21 @@ fn start_timer(app: &ThreadTest) {
     app.timer.start();
 }
 
+fn start_high_resolution_timer(app: &ThreadTest) {
+    assert_eq!(app.timer.high_resolution(), false);
+    assert_eq!(app.high_resolution_timer.high_resolution(), true);
+
+    app.high_resolution_timer.set_high_resolution(false);
+    assert_eq!(app.high_resolution_timer.high_resolution(), false);
+    app.high_resolution_timer.set_high_resolution(true);
+    assert_eq!(app.high_resolution_timer.high_resolution(), true);
+
+    app.high_resolution_timer.start();
+}
+
 fn stop_timer(app: &ThreadTest) {
     app.timer.stop();
+    app.high_resolution_timer.stop();
 }
 
 fn sleep() {
@@ -77,7 +92,7 @@ mod partial_canvas_test_ui {
             
             Window::builder()
                 .flags(WindowFlags::WINDOW)
-                .size((300, 300))
+                .size((300, 350))
                 .position((250, 100))
                 .title("Threads")
                 .build(&mut data.window)?;
@@ -92,6 +107,11 @@ mod partial_canvas_test_ui {
                 .parent(&data.window)
                 .build(&mut data.timer_start_btn)?;
 
+            Button::builder()
+                .text("Start timer (high resolution)")
+                .parent(&data.window)
+                .build(&mut data.high_resolution_timer_start_btn)?;
+
             Button::builder()
                 .text("Stop timer")
                 .parent(&data.window)
@@ -112,6 +132,12 @@ mod partial_canvas_test_ui {
                 .interval(Duration::from_millis(25))
                 .build(&mut data.timer)?;
 
+            AnimationTimer::builder()
+                .parent(&data.window)
+                .interval(Duration::from_millis(25))
+                .high_resolution(true)
+                .build(&mut data.high_resolution_timer)?;
+
             Notice::builder()
                 .parent(&data.window)
                 .build(&mut data.notice)?;
@@ -123,6 +149,7 @@ mod partial_canvas_test_ui {
                 .auto_spacing(Some(5))
                 .child(&data.counter)
                 .child(&data.timer_start_btn)
+                .child(&data.high_resolution_timer_start_btn)
                 .child(&data.timer_stop_btn)
                 .child(&data.sleep_btn)
                 .child(&data.thread_sleep_btn)
@@ -138,6 +165,8 @@ mod partial_canvas_test_ui {
                 E::OnButtonClick =>
                     if &handle == &self.timer_start_btn {
                         start_timer(self);
+                    } else if &handle == &self.high_resolution_timer_start_btn {
+                        start_high_resolution_timer(self);
                     } else if &handle == &self.timer_stop_btn {
                         stop_timer(self);
                     } else if &handle == &self.sleep_btn {
@@ -146,7 +175,7 @@ mod partial_canvas_test_ui {
                         thread_sleep(self);
                     },
                 E::OnTimerTick => 
-                    if &handle == &self.timer {
+                    if &handle == &self.timer || &handle == &self.high_resolution_timer {
                         timer_tick(self)
                     },
                 E::OnNotice => 
@@ -0,0 +1,169 @@
+/*!
+    Timer driven geometry animations. See `ControlHandle::animate_to`.
+
+    The state of the running animations is kept per thread, because the controls (and their timers) live on the UI thread.
+    A single raw event handler is bound on each animated control. It stays bound after the animation ends and is reused by the next one.
+    It is freed when the control is destroyed.
+*/
+use winapi::shared::windef::HWND;
+use winapi::shared::basetsd::UINT_PTR;
+use super::window_helper::{self as wh, ANIMATE_HANDLER_ID};
+use std::time::{Duration, Instant};
+use std::collections::HashMap;
+use std::cell::RefCell;
+
+const ANIMATE_INTERVAL: u32 = 15;
+
+
+/// The easing function applied to the progress of an animation. See `ControlHandle::animate_to`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Constant speed
+    #[default]
+    Linear,
+    /// Starts slowly and accelerates (cubic)
+    EaseIn,
+    /// Starts quickly and decelerates (cubic)
+    EaseOut,
+    /// Accelerates until the middle of the animation and then decelerates (cubic)
+    EaseInOut,
+}
+
+impl Easing {
+
+    /// Maps a linear progress between 0.0 and 1.0 to the eased progress
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => match t < 0.5 {
+                true => 4.0 * t * t * t,
+                false => 1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+            }
+        }
+    }
+
+}
+
+struct AnimationState {
+    start: Instant,
+    duration: Duration,
+    easing: Easing,
+    from_pos: (i32, i32),
+    from_size: (u32, u32),
+    to_pos: (i32, i32),
+    to_size: (u32, u32),
+    done: Option<Box<dyn FnOnce()>>,
+}
+
+thread_local! {
+    static ANIMATIONS: RefCell<HashMap<usize, AnimationState>> = RefCell::new(HashMap::new());
+}
+
+
+/**
+    Starts animating the geometry of `handle`. If the control is already animated, the old animation is replaced
+    and its completion callback is dropped without being called.
+*/
+pub unsafe fn animate_to(handle: HWND, pos: (i32, i32), size: (u32, u32), duration: Duration, easing: Easing, done: Box<dyn FnOnce()>) {
+    use crate::bind_raw_event_handler_until_destroyed;
+    use winapi::um::winuser::{SetTimer, WM_TIMER, WM_DESTROY};
+
+    let state = AnimationState {
+        start: Instant::now(),
+        duration,
+        easing,
+        from_pos: wh::get_window_position(handle),
+        from_size: wh::get_window_size(handle),
+        to_pos: pos,
+        to_size: size,
+        done: Some(done),
+    };
+
+    ANIMATIONS.with(|animations| {
+        animations.borrow_mut().insert(handle as usize, state);
+    });
+
+    bind_raw_event_handler_until_destroyed(handle, ANIMATE_HANDLER_ID, move |hwnd, msg, w, _l| {
+        match msg {
+            WM_TIMER if w == ANIMATE_HANDLER_ID => {
+                unsafe { animation_step(hwnd); }
+                Some(0)
+            },
+            WM_DESTROY => {
+                ANIMATIONS.with(|animations| animations.borrow_mut().remove(&(hwnd as usize)));
+                None
+            },
+            _ => None
+        }
+    });
+
+    SetTimer(handle, ANIMATE_HANDLER_ID as UINT_PTR, ANIMATE_INTERVAL, None);
+}
+
+/// Stops the animation of `handle` where it is. The completion callback is dropped without being called.
+pub unsafe fn stop_animation(handle: HWND) {
+    use winapi::um::winuser::KillTimer;
+
+    let state = ANIMATIONS.with(|animations| animations.borrow_mut().remove(&(handle as usize)));
+    if state.is_some() {
+        KillTimer(handle, ANIMATE_HANDLER_ID as UINT_PTR);
+    }
+}
+
+/// Returns `true` if `handle` is currently animated
+pub fn is_animating(handle: HWND) -> bool {
+    ANIMATIONS.with(|animations| animations.borrow().contains_key(&(handle as usize)))
+}
+
+unsafe fn animation_step(hwnd: HWND) {
+    use winapi::um::winuser::{KillTimer, WS_CHILD};
+
+    let (pos, size, finished) = match ANIMATIONS.with(|animations| animations.borrow().get(&(hwnd as usize)).map(step_geometry)) {
+        Some(step) => step,
+        None => {
+            KillTimer(hwnd, ANIMATE_HANDLER_ID as UINT_PTR);
+            return;
+        }
+    };
+
+    // Top level windows are sized by their client area, like `Window::set_size`
+    let fix_size = wh::get_style(hwnd) & WS_CHILD == 0;
+    wh::set_window_position(hwnd, pos.0, pos.1);
+    wh::set_window_size(hwnd, size.0, size.1, fix_size);
+
+    if finished {
+        KillTimer(hwnd, ANIMATE_HANDLER_ID as UINT_PTR);
+
+        let state = ANIMATIONS.with(|animations| animations.borrow_mut().remove(&(hwnd as usize)));
+
+        // Called last because the callback might start a new animation
+        if let Some(done) = state.and_then(|s| s.done) {
+            done();
+        }
+    }
+}
+
+fn step_geometry(state: &AnimationState) -> ((i32, i32), (u32, u32), bool) {
+    let progress = match state.duration.as_millis() {
+        0 => 1.0,
+        d => (state.start.elapsed().as_millis() as f64 / d as f64).min(1.0)
+    };
+
+    let t = state.easing.apply(progress);
+    let lerp = |from: f64, to: f64| (from + ((to - from) * t)).round();
+
+    let pos = (
+        lerp(state.from_pos.0 as f64, state.to_pos.0 as f64) as i32,
+        lerp(state.from_pos.1 as f64, state.to_pos.1 as f64) as i32,
+    );
+
+    let size = (
+        lerp(state.from_size.0 as f64, state.to_size.0 as f64) as u32,
+        lerp(state.from_size.1 as f64, state.to_size.1 as f64) as u32,
+    );
+
+    (pos, size, progress >= 1.0)
+}
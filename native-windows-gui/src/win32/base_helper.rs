@@ -45,7 +45,6 @@ pub fn from_utf16(s: &[u16]) -> String {
 /**
     Read a string from a wide char pointer. Undefined behaviour if [ptr] is not null terminated.
 */
-#[cfg(feature = "winnls")]
 pub unsafe fn from_wide_ptr(ptr: *mut u16, length: Option<usize>) -> String {
     use std::slice::from_raw_parts;
 
@@ -53,7 +52,7 @@ pub unsafe fn from_wide_ptr(ptr: *mut u16, length: Option<usize>) -> String {
         Some(v) => v,
         None => {
             let mut length: isize = 0;
-            while *&*ptr.offset(length) != 0 {
+            while *ptr.offset(length) != 0 {
                 length += 1;
             }
 
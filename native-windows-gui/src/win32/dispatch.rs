@@ -0,0 +1,136 @@
+/*!
+    Closures posted to the GUI thread. See `dispatch`.
+
+    A hidden message-only window is created on the GUI thread by `nwg::init`. `dispatch` posts a message to this window
+    with a pointer to the boxed closure and the window procedure runs the closure when the message is dispatched by the events loop.
+*/
+use winapi::shared::minwindef::{UINT, WPARAM, LPARAM, LRESULT};
+use winapi::shared::windef::HWND;
+use super::base_helper::to_utf16;
+use super::window::build_sysclass;
+use super::window_helper::NWG_DISPATCH;
+use crate::NwgError;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{mem, ptr};
+
+const DISPATCH_CLASS: &str = "NWG_DISPATCH";
+
+type DispatchFn = Box<dyn FnOnce() + Send + 'static>;
+
+/// The message-only window that runs the dispatched closures. 0 if `init_dispatch_window` was not called
+static DISPATCH_WINDOW: AtomicUsize = AtomicUsize::new(0);
+
+
+/**
+    Queues `f` to run on the GUI thread (the thread that called `nwg::init`) the next time its events loop dispatches messages.
+    This can be called from any thread and is the simplest way to update the controls from a worker thread or an async task.
+
+    Ordering:
+    - The closures run in the order they were dispatched, and in order with the other messages posted to the GUI thread
+      (ex: the `Notice` notifications). Messages sent with `SendMessage` and the input events can be processed before them.
+    - The closure never runs inside the `dispatch` call, even if `dispatch` is called from the GUI thread.
+
+    Memory:
+    - The closure is boxed and the pointer is carried by the posted message. The GUI thread takes back the box, calls the closure once and drops it.
+    - If the events loop exits before the closure runs, the closure is dropped without being called when the loop returns
+      (`dispatch_thread_events` and `dispatch_thread_events_with_callback`) or when the dispatch window is destroyed.
+
+    Returns `false` if the closure could not be queued (ex: `nwg::init` was not called or the GUI thread exited). The closure is dropped in that case.
+*/
+pub fn dispatch<F: FnOnce() + Send + 'static>(f: F) -> bool {
+    use winapi::um::winuser::PostMessageW;
+
+    let hwnd = DISPATCH_WINDOW.load(Ordering::SeqCst) as HWND;
+    if hwnd.is_null() {
+        return false;
+    }
+
+    let callback: DispatchFn = Box::new(f);
+    let callback_ptr = Box::into_raw(Box::new(callback));
+
+    unsafe {
+        if PostMessageW(hwnd, NWG_DISPATCH, 0, callback_ptr as LPARAM) == 0 {
+            drop(Box::from_raw(callback_ptr));
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Creates the dispatch window on the current thread. Does nothing if the window already exists.
+pub(crate) fn init_dispatch_window() -> Result<(), NwgError> {
+    use winapi::um::winuser::{CreateWindowExW, DestroyWindow, HWND_MESSAGE};
+    use winapi::um::libloaderapi::GetModuleHandleW;
+
+    if DISPATCH_WINDOW.load(Ordering::SeqCst) != 0 {
+        return Ok(());
+    }
+
+    unsafe {
+        let hmod = GetModuleHandleW(ptr::null_mut());
+        if hmod.is_null() { return Err(NwgError::initialization("GetModuleHandleW failed")); }
+
+        build_sysclass(hmod, DISPATCH_CLASS, Some(dispatch_window_proc), None, None)?;
+
+        let class_name = to_utf16(DISPATCH_CLASS);
+        let window_title = [0u16];
+        let handle = CreateWindowExW(
+            0,
+            class_name.as_ptr(),
+            window_title.as_ptr(),
+            0,
+            0, 0,
+            0, 0,
+            HWND_MESSAGE,
+            ptr::null_mut(),
+            hmod,
+            ptr::null_mut()
+        );
+
+        if handle.is_null() {
+            return Err(NwgError::initialization("Dispatch window creation failed"));
+        }
+
+        if DISPATCH_WINDOW.compare_exchange(0, handle as usize, Ordering::SeqCst, Ordering::SeqCst).is_err() {
+            DestroyWindow(handle);
+        }
+    }
+
+    Ok(())
+}
+
+/// Drops the closures that are still in the message queue of the current thread without calling them
+pub(crate) fn drop_pending_dispatch() {
+    let hwnd = DISPATCH_WINDOW.load(Ordering::SeqCst) as HWND;
+    if !hwnd.is_null() {
+        unsafe { drop_window_messages(hwnd); }
+    }
+}
+
+unsafe extern "system" fn dispatch_window_proc(hwnd: HWND, msg: UINT, w: WPARAM, l: LPARAM) -> LRESULT {
+    use winapi::um::winuser::{DefWindowProcW, WM_DESTROY};
+
+    match msg {
+        NWG_DISPATCH => {
+            let callback = Box::from_raw(l as *mut DispatchFn);
+            callback();
+            0
+        },
+        WM_DESTROY => {
+            let _ = DISPATCH_WINDOW.compare_exchange(hwnd as usize, 0, Ordering::SeqCst, Ordering::SeqCst);
+            drop_window_messages(hwnd);
+            0
+        },
+        _ => DefWindowProcW(hwnd, msg, w, l)
+    }
+}
+
+unsafe fn drop_window_messages(hwnd: HWND) {
+    use winapi::um::winuser::{PeekMessageW, MSG, PM_REMOVE};
+
+    let mut msg: MSG = mem::zeroed();
+    while PeekMessageW(&mut msg, hwnd, NWG_DISPATCH, NWG_DISPATCH, PM_REMOVE) != 0 {
+        drop(Box::from_raw(msg.lParam as *mut DispatchFn));
+    }
+}
@@ -0,0 +1,691 @@
+/*!
+    A minimal OLE drag source and drop target. Implements the `IDropSource` and `IDataObject` interfaces required by `DoDragDrop`
+    and the `IDropTarget` interface required by `RegisterDragDrop` for two formats: `CF_UNICODETEXT` and `CF_HDROP`.
+
+    The vtables are declared here instead of using the winapi interfaces so that the layout of the
+    objects is fully under our control. They only need to be ABI compatible with the COM interfaces.
+*/
+use winapi::shared::minwindef::{BOOL, DWORD, ULONG, UINT};
+use winapi::shared::guiddef::{GUID, REFIID, IsEqualGUID};
+use winapi::shared::ntdef::{HRESULT, LONG};
+use winapi::shared::windef::{HWND, POINT, POINTL};
+use winapi::shared::winerror::{S_OK, E_NOTIMPL, E_NOINTERFACE, E_OUTOFMEMORY, DV_E_FORMATETC, OLE_E_ADVISENOTSUPPORTED,
+  DRAGDROP_S_DROP, DRAGDROP_S_CANCEL, DRAGDROP_S_USEDEFAULTCURSORS};
+use winapi::um::winnt::HANDLE;
+use winapi::ctypes::c_void;
+use crate::win32::base_helper::to_utf16;
+use crate::win32::window_helper::{send_message, NWG_DROP_TARGET};
+use crate::{NwgError, DropTargetData};
+use std::cell::{Cell, RefCell};
+use std::{mem, ptr};
+
+const DVASPECT_CONTENT: DWORD = 1;
+const TYMED_HGLOBAL: DWORD = 1;
+const DATADIR_GET: DWORD = 1;
+
+const DROPEFFECT_NONE: DWORD = 0;
+const DROPEFFECT_COPY: DWORD = 1;
+const DROPEFFECT_MOVE: DWORD = 2;
+const DROPEFFECT_LINK: DWORD = 4;
+
+// The stage of a drop target operation, sent in the `WPARAM` of `NWG_DROP_TARGET`
+pub const DROP_TARGET_ENTER: usize = 0;
+pub const DROP_TARGET_OVER: usize = 1;
+pub const DROP_TARGET_LEAVE: usize = 2;
+pub const DROP_TARGET_DROP: usize = 3;
+
+// Not declared by winapi. Shell32 is already linked by the `shellapi` feature.
+extern "system" {
+    fn SHCreateStdEnumFmtEtc(cfmt: UINT, afmt: *const FORMATETC, ppenum: *mut *mut c_void) -> HRESULT;
+}
+
+// Not declared by winapi 0.3
+#[link(name = "ole32")]
+extern "system" {
+    fn DoDragDrop(data_object: *mut c_void, drop_source: *mut c_void, ok_effects: DWORD, effect: *mut DWORD) -> HRESULT;
+    fn ReleaseStgMedium(medium: *mut STGMEDIUM);
+}
+
+// The IID of `IDropSource`. The interface is not declared by winapi 0.3, its vtable is `DropSourceVtbl`.
+const IID_IDROP_SOURCE: GUID = GUID {
+    Data1: 0x00000121,
+    Data2: 0x0000,
+    Data3: 0x0000,
+    Data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+};
+
+
+/// The data carried by a drag and drop operation started with `ControlHandle::begin_drag_drop`
+/// or received by a control registered with `ControlHandle::set_drop_target`
+#[derive(Clone, Debug)]
+pub enum DragData {
+    /// Unicode text (`CF_UNICODETEXT`)
+    Text(String),
+
+    /// A list of absolute file paths (`CF_HDROP`), the same format the explorer uses
+    Files(Vec<String>),
+}
+
+/// The result of a drag and drop operation, or the effect chosen by a drop target
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DropEffect {
+    /// The drop was canceled or the target refused the data
+    None,
+    /// The target copied the data
+    Copy,
+    /// The target moved the data. The source should remove its own copy.
+    Move,
+    /// The target created a link to the data
+    Link,
+}
+
+impl DropEffect {
+    fn from_raw(effect: DWORD) -> DropEffect {
+        if effect & DROPEFFECT_MOVE == DROPEFFECT_MOVE {
+            DropEffect::Move
+        } else if effect & DROPEFFECT_COPY == DROPEFFECT_COPY {
+            DropEffect::Copy
+        } else if effect & DROPEFFECT_LINK == DROPEFFECT_LINK {
+            DropEffect::Link
+        } else {
+            DropEffect::None
+        }
+    }
+
+    pub(crate) fn to_raw(self) -> DWORD {
+        match self {
+            DropEffect::None => DROPEFFECT_NONE,
+            DropEffect::Copy => DROPEFFECT_COPY,
+            DropEffect::Move => DROPEFFECT_MOVE,
+            DropEffect::Link => DROPEFFECT_LINK,
+        }
+    }
+}
+
+
+#[repr(C)]
+#[allow(non_snake_case, clippy::upper_case_acronyms)]
+#[derive(Copy, Clone)]
+struct FORMATETC {
+    cfFormat: u16,
+    ptd: *mut c_void,
+    dwAspect: DWORD,
+    lindex: LONG,
+    tymed: DWORD,
+}
+
+#[repr(C)]
+#[allow(non_snake_case, clippy::upper_case_acronyms)]
+struct STGMEDIUM {
+    tymed: DWORD,
+    hGlobal: HANDLE,
+    pUnkForRelease: *mut c_void,
+}
+
+#[repr(C)]
+#[allow(non_snake_case, clippy::upper_case_acronyms)]
+struct DROPFILES {
+    pFiles: DWORD,
+    pt: POINT,
+    fNC: BOOL,
+    fWide: BOOL,
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct DropSourceVtbl {
+    QueryInterface: unsafe extern "system" fn(*mut DropSource, REFIID, *mut *mut c_void) -> HRESULT,
+    AddRef: unsafe extern "system" fn(*mut DropSource) -> ULONG,
+    Release: unsafe extern "system" fn(*mut DropSource) -> ULONG,
+    QueryContinueDrag: unsafe extern "system" fn(*mut DropSource, BOOL, DWORD) -> HRESULT,
+    GiveFeedback: unsafe extern "system" fn(*mut DropSource, DWORD) -> HRESULT,
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct DataObjectVtbl {
+    QueryInterface: unsafe extern "system" fn(*mut DataObject, REFIID, *mut *mut c_void) -> HRESULT,
+    AddRef: unsafe extern "system" fn(*mut DataObject) -> ULONG,
+    Release: unsafe extern "system" fn(*mut DataObject) -> ULONG,
+    GetData: unsafe extern "system" fn(*mut DataObject, *const FORMATETC, *mut STGMEDIUM) -> HRESULT,
+    GetDataHere: unsafe extern "system" fn(*mut DataObject, *const FORMATETC, *mut STGMEDIUM) -> HRESULT,
+    QueryGetData: unsafe extern "system" fn(*mut DataObject, *const FORMATETC) -> HRESULT,
+    GetCanonicalFormatEtc: unsafe extern "system" fn(*mut DataObject, *const FORMATETC, *mut FORMATETC) -> HRESULT,
+    SetData: unsafe extern "system" fn(*mut DataObject, *const FORMATETC, *const STGMEDIUM, BOOL) -> HRESULT,
+    EnumFormatEtc: unsafe extern "system" fn(*mut DataObject, DWORD, *mut *mut c_void) -> HRESULT,
+    DAdvise: unsafe extern "system" fn(*mut DataObject, *const FORMATETC, DWORD, *mut c_void, *mut DWORD) -> HRESULT,
+    DUnadvise: unsafe extern "system" fn(*mut DataObject, DWORD) -> HRESULT,
+    EnumDAdvise: unsafe extern "system" fn(*mut DataObject, *mut *mut c_void) -> HRESULT,
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct DropTargetVtbl {
+    QueryInterface: unsafe extern "system" fn(*mut DropTarget, REFIID, *mut *mut c_void) -> HRESULT,
+    AddRef: unsafe extern "system" fn(*mut DropTarget) -> ULONG,
+    Release: unsafe extern "system" fn(*mut DropTarget) -> ULONG,
+    DragEnter: unsafe extern "system" fn(*mut DropTarget, *mut DataObject, DWORD, POINTL, *mut DWORD) -> HRESULT,
+    DragOver: unsafe extern "system" fn(*mut DropTarget, DWORD, POINTL, *mut DWORD) -> HRESULT,
+    DragLeave: unsafe extern "system" fn(*mut DropTarget) -> HRESULT,
+    Drop: unsafe extern "system" fn(*mut DropTarget, *mut DataObject, DWORD, POINTL, *mut DWORD) -> HRESULT,
+}
+
+
+//
+// IDropSource
+//
+
+#[repr(C)]
+struct DropSource {
+    vtbl: *const DropSourceVtbl,
+    refs: Cell<ULONG>,
+}
+
+static DROP_SOURCE_VTBL: DropSourceVtbl = DropSourceVtbl {
+    QueryInterface: drop_source_query_interface,
+    AddRef: drop_source_add_ref,
+    Release: drop_source_release,
+    QueryContinueDrag: drop_source_query_continue_drag,
+    GiveFeedback: drop_source_give_feedback,
+};
+
+unsafe extern "system" fn drop_source_query_interface(this: *mut DropSource, riid: REFIID, out: *mut *mut c_void) -> HRESULT {
+    use winapi::um::unknwnbase::IUnknown;
+    use winapi::Interface;
+
+    if IsEqualGUID(&*riid, &IUnknown::uuidof()) || IsEqualGUID(&*riid, &IID_IDROP_SOURCE) {
+        drop_source_add_ref(this);
+        *out = this as *mut c_void;
+        S_OK
+    } else {
+        *out = ptr::null_mut();
+        E_NOINTERFACE
+    }
+}
+
+unsafe extern "system" fn drop_source_add_ref(this: *mut DropSource) -> ULONG {
+    let refs = &(&*this).refs;
+    refs.set(refs.get() + 1);
+    refs.get()
+}
+
+unsafe extern "system" fn drop_source_release(this: *mut DropSource) -> ULONG {
+    let refs = (&*this).refs.get() - 1;
+    (&*this).refs.set(refs);
+    if refs == 0 {
+        drop(Box::from_raw(this));
+    }
+
+    refs
+}
+
+unsafe extern "system" fn drop_source_query_continue_drag(_this: *mut DropSource, escape: BOOL, key_state: DWORD) -> HRESULT {
+    use winapi::um::winuser::{MK_LBUTTON, MK_RBUTTON};
+
+    if escape != 0 {
+        DRAGDROP_S_CANCEL
+    } else if key_state & (MK_LBUTTON | MK_RBUTTON) as DWORD == 0 {
+        DRAGDROP_S_DROP
+    } else {
+        S_OK
+    }
+}
+
+unsafe extern "system" fn drop_source_give_feedback(_this: *mut DropSource, _effect: DWORD) -> HRESULT {
+    DRAGDROP_S_USEDEFAULTCURSORS
+}
+
+
+//
+// IDataObject
+//
+
+#[repr(C)]
+struct DataObject {
+    vtbl: *const DataObjectVtbl,
+    refs: Cell<ULONG>,
+    format: FORMATETC,
+    bytes: Vec<u8>,
+}
+
+static DATA_OBJECT_VTBL: DataObjectVtbl = DataObjectVtbl {
+    QueryInterface: data_object_query_interface,
+    AddRef: data_object_add_ref,
+    Release: data_object_release,
+    GetData: data_object_get_data,
+    GetDataHere: data_object_get_data_here,
+    QueryGetData: data_object_query_get_data,
+    GetCanonicalFormatEtc: data_object_get_canonical_format_etc,
+    SetData: data_object_set_data,
+    EnumFormatEtc: data_object_enum_format_etc,
+    DAdvise: data_object_d_advise,
+    DUnadvise: data_object_d_unadvise,
+    EnumDAdvise: data_object_enum_d_advise,
+};
+
+impl DataObject {
+
+    fn new(data: &DragData) -> DataObject {
+        use winapi::um::winuser::{CF_UNICODETEXT, CF_HDROP};
+
+        let (format, bytes) = match data {
+            DragData::Text(text) => (CF_UNICODETEXT, wide_bytes(&to_utf16(text))),
+            DragData::Files(files) => {
+                let header = DROPFILES {
+                    pFiles: mem::size_of::<DROPFILES>() as DWORD,
+                    pt: POINT { x: 0, y: 0 },
+                    fNC: 0,
+                    fWide: 1,
+                };
+
+                let mut bytes: Vec<u8> = unsafe {
+                    let header_ptr = &header as *const DROPFILES as *const u8;
+                    ::std::slice::from_raw_parts(header_ptr, mem::size_of::<DROPFILES>()).to_vec()
+                };
+
+                // Every path is null terminated, and the list ends with an extra null character
+                for file in files.iter() {
+                    bytes.extend(wide_bytes(&to_utf16(file)));
+                }
+                bytes.extend(&[0, 0]);
+
+                (CF_HDROP, bytes)
+            }
+        };
+
+        DataObject {
+            vtbl: &DATA_OBJECT_VTBL,
+            refs: Cell::new(1),
+            format: FORMATETC {
+                cfFormat: format as u16,
+                ptd: ptr::null_mut(),
+                dwAspect: DVASPECT_CONTENT,
+                lindex: -1,
+                tymed: TYMED_HGLOBAL,
+            },
+            bytes,
+        }
+    }
+
+    fn accepts(&self, format: &FORMATETC) -> bool {
+        format.cfFormat == self.format.cfFormat && format.tymed & TYMED_HGLOBAL != 0 && format.dwAspect == DVASPECT_CONTENT
+    }
+
+}
+
+fn wide_bytes(text: &[u16]) -> Vec<u8> {
+    text.iter().flat_map(|c| c.to_le_bytes().to_vec()).collect()
+}
+
+unsafe extern "system" fn data_object_query_interface(this: *mut DataObject, riid: REFIID, out: *mut *mut c_void) -> HRESULT {
+    use winapi::um::unknwnbase::IUnknown;
+    use winapi::um::objidl::IDataObject;
+    use winapi::Interface;
+
+    if IsEqualGUID(&*riid, &IUnknown::uuidof()) || IsEqualGUID(&*riid, &IDataObject::uuidof()) {
+        data_object_add_ref(this);
+        *out = this as *mut c_void;
+        S_OK
+    } else {
+        *out = ptr::null_mut();
+        E_NOINTERFACE
+    }
+}
+
+unsafe extern "system" fn data_object_add_ref(this: *mut DataObject) -> ULONG {
+    let refs = &(&*this).refs;
+    refs.set(refs.get() + 1);
+    refs.get()
+}
+
+unsafe extern "system" fn data_object_release(this: *mut DataObject) -> ULONG {
+    let refs = (&*this).refs.get() - 1;
+    (&*this).refs.set(refs);
+    if refs == 0 {
+        drop(Box::from_raw(this));
+    }
+
+    refs
+}
+
+unsafe extern "system" fn data_object_get_data(this: *mut DataObject, format: *const FORMATETC, medium: *mut STGMEDIUM) -> HRESULT {
+    use winapi::um::winbase::{GlobalAlloc, GlobalLock, GlobalUnlock, GMEM_MOVEABLE};
+
+    let this = &*this;
+    if format.is_null() || !this.accepts(&*format) {
+        return DV_E_FORMATETC;
+    }
+
+    // The receiver owns the memory and frees it with `ReleaseStgMedium`
+    let global = GlobalAlloc(GMEM_MOVEABLE, this.bytes.len());
+    if global.is_null() {
+        return E_OUTOFMEMORY;
+    }
+
+    let dst = GlobalLock(global) as *mut u8;
+    ptr::copy_nonoverlapping(this.bytes.as_ptr(), dst, this.bytes.len());
+    GlobalUnlock(global);
+
+    *medium = STGMEDIUM {
+        tymed: TYMED_HGLOBAL,
+        hGlobal: global,
+        pUnkForRelease: ptr::null_mut(),
+    };
+
+    S_OK
+}
+
+unsafe extern "system" fn data_object_get_data_here(_this: *mut DataObject, _format: *const FORMATETC, _medium: *mut STGMEDIUM) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn data_object_query_get_data(this: *mut DataObject, format: *const FORMATETC) -> HRESULT {
+    match !format.is_null() && (&*this).accepts(&*format) {
+        true => S_OK,
+        false => DV_E_FORMATETC
+    }
+}
+
+unsafe extern "system" fn data_object_get_canonical_format_etc(_this: *mut DataObject, _in: *const FORMATETC, out: *mut FORMATETC) -> HRESULT {
+    if !out.is_null() {
+        (&mut *out).ptd = ptr::null_mut();
+    }
+
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn data_object_set_data(_this: *mut DataObject, _format: *const FORMATETC, _medium: *const STGMEDIUM, _release: BOOL) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn data_object_enum_format_etc(this: *mut DataObject, direction: DWORD, out: *mut *mut c_void) -> HRESULT {
+    if direction != DATADIR_GET {
+        *out = ptr::null_mut();
+        return E_NOTIMPL;
+    }
+
+    SHCreateStdEnumFmtEtc(1, &(&*this).format, out)
+}
+
+unsafe extern "system" fn data_object_d_advise(_this: *mut DataObject, _f: *const FORMATETC, _advf: DWORD, _sink: *mut c_void, _conn: *mut DWORD) -> HRESULT {
+    OLE_E_ADVISENOTSUPPORTED
+}
+
+unsafe extern "system" fn data_object_d_unadvise(_this: *mut DataObject, _conn: DWORD) -> HRESULT {
+    OLE_E_ADVISENOTSUPPORTED
+}
+
+unsafe extern "system" fn data_object_enum_d_advise(_this: *mut DataObject, _out: *mut *mut c_void) -> HRESULT {
+    OLE_E_ADVISENOTSUPPORTED
+}
+
+
+
+//
+// IDropTarget
+//
+
+#[repr(C)]
+struct DropTarget {
+    vtbl: *const DropTargetVtbl,
+    refs: Cell<ULONG>,
+    hwnd: HWND,
+    data: RefCell<Option<DragData>>,
+    effect: Cell<DropEffect>,
+}
+
+static DROP_TARGET_VTBL: DropTargetVtbl = DropTargetVtbl {
+    QueryInterface: drop_target_query_interface,
+    AddRef: drop_target_add_ref,
+    Release: drop_target_release,
+    DragEnter: drop_target_drag_enter,
+    DragOver: drop_target_drag_over,
+    DragLeave: drop_target_drag_leave,
+    Drop: drop_target_drop,
+};
+
+impl DropTarget {
+
+    /// Sends the `NWG_DROP_TARGET` message to the window so that the event handlers can inspect the data
+    /// and choose the effect. Writes the chosen effect, restricted to the effects allowed by the source, in `effect`.
+    unsafe fn raise(&self, stage: usize, key_state: DWORD, pt: POINTL, effect: *mut DWORD) {
+        let allowed = match effect.is_null() {
+            true => DROPEFFECT_NONE,
+            false => *effect
+        };
+
+        let data = self.data.borrow();
+        let mut chosen = self.effect.get();
+        let info = DropTargetData {
+            data: data.as_ref().map(|d| d as *const DragData).unwrap_or(ptr::null()),
+            position: (pt.x, pt.y),
+            key_state,
+            allowed,
+            effect: &mut chosen,
+        };
+
+        send_message(self.hwnd, NWG_DROP_TARGET, stage, &info as *const DropTargetData as _);
+
+        self.effect.set(chosen);
+        if !effect.is_null() {
+            *effect = chosen.to_raw() & allowed;
+        }
+    }
+
+}
+
+/// Reads the dragged data from a foreign data object. Only the formats supported by `DragData` are read.
+unsafe fn read_drag_data(object: *mut DataObject) -> Option<DragData> {
+    use winapi::um::winuser::{CF_UNICODETEXT, CF_HDROP};
+    use winapi::um::shellapi::DragQueryFileW;
+    use winapi::um::winbase::{GlobalLock, GlobalUnlock, GlobalSize};
+    use crate::win32::base_helper::from_utf16;
+
+    if object.is_null() {
+        return None;
+    }
+
+    // Only the vtable of the object is accessed, so any `IDataObject` implementation can be used here
+    let vtbl = &*(&*object).vtbl;
+
+    for &format in &[CF_HDROP, CF_UNICODETEXT] {
+        let format_etc = FORMATETC {
+            cfFormat: format as u16,
+            ptd: ptr::null_mut(),
+            dwAspect: DVASPECT_CONTENT,
+            lindex: -1,
+            tymed: TYMED_HGLOBAL,
+        };
+
+        let mut medium: STGMEDIUM = mem::zeroed();
+        if (vtbl.GetData)(object, &format_etc, &mut medium) != S_OK {
+            continue;
+        }
+
+        let data = match format {
+            CF_HDROP => {
+                let drop = medium.hGlobal as _;
+                let count = DragQueryFileW(drop, 0xFFFFFFFF, ptr::null_mut(), 0);
+                let mut files = Vec::with_capacity(count as usize);
+                for i in 0..count {
+                    let size = DragQueryFileW(drop, i, ptr::null_mut(), 0) + 1;
+                    let mut buffer: Vec<u16> = vec![0; size as usize];
+                    DragQueryFileW(drop, i, buffer.as_mut_ptr(), size);
+                    files.push(from_utf16(&buffer));
+                }
+
+                DragData::Files(files)
+            },
+            _ => {
+                let text_ptr = GlobalLock(medium.hGlobal) as *const u16;
+                let max_len = GlobalSize(medium.hGlobal) / 2;
+                let text = match text_ptr.is_null() {
+                    true => String::new(),
+                    false => {
+                        let text = ::std::slice::from_raw_parts(text_ptr, max_len);
+                        let len = text.iter().position(|&c| c == 0).unwrap_or(max_len);
+                        String::from_utf16_lossy(&text[..len])
+                    }
+                };
+
+                GlobalUnlock(medium.hGlobal);
+                DragData::Text(text)
+            }
+        };
+
+        ReleaseStgMedium(&mut medium);
+        return Some(data);
+    }
+
+    None
+}
+
+unsafe extern "system" fn drop_target_query_interface(this: *mut DropTarget, riid: REFIID, out: *mut *mut c_void) -> HRESULT {
+    use winapi::um::unknwnbase::IUnknown;
+    use winapi::um::oleidl::IDropTarget;
+    use winapi::Interface;
+
+    if IsEqualGUID(&*riid, &IUnknown::uuidof()) || IsEqualGUID(&*riid, &IDropTarget::uuidof()) {
+        drop_target_add_ref(this);
+        *out = this as *mut c_void;
+        S_OK
+    } else {
+        *out = ptr::null_mut();
+        E_NOINTERFACE
+    }
+}
+
+unsafe extern "system" fn drop_target_add_ref(this: *mut DropTarget) -> ULONG {
+    let refs = &(&*this).refs;
+    refs.set(refs.get() + 1);
+    refs.get()
+}
+
+unsafe extern "system" fn drop_target_release(this: *mut DropTarget) -> ULONG {
+    let refs = (&*this).refs.get() - 1;
+    (&*this).refs.set(refs);
+    if refs == 0 {
+        drop(Box::from_raw(this));
+    }
+
+    refs
+}
+
+unsafe extern "system" fn drop_target_drag_enter(this: *mut DropTarget, object: *mut DataObject, key_state: DWORD, pt: POINTL, effect: *mut DWORD) -> HRESULT {
+    let this = &*this;
+    let data = read_drag_data(object);
+
+    // Unless the handlers decide otherwise, known data is copied and unknown data is refused
+    let allowed = match effect.is_null() { true => DROPEFFECT_NONE, false => *effect };
+    let default_effect = match (data.is_some(), allowed & DROPEFFECT_COPY != 0) {
+        (false, _) => DropEffect::None,
+        (true, true) => DropEffect::Copy,
+        (true, false) => DropEffect::from_raw(allowed),
+    };
+
+    *this.data.borrow_mut() = data;
+    this.effect.set(default_effect);
+    this.raise(DROP_TARGET_ENTER, key_state, pt, effect);
+
+    S_OK
+}
+
+unsafe extern "system" fn drop_target_drag_over(this: *mut DropTarget, key_state: DWORD, pt: POINTL, effect: *mut DWORD) -> HRESULT {
+    (&*this).raise(DROP_TARGET_OVER, key_state, pt, effect);
+    S_OK
+}
+
+unsafe extern "system" fn drop_target_drag_leave(this: *mut DropTarget) -> HRESULT {
+    let this = &*this;
+    this.raise(DROP_TARGET_LEAVE, 0, POINTL { x: 0, y: 0 }, ptr::null_mut());
+    *this.data.borrow_mut() = None;
+    S_OK
+}
+
+unsafe extern "system" fn drop_target_drop(this: *mut DropTarget, _object: *mut DataObject, key_state: DWORD, pt: POINTL, effect: *mut DWORD) -> HRESULT {
+    let this = &*this;
+
+    // The data was already read in `DragEnter`
+    if this.effect.get() == DropEffect::None {
+        if !effect.is_null() { *effect = DROPEFFECT_NONE; }
+    } else {
+        this.raise(DROP_TARGET_DROP, key_state, pt, effect);
+    }
+
+    *this.data.borrow_mut() = None;
+    S_OK
+}
+
+
+/**
+    Registers a drop target for the window. The drop target sends `NWG_DROP_TARGET` to the window during a drag and drop operation.
+    Registering a window twice does nothing.
+*/
+pub unsafe fn register_drop_target(hwnd: HWND) -> Result<(), NwgError> {
+    use winapi::um::ole2::RegisterDragDrop;
+    use winapi::shared::winerror::DRAGDROP_E_ALREADYREGISTERED;
+
+    let target = Box::into_raw(Box::new(DropTarget {
+        vtbl: &DROP_TARGET_VTBL,
+        refs: Cell::new(1),
+        hwnd,
+        data: RefCell::new(None),
+        effect: Cell::new(DropEffect::None),
+    }));
+
+    let result = RegisterDragDrop(hwnd, target as *mut _);
+
+    // OLE keeps its own reference until `RevokeDragDrop` is called
+    drop_target_release(target);
+
+    match result {
+        S_OK | DRAGDROP_E_ALREADYREGISTERED => Ok(()),
+        _ => Err(NwgError::events_binding(format!("RegisterDragDrop failed with the error 0x{:X}", result)))
+    }
+}
+
+/// Revokes the drop target of a window registered with `register_drop_target`. Does nothing if the window is not registered.
+pub unsafe fn revoke_drop_target(hwnd: HWND) {
+    use winapi::um::ole2::RevokeDragDrop;
+    RevokeDragDrop(hwnd);
+}
+
+
+/**
+    Runs a modal OLE drag and drop operation with `data` and returns the effect chosen by the drop target.
+    The copy and the move effects are allowed.
+*/
+pub unsafe fn do_drag_drop(data: &DragData) -> Result<DropEffect, NwgError> {
+    let source = Box::into_raw(Box::new(DropSource { vtbl: &DROP_SOURCE_VTBL, refs: Cell::new(1) }));
+    let data_object = Box::into_raw(Box::new(DataObject::new(data)));
+
+    let mut effect: DWORD = DROPEFFECT_NONE;
+    let result = DoDragDrop(
+        data_object as *mut _,
+        source as *mut _,
+        DROPEFFECT_COPY | DROPEFFECT_MOVE,
+        &mut effect
+    );
+
+    // The system keeps its own references if it needs the objects after the drop
+    data_object_release(data_object);
+    drop_source_release(source);
+
+    match result {
+        DRAGDROP_S_DROP => Ok(DropEffect::from_raw(effect)),
+        DRAGDROP_S_CANCEL => Ok(DropEffect::None),
+        _ => Err(NwgError::drag_drop(format!("DoDragDrop failed with the error 0x{:X}", result)))
+    }
+}
+
+
+/// NWG initializes COM with `CoInitialize`, but drag and drop requires OLE. Called once per thread by `init_common_controls`.
+pub(crate) unsafe fn ole_initialize() -> Result<(), NwgError> {
+    use winapi::um::ole2::OleInitialize;
+    use winapi::shared::winerror::S_FALSE;
+
+    match OleInitialize(ptr::null_mut()) {
+        S_OK | S_FALSE => Ok(()),
+        _ => Err(NwgError::initialization("OleInitialize failed"))
+    }
+}
@@ -60,3 +60,41 @@ pub unsafe fn dpi() -> i32 {
     let dpi = GetDeviceCaps(screen, LOGPIXELSX);
     dpi
 }
+
+/**
+    Returns the DPI of the window `handle` with `GetDpiForWindow`. For a top level window that is the DPI of its monitor
+    if the process is per-monitor DPI aware, otherwise the system DPI.
+
+    `GetDpiForWindow` only exists on Windows 10 (1607) and later, so it is loaded at runtime. On older systems,
+    the horizontal DPI of the device context of the window (`GetDeviceCaps(LOGPIXELSX)`) is returned instead, which is the system DPI.
+*/
+pub unsafe fn window_dpi(handle: winapi::shared::windef::HWND) -> u32 {
+    use winapi::um::libloaderapi::{GetModuleHandleW, GetProcAddress};
+    use winapi::um::winuser::{GetDC, ReleaseDC, USER_DEFAULT_SCREEN_DPI};
+    use winapi::um::wingdi::{GetDeviceCaps, LOGPIXELSX};
+    use winapi::shared::windef::HWND;
+    use super::base_helper::to_utf16;
+
+    type GetDpiForWindowFn = unsafe extern "system" fn(HWND) -> u32;
+
+    let user32 = to_utf16("user32.dll");
+    let lib = GetModuleHandleW(user32.as_ptr());
+    let proc = GetProcAddress(lib, "GetDpiForWindow\0".as_ptr() as _);
+    if !proc.is_null() {
+        let get_dpi_for_window: GetDpiForWindowFn = std::mem::transmute(proc);
+        let dpi = get_dpi_for_window(handle);
+        if dpi != 0 {
+            return dpi;
+        }
+    }
+
+    let dc = GetDC(handle);
+    if dc.is_null() {
+        return USER_DEFAULT_SCREEN_DPI as u32;
+    }
+
+    let dpi = GetDeviceCaps(dc, LOGPIXELSX);
+    ReleaseDC(handle, dc);
+
+    dpi as u32
+}
@@ -0,0 +1,209 @@
+/*!
+    Transient colored frames drawn around a control. See `ControlHandle::highlight`.
+
+    The frame is a layered popup window owned by the top level window of the control. Because it is a separate window,
+    the highlighted control does not need to handle custom drawing. The popup is shaped with a window region so that
+    only the border is visible, and it is transparent to the mouse.
+*/
+use winapi::shared::minwindef::{UINT, WPARAM, LPARAM, LRESULT};
+use winapi::shared::windef::{HWND, HBRUSH, RECT};
+use winapi::shared::basetsd::LONG_PTR;
+use super::base_helper::to_utf16;
+use super::window::build_sysclass;
+use super::high_dpi::logical_to_physical;
+use crate::NwgError;
+use std::time::{Duration, Instant};
+use std::{mem, ptr};
+
+const HIGHLIGHT_CLASS: &str = "NWG_HIGHLIGHT";
+const HIGHLIGHT_PROP: &str = "NWG_HIGHLIGHT";
+const HIGHLIGHT_TIMER_ID: usize = 1;
+const HIGHLIGHT_INTERVAL: u32 = 50;
+const HIGHLIGHT_THICKNESS: i32 = 3;
+
+struct HighlightState {
+    target: HWND,
+    start: Instant,
+    duration: Duration,
+    brush: HBRUSH,
+    size: (i32, i32),
+}
+
+
+/**
+    Shows a colored frame around `target` for `duration`. If the control is already highlighted, the old frame is replaced.
+*/
+pub unsafe fn show_highlight(target: HWND, duration: Duration, color: [u8; 3]) -> Result<(), NwgError> {
+    use winapi::um::winuser::{CreateWindowExW, GetAncestor, SetPropW, SetTimer, SetLayeredWindowAttributes, SetWindowLongPtrW};
+    use winapi::um::winuser::{GA_ROOT, GWLP_USERDATA, LWA_ALPHA, WS_POPUP, WS_DISABLED, WS_EX_LAYERED, WS_EX_TRANSPARENT, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW};
+    use winapi::um::wingdi::{CreateSolidBrush, RGB};
+    use winapi::um::libloaderapi::GetModuleHandleW;
+
+    cancel_highlight(target);
+
+    let hmod = GetModuleHandleW(ptr::null_mut());
+    if hmod.is_null() { return Err(NwgError::initialization("GetModuleHandleW failed")); }
+
+    build_sysclass(hmod, HIGHLIGHT_CLASS, Some(highlight_window_proc), None, None)?;
+
+    let class_name = to_utf16(HIGHLIGHT_CLASS);
+    let window_title = [0u16];
+    let handle = CreateWindowExW(
+        WS_EX_LAYERED | WS_EX_TRANSPARENT | WS_EX_NOACTIVATE | WS_EX_TOOLWINDOW,
+        class_name.as_ptr(),
+        window_title.as_ptr(),
+        WS_POPUP | WS_DISABLED,
+        0, 0,
+        0, 0,
+        GetAncestor(target, GA_ROOT),
+        ptr::null_mut(),
+        hmod,
+        ptr::null_mut()
+    );
+
+    if handle.is_null() {
+        return Err(NwgError::control_create("Failed to create the highlight overlay"));
+    }
+
+    let state = Box::new(HighlightState {
+        target,
+        start: Instant::now(),
+        duration,
+        brush: CreateSolidBrush(RGB(color[0], color[1], color[2])),
+        size: (0, 0),
+    });
+
+    SetWindowLongPtrW(handle, GWLP_USERDATA, Box::into_raw(state) as LONG_PTR);
+    SetLayeredWindowAttributes(handle, 0, 255, LWA_ALPHA);
+
+    let prop_name = to_utf16(HIGHLIGHT_PROP);
+    SetPropW(target, prop_name.as_ptr(), handle as _);
+
+    follow_target(handle);
+    SetTimer(handle, HIGHLIGHT_TIMER_ID, HIGHLIGHT_INTERVAL, None);
+
+    Ok(())
+}
+
+/**
+    Removes the frame around `target`, if there is one.
+*/
+pub unsafe fn cancel_highlight(target: HWND) {
+    use winapi::um::winuser::{GetPropW, IsWindow, DestroyWindow};
+
+    let prop_name = to_utf16(HIGHLIGHT_PROP);
+    let overlay = GetPropW(target, prop_name.as_ptr()) as HWND;
+    if !overlay.is_null() && IsWindow(overlay) != 0 {
+        // The property is removed when the overlay is destroyed
+        DestroyWindow(overlay);
+    }
+}
+
+/**
+    Returns `true` if `target` currently shows a frame
+*/
+pub unsafe fn is_highlighted(target: HWND) -> bool {
+    use winapi::um::winuser::GetPropW;
+
+    let prop_name = to_utf16(HIGHLIGHT_PROP);
+    !GetPropW(target, prop_name.as_ptr()).is_null()
+}
+
+/// Moves the overlay over the target, with a margin for the frame. Hides it if the target is hidden.
+unsafe fn follow_target(overlay: HWND) {
+    use winapi::um::winuser::{GetWindowRect, IsWindowVisible, SetWindowPos, SetWindowRgn, ShowWindow, SW_HIDE};
+    use winapi::um::winuser::{SWP_NOZORDER, SWP_NOACTIVATE, SWP_SHOWWINDOW};
+    use winapi::um::wingdi::{CreateRectRgn, CombineRgn, DeleteObject, RGN_DIFF};
+
+    let state = match state_mut(overlay) {
+        Some(s) => s,
+        None => { return; }
+    };
+
+    if IsWindowVisible(state.target) == 0 {
+        ShowWindow(overlay, SW_HIDE);
+        return;
+    }
+
+    let (thickness, _) = logical_to_physical(HIGHLIGHT_THICKNESS, HIGHLIGHT_THICKNESS);
+
+    let mut r: RECT = mem::zeroed();
+    GetWindowRect(state.target, &mut r);
+
+    let (x, y) = (r.left - thickness, r.top - thickness);
+    let (w, h) = ((r.right - r.left) + thickness * 2, (r.bottom - r.top) + thickness * 2);
+
+    if state.size != (w, h) {
+        let outer = CreateRectRgn(0, 0, w, h);
+        let inner = CreateRectRgn(thickness, thickness, w - thickness, h - thickness);
+        CombineRgn(outer, outer, inner, RGN_DIFF);
+        DeleteObject(inner as _);
+
+        // The system owns the region after this call
+        SetWindowRgn(overlay, outer, 1);
+        state.size = (w, h);
+    }
+
+    SetWindowPos(overlay, ptr::null_mut(), x, y, w, h, SWP_NOZORDER | SWP_NOACTIVATE | SWP_SHOWWINDOW);
+}
+
+unsafe fn state_mut<'a>(overlay: HWND) -> Option<&'a mut HighlightState> {
+    use winapi::um::winuser::{GetWindowLongPtrW, GWLP_USERDATA};
+
+    let state = GetWindowLongPtrW(overlay, GWLP_USERDATA) as *mut HighlightState;
+    state.as_mut()
+}
+
+unsafe extern "system" fn highlight_window_proc(hwnd: HWND, msg: UINT, w: WPARAM, l: LPARAM) -> LRESULT {
+    use winapi::um::winuser::{WM_TIMER, WM_ERASEBKGND, WM_NCHITTEST, WM_DESTROY, WM_NCDESTROY, HTTRANSPARENT, GWLP_USERDATA};
+    use winapi::um::winuser::{DefWindowProcW, DestroyWindow, IsWindow, KillTimer, GetClientRect, FillRect, GetPropW, RemovePropW, SetWindowLongPtrW};
+    use winapi::um::wingdi::DeleteObject;
+
+    match msg {
+        WM_TIMER if w == HIGHLIGHT_TIMER_ID => {
+            let expired = match state_mut(hwnd) {
+                Some(state) => IsWindow(state.target) == 0 || state.start.elapsed() >= state.duration,
+                None => true
+            };
+
+            match expired {
+                true => { DestroyWindow(hwnd); },
+                false => follow_target(hwnd)
+            }
+
+            0
+        },
+        WM_ERASEBKGND => {
+            if let Some(state) = state_mut(hwnd) {
+                let mut r: RECT = mem::zeroed();
+                GetClientRect(hwnd, &mut r);
+                FillRect(w as _, &r, state.brush);
+            }
+            1
+        },
+        WM_NCHITTEST => HTTRANSPARENT as LRESULT,
+        WM_DESTROY => {
+            KillTimer(hwnd, HIGHLIGHT_TIMER_ID);
+
+            if let Some(state) = state_mut(hwnd) {
+                let prop_name = to_utf16(HIGHLIGHT_PROP);
+                if GetPropW(state.target, prop_name.as_ptr()) as HWND == hwnd {
+                    RemovePropW(state.target, prop_name.as_ptr());
+                }
+            }
+
+            0
+        },
+        WM_NCDESTROY => {
+            let state = state_mut(hwnd).map(|s| s as *mut HighlightState);
+            if let Some(state) = state {
+                SetWindowLongPtrW(hwnd, GWLP_USERDATA, 0);
+                let state = Box::from_raw(state);
+                DeleteObject(state.brush as _);
+            }
+
+            DefWindowProcW(hwnd, msg, w, l)
+        },
+        _ => DefWindowProcW(hwnd, msg, w, l)
+    }
+}
@@ -147,7 +147,8 @@ pub unsafe fn create_bitmap_from_wic(image: &ImageData) -> Result<Bitmap, NwgErr
     Ok(
         Bitmap {
             handle: bitmap as _,
-            owned: true
+            owned: true,
+            dc: ptr::null_mut()
         }
     )
 }
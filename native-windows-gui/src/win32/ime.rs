@@ -0,0 +1,141 @@
+/*!
+    Input method editor (IME) helpers for the controls that draw their own text. See `Event::OnImeComposition`.
+*/
+use winapi::shared::minwindef::{DWORD, LPVOID, BOOL};
+use winapi::shared::windef::{HWND, POINT, RECT};
+use winapi::shared::ntdef::LONG;
+use winapi::um::imm::{HIMC, COMPOSITIONFORM, CFS_POINT, ImmGetContext, ImmReleaseContext, ImmSetCompositionWindow};
+use super::base_helper::to_utf16;
+use super::window_helper::{IME_CONTEXT_PROP, IME_CONTEXT_HANDLER_ID};
+use crate::ImeCompositionData;
+use std::ptr;
+
+// Not declared by winapi
+const GCS_COMPSTR: DWORD = 0x0008;
+const GCS_CURSORPOS: DWORD = 0x0080;
+const GCS_RESULTSTR: DWORD = 0x0800;
+const IACE_DEFAULT: DWORD = 0x0010;
+
+#[link(name = "imm32")]
+extern "system" {
+    fn ImmGetCompositionStringW(himc: HIMC, index: DWORD, buf: LPVOID, buf_len: DWORD) -> LONG;
+    fn ImmAssociateContext(hwnd: HWND, himc: HIMC) -> HIMC;
+    fn ImmAssociateContextEx(hwnd: HWND, himc: HIMC, flags: DWORD) -> BOOL;
+}
+
+
+/// Reads the composition data of a `WM_IME_COMPOSITION` message. `flags` is the lParam of the message.
+pub unsafe fn composition_data(hwnd: HWND, flags: DWORD) -> ImeCompositionData {
+    let himc = ImmGetContext(hwnd);
+    if himc.is_null() {
+        return ImeCompositionData { composition: None, result: None, cursor: 0 };
+    }
+
+    let composition = match flags & GCS_COMPSTR != 0 {
+        true => composition_string(himc, GCS_COMPSTR),
+        false => None
+    };
+
+    let result = match flags & GCS_RESULTSTR != 0 {
+        true => composition_string(himc, GCS_RESULTSTR),
+        false => None
+    };
+
+    let cursor = match flags & GCS_CURSORPOS != 0 {
+        true => ImmGetCompositionStringW(himc, GCS_CURSORPOS, ptr::null_mut(), 0).max(0) as usize,
+        false => 0
+    };
+
+    ImmReleaseContext(hwnd, himc);
+
+    ImeCompositionData { composition, result, cursor }
+}
+
+/// Moves the composition window of `hwnd` to `(x, y)`, in physical pixels relative to the client area
+pub unsafe fn set_ime_position(hwnd: HWND, x: i32, y: i32) -> bool {
+    let himc = ImmGetContext(hwnd);
+    if himc.is_null() {
+        return false;
+    }
+
+    let mut form = COMPOSITIONFORM {
+        dwStyle: CFS_POINT,
+        ptCurrentPos: POINT { x, y },
+        rcArea: RECT { left: 0, top: 0, right: 0, bottom: 0 },
+    };
+
+    let ok = ImmSetCompositionWindow(himc, &mut form) != 0;
+    ImmReleaseContext(hwnd, himc);
+
+    ok
+}
+
+/**
+    Enables or disables the IME of `hwnd`. Disabling the IME removes the input context of the window (`ImmAssociateContext` with a null context)
+    and saves the old context in `IME_CONTEXT_PROP`. Enabling it associates the saved context again.
+*/
+pub unsafe fn set_ime_enabled(hwnd: HWND, enabled: bool) {
+    use winapi::um::winuser::{GetPropW, SetPropW, RemovePropW};
+
+    let prop_name = to_utf16(IME_CONTEXT_PROP);
+    let disabled = !GetPropW(hwnd, prop_name.as_ptr()).is_null();
+
+    match (enabled, disabled) {
+        (false, false) => {
+            let old = ImmAssociateContext(hwnd, ptr::null_mut());
+
+            // A null old context is saved as 1 so the property exists. It is restored with `IACE_DEFAULT`
+            let saved = match old.is_null() {
+                true => 1 as HIMC,
+                false => old
+            };
+
+            SetPropW(hwnd, prop_name.as_ptr(), saved as _);
+            bind_restore(hwnd);
+        },
+        (true, true) => {
+            let saved = RemovePropW(hwnd, prop_name.as_ptr()) as HIMC;
+            match saved as usize == 1 {
+                true => { ImmAssociateContextEx(hwnd, ptr::null_mut(), IACE_DEFAULT); },
+                false => { ImmAssociateContext(hwnd, saved); }
+            }
+        },
+        _ => {}
+    }
+}
+
+/// Returns `false` if the IME of `hwnd` was disabled with `set_ime_enabled`
+pub unsafe fn ime_enabled(hwnd: HWND) -> bool {
+    use winapi::um::winuser::GetPropW;
+
+    let prop_name = to_utf16(IME_CONTEXT_PROP);
+    GetPropW(hwnd, prop_name.as_ptr()).is_null()
+}
+
+/// Restores the input context when the window is destroyed. The handler itself is freed once the control receives `WM_NCDESTROY`.
+fn bind_restore(hwnd: HWND) {
+    use crate::bind_raw_event_handler_until_destroyed;
+    use winapi::um::winuser::WM_DESTROY;
+
+    let restore = move |hwnd, msg, _w, _l| {
+        if msg == WM_DESTROY {
+            unsafe { set_ime_enabled(hwnd, true); }
+        }
+        None
+    };
+
+    unsafe { bind_raw_event_handler_until_destroyed(hwnd, IME_CONTEXT_HANDLER_ID, restore); }
+}
+
+unsafe fn composition_string(himc: HIMC, index: DWORD) -> Option<String> {
+    // The length is in bytes
+    let len = ImmGetCompositionStringW(himc, index, ptr::null_mut(), 0);
+    if len < 0 {
+        return None;
+    }
+
+    let mut buffer: Vec<u16> = vec![0; (len as usize) / 2];
+    ImmGetCompositionStringW(himc, index, buffer.as_mut_ptr() as LPVOID, len as DWORD);
+
+    Some(String::from_utf16_lossy(&buffer))
+}
@@ -0,0 +1,14 @@
+/*!
+    Keyboard layout queries. See `OnInputLanguageChanged` for the event sent when the user switches the keyboard layout.
+*/
+
+
+/**
+    Returns the language identifier of the keyboard layout used by the current thread (ex: `0x0409` for English (United States)).
+    The primary language is in the low 10 bits and the sublanguage is in the high 6 bits.
+*/
+pub fn current_layout() -> u16 {
+    use winapi::um::winuser::GetKeyboardLayout;
+
+    unsafe { (GetKeyboardLayout(0) as usize & 0xFFFF) as u16 }
+}
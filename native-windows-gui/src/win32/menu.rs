@@ -1,7 +1,7 @@
 /*!
 Native Windows GUI menu base.
 */
-use winapi::shared::windef::{HMENU, HWND};
+use winapi::shared::windef::{HMENU, HWND, HBITMAP};
 use winapi::shared::minwindef::UINT;
 use super::base_helper::{CUSTOM_ID_BEGIN, to_utf16};
 use crate::controls::ControlHandle;
@@ -190,6 +190,46 @@ pub unsafe fn menu_item_checked(parent_menu: HMENU, id: u32) -> bool {
     GetMenuState(parent_menu, id, MF_BYCOMMAND) & MF_CHECKED == MF_CHECKED
 }
 
+pub unsafe fn set_menu_item_bitmap(parent_menu: HMENU, id: u32, bitmap: HBITMAP) -> bool {
+    use winapi::um::winuser::{SetMenuItemInfoW, MENUITEMINFOW, MIIM_BITMAP};
+
+    let mut info: MENUITEMINFOW = mem::zeroed();
+    info.cbSize = mem::size_of::<MENUITEMINFOW>() as UINT;
+    info.fMask = MIIM_BITMAP;
+    info.hbmpItem = bitmap;
+
+    SetMenuItemInfoW(parent_menu, id, 0, &info) != 0
+}
+
+pub unsafe fn menu_item_bitmap(parent_menu: HMENU, id: u32) -> HBITMAP {
+    use winapi::um::winuser::{GetMenuItemInfoW, MENUITEMINFOW, MIIM_BITMAP};
+
+    let mut info: MENUITEMINFOW = mem::zeroed();
+    info.cbSize = mem::size_of::<MENUITEMINFOW>() as UINT;
+    info.fMask = MIIM_BITMAP;
+
+    match GetMenuItemInfoW(parent_menu, id, 0, &mut info) {
+        0 => ptr::null_mut(),
+        _ => info.hbmpItem
+    }
+}
+
+pub unsafe fn set_menu_item_check_bitmaps(parent_menu: HMENU, id: u32, unchecked: HBITMAP, checked: HBITMAP) -> bool {
+    use winapi::um::winuser::{SetMenuItemBitmaps, MF_BYCOMMAND};
+    SetMenuItemBitmaps(parent_menu, id, MF_BYCOMMAND, unchecked, checked) != 0
+}
+
+
+/// Removes every item of a menu. `DeleteMenu` destroys the submenus.
+pub unsafe fn clear_menu(menu: HMENU) {
+    use winapi::um::winuser::{GetMenuItemCount, DeleteMenu, MF_BYPOSITION};
+
+    while GetMenuItemCount(menu) > 0 {
+        if DeleteMenu(menu, 0, MF_BYPOSITION) == 0 {
+            break;
+        }
+    }
+}
 
 unsafe fn build_hmenu_separator(menu: HMENU) -> ControlHandle {
     use winapi::um::winuser::{GetMenuItemCount, SetMenuItemInfoW, AppendMenuW};
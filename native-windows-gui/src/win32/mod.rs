@@ -3,12 +3,24 @@ pub(crate) mod window_helper;
 pub(crate) mod resources_helper;
 pub(crate) mod window;
 pub(crate) mod message_box;
+pub(crate) mod task_dialog;
 pub(crate) mod high_dpi;
 pub(crate) mod monitor;
+pub(crate) mod highlight;
+pub(crate) mod animation;
+pub(crate) mod validation;
+pub(crate) mod dispatch;
+pub(crate) mod rate_limit;
+pub mod keyboard;
+pub mod system;
+pub(crate) mod ime;
 
 #[cfg(feature = "menu")]
 pub(crate) mod menu;
 
+#[cfg(feature = "tooltip")]
+pub(crate) mod shared_tooltip;
+
 #[cfg(feature = "cursor")]
 pub(crate) mod cursor;
 
@@ -30,6 +42,12 @@ pub(crate) mod richedit;
 #[cfg(feature = "plotting")]
 pub(crate) mod plotters_d2d;
 
+#[cfg(feature = "drag-drop")]
+pub(crate) mod drag_drop;
+
+#[cfg(feature = "single-instance")]
+pub(crate) mod single_instance;
+
 use std::{fs, mem, ptr};
 use crate::errors::NwgError;
 
@@ -52,6 +70,8 @@ pub fn dispatch_thread_events() {
             }
         }
     }
+
+    dispatch::drop_pending_dispatch();
 }
 
 
@@ -79,6 +99,8 @@ pub fn dispatch_thread_events_with_callback<F>(mut cb: F)
             cb();
         }
     }
+
+    dispatch::drop_pending_dispatch();
 }
 
 /**
@@ -149,6 +171,35 @@ pub fn enable_visual_styles() {
     let _ = fs::remove_file(&manifest_path);
 }
 
+/**
+    Makes the mouse raise the pointer events (`OnPointerDown`, `OnPointerUp`, `OnPointerUpdate`) like touch and pen input,
+    so that an application can handle every kind of input in the pointer events.
+
+    This applies to the whole process and can only be changed once, before any window is created. 
+    `EnableMouseInPointer` only exists on Windows 8 and later. An error is returned on older systems.
+*/
+pub fn enable_mouse_in_pointer(enabled: bool) -> Result<(), NwgError> {
+    use winapi::um::libloaderapi::{GetModuleHandleW, GetProcAddress};
+    use winapi::shared::minwindef::BOOL;
+
+    type EnableMouseInPointerFn = unsafe extern "system" fn(BOOL) -> BOOL;
+
+    unsafe {
+        let user32 = base_helper::to_utf16("user32.dll");
+        let lib = GetModuleHandleW(user32.as_ptr());
+        let proc = GetProcAddress(lib, "EnableMouseInPointer\0".as_ptr() as _);
+        if proc.is_null() {
+            return Err(NwgError::initialization("EnableMouseInPointer requires Windows 8 or later"));
+        }
+
+        let enable_mouse_in_pointer: EnableMouseInPointerFn = mem::transmute(proc);
+        match enable_mouse_in_pointer(enabled as BOOL) {
+            0 => Err(NwgError::initialization("EnableMouseInPointer failed")),
+            _ => Ok(())
+        }
+    }
+}
+
 /**
     Ensure that the dll containing the winapi controls is loaded.
     Also register the custom classes used by NWG
@@ -201,13 +252,23 @@ pub fn init_common_controls() -> Result<(), NwgError> {
     tabs_init()?;
     extern_canvas_init()?;
     frame_init()?;
+    splitter_init()?;
+    dispatch::init_dispatch_window()?;
     
     match unsafe { CoInitialize(ptr::null_mut()) } {
-        S_OK | S_FALSE => Ok(()),
-        _ => Err(NwgError::initialization("CoInitialize failed"))
+        S_OK | S_FALSE => {},
+        _ => { return Err(NwgError::initialization("CoInitialize failed")); }
     }
+
+    drag_drop_init()
 }
 
+#[cfg(feature = "drag-drop")]
+fn drag_drop_init() -> Result<(), NwgError> { unsafe { drag_drop::ole_initialize() } }
+
+#[cfg(not(feature = "drag-drop"))]
+fn drag_drop_init() -> Result<(), NwgError> { Ok(()) }
+
 #[cfg(feature = "tabs")]
 fn tabs_init() -> Result<(), NwgError> { tabs::create_tab_classes() }
 
@@ -226,3 +287,8 @@ fn frame_init() -> Result<(), NwgError> { window::create_frame_classes() }
 #[cfg(not(feature = "frame"))]
 fn frame_init() -> Result<(), NwgError> { Ok(()) }
 
+#[cfg(feature = "splitter")]
+fn splitter_init() -> Result<(), NwgError> { window::create_splitter_classes() }
+
+#[cfg(not(feature = "splitter"))]
+fn splitter_init() -> Result<(), NwgError> { Ok(()) }
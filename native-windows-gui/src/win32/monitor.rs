@@ -82,6 +82,23 @@ impl Monitor {
         ]
     }
 
+    /// Returns a [left, top, right, bottom] rectangle that specifies the work area of the display monitor (the monitor
+    /// rectangle without the taskbar and the docked toolbars), expressed in virtual-screen coordinates. 
+    /// Note that if the monitor is not the primary display monitor, some of the rectangle's coordinates may be negative values.
+    /// Panics if `window` is not a window like control.
+    pub fn work_rect_from_window<H: Into<ControlHandle>>(window: H) -> [i32; 4] {
+        let handle = window.into().hwnd().expect("Window to be a window-like control");
+        let info = Self::monitor_info_from_window(handle);
+        let m = info.rcWork;
+
+        [
+            m.left,
+            m.top,
+            m.right,
+            m.bottom
+        ]
+    }
+
     /// Returns the primary monitor width in pixel
     /// Use `Monitor::virtual_width` to get the dimensions of the virtual screen
     pub fn width() -> i32 {
@@ -0,0 +1,221 @@
+/*!
+    Event handler adapters that limit the rate of the noisy events. See `debounce` and `throttle`.
+
+    The deferred calls use thread timers (`SetTimer` without a window). The timer procedure is called by the events loop
+    of the thread that created the timer, so the deferred calls run on the GUI thread like the other event handlers.
+*/
+use winapi::shared::minwindef::{UINT, DWORD};
+use winapi::shared::windef::HWND;
+use winapi::shared::basetsd::UINT_PTR;
+use crate::{Event, EventData, ControlHandle};
+use std::collections::HashMap;
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use std::time::Duration;
+use std::ptr;
+
+type Handler = Box<dyn Fn(Event, EventData, ControlHandle)>;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Debounce,
+    Throttle,
+}
+
+struct RateLimit {
+    mode: Mode,
+    delay: UINT,
+    handler: Handler,
+    pending: RefCell<Option<(Event, EventData, ControlHandle)>>,
+    timer: Cell<UINT_PTR>,
+}
+
+thread_local! {
+    /// The running timers, by timer id
+    static TIMERS: RefCell<HashMap<UINT_PTR, Weak<RateLimit>>> = RefCell::new(HashMap::new());
+}
+
+
+/**
+    Wraps `handler` so that it only runs once the events stop coming. Each call restarts the delay, and `handler` is called
+    `delay` after the last call, with the arguments of the last call. The calls before it are dropped.
+
+    This is the trailing edge only: nothing runs while the events keep coming faster than `delay`, and the first event is not
+    handled immediately. Use it for the work that only needs the final state (ex: a search as you type, or saving a setting
+    after a slider stops moving). See `throttle` to also handle the events while they come.
+
+    The adapter keeps one pending call, so wrap the handler of one event of one control, not a whole event handler:
+    the events of the other controls would replace the pending call. The deferred call runs on the GUI thread, from the events loop.
+    The data of the events that refers to the message being processed (ex: `OnPaint`, or the events that can be consumed) is not
+    valid anymore when the call runs. The noisy events usually have no data (`OnTextInput`, `OnMouseMove`, `OnResize`).
+
+    If the adapter is dropped before the delay (ex: the event handler is unbound), the pending call is dropped without being called.
+    The resolution of the timers is about 10 milliseconds.
+
+    ```rust
+    use native_windows_gui as nwg;
+    use std::{rc::Rc, time::Duration};
+
+    fn search_as_you_type(window: &nwg::Window, input: &Rc<nwg::TextInput>) -> nwg::EventHandler {
+        let search_input = Rc::downgrade(input);
+        let search = nwg::debounce(Duration::from_millis(300), move |_evt, _evt_data, _handle| {
+            if let Some(input) = search_input.upgrade() {
+                println!("Searching {:?}", input.text());
+            }
+        });
+
+        let input = input.handle;
+        nwg::full_bind_event_handler(&window.handle, move |evt, evt_data, handle| {
+            if evt == nwg::Event::OnTextInput && handle == input {
+                search(evt, evt_data, handle);
+            }
+        })
+    }
+    ```
+*/
+pub fn debounce<F>(delay: Duration, handler: F) -> impl Fn(Event, EventData, ControlHandle)
+    where F: Fn(Event, EventData, ControlHandle) + 'static
+{
+    let state = RateLimit::new(Mode::Debounce, delay, Box::new(handler));
+
+    move |evt, evt_data, handle| {
+        *state.pending.borrow_mut() = Some((evt, evt_data, handle));
+        state.start_timer();
+    }
+}
+
+/**
+    Wraps `handler` so that it runs at most once every `interval` while the events keep coming.
+
+    This is the leading and the trailing edge: the first event is handled immediately and starts the interval. The events that come
+    during the interval are not handled, except the last one, which is handled at the end of the interval and starts another one.
+    So the last event is never lost, and `handler` is called at most once per `interval`. When an interval ends without an event,
+    the next event is handled immediately again. Use it for the work that must follow the input while it changes
+    (ex: a live preview while a window is resized). See `debounce` to only handle the final state.
+
+    The same restrictions as `debounce` apply: wrap the handler of one event of one control, the deferred call runs on the GUI thread
+    from the events loop, and the data of the deferred event must not refer to the message that raised it.
+
+    ```rust
+    use native_windows_gui as nwg;
+    use std::time::Duration;
+
+    fn live_preview(window: &nwg::Window) -> nwg::EventHandler {
+        let preview = nwg::throttle(Duration::from_millis(100), |_evt, _evt_data, handle| {
+            println!("Rendering the preview for {:?}", handle);
+        });
+
+        let window = window.handle;
+        nwg::full_bind_event_handler(&window, move |evt, evt_data, handle| {
+            if evt == nwg::Event::OnResize && handle == window {
+                preview(evt, evt_data, handle);
+            }
+        })
+    }
+    ```
+*/
+pub fn throttle<F>(interval: Duration, handler: F) -> impl Fn(Event, EventData, ControlHandle)
+    where F: Fn(Event, EventData, ControlHandle) + 'static
+{
+    let state = RateLimit::new(Mode::Throttle, interval, Box::new(handler));
+
+    move |evt, evt_data, handle| {
+        if state.timer.get() == 0 {
+            // The interval starts before the call, so that the events raised by `handler` are throttled too
+            state.start_timer();
+            (state.handler)(evt, evt_data, handle);
+        } else {
+            *state.pending.borrow_mut() = Some((evt, evt_data, handle));
+        }
+    }
+}
+
+impl RateLimit {
+
+    fn new(mode: Mode, delay: Duration, handler: Handler) -> Rc<RateLimit> {
+        let delay = delay.as_millis().min(UINT::MAX as u128) as UINT;
+
+        Rc::new(RateLimit {
+            mode,
+            delay,
+            handler,
+            pending: RefCell::new(None),
+            timer: Cell::new(0),
+        })
+    }
+
+    /// Starts the timer, or restarts it if it is already running
+    fn start_timer(self: &Rc<Self>) {
+        use winapi::um::winuser::SetTimer;
+
+        self.stop_timer();
+
+        let id = unsafe { SetTimer(ptr::null_mut(), 0, self.delay, Some(timer_proc)) };
+        if id == 0 {
+            // Without a timer, the pending call would never run
+            self.run_pending();
+            return;
+        }
+
+        self.timer.set(id);
+        TIMERS.with(|timers| timers.borrow_mut().insert(id, Rc::downgrade(self)));
+    }
+
+    fn stop_timer(&self) {
+        use winapi::um::winuser::KillTimer;
+
+        let id = self.timer.replace(0);
+        if id != 0 {
+            unsafe { KillTimer(ptr::null_mut(), id); }
+
+            // The map may already be destroyed if the adapter is dropped when the thread exits
+            let _ = TIMERS.try_with(|timers| timers.borrow_mut().remove(&id));
+        }
+    }
+
+    /// Calls the handler with the pending call. Returns `false` if there was no pending call
+    fn run_pending(&self) -> bool {
+        // The handler is called without borrowing the pending call, so it can raise the event again
+        let call = self.pending.borrow_mut().take();
+        match call {
+            Some((evt, evt_data, handle)) => {
+                (self.handler)(evt, evt_data, handle);
+                true
+            },
+            None => false
+        }
+    }
+
+}
+
+impl Drop for RateLimit {
+    fn drop(&mut self) {
+        self.stop_timer();
+    }
+}
+
+unsafe extern "system" fn timer_proc(_hwnd: HWND, _msg: UINT, id: UINT_PTR, _time: DWORD) {
+    use winapi::um::winuser::KillTimer;
+
+    let state = TIMERS.with(|timers| timers.borrow().get(&id).and_then(|state| state.upgrade()));
+    let state = match state {
+        Some(state) => state,
+        None => {
+            KillTimer(ptr::null_mut(), id);
+            return;
+        }
+    };
+
+    match state.mode {
+        Mode::Debounce => {
+            state.stop_timer();
+            state.run_pending();
+        },
+        Mode::Throttle => {
+            // The timer keeps running while there are calls to run, so the next interval starts now
+            if !state.run_pending() && state.timer.get() == id {
+                state.stop_timer();
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+/*!
+    The tooltip shared by the controls of a top level window. See `ControlHandle::set_tooltip`.
+
+    The tooltip window is created the first time a control of a top level window gets a tooltip. It is owned by the top level window,
+    so the system destroys it with the window, and its handle is saved in the `SHARED_TOOLTIP_PROP` property of the window.
+
+    The text of each tool is kept in a buffer per control. A raw event handler bound on the control removes the tool and frees the buffer
+    when the control is destroyed. The handler itself is freed once the control receives `WM_NCDESTROY`.
+*/
+use winapi::shared::windef::{HWND, RECT};
+use winapi::shared::minwindef::{UINT, LPARAM};
+use winapi::shared::basetsd::UINT_PTR;
+use winapi::um::commctrl::TTTOOLINFOW;
+use super::base_helper::to_utf16;
+use super::window_helper::{self as wh, SHARED_TOOLTIP_PROP, SHARED_TOOLTIP_HANDLER_ID};
+use std::collections::HashMap;
+use std::cell::RefCell;
+use std::{mem, ptr};
+
+/// Maximum width of the tooltip in logical pixels. Longer texts are wrapped on multiple lines
+const MAX_TIP_WIDTH: i32 = 400;
+
+thread_local! {
+    static TOOLTIP_TEXTS: RefCell<HashMap<usize, Vec<u16>>> = RefCell::new(HashMap::new());
+}
+
+
+/**
+    Sets the text of the tooltip of `handle`, or removes the tooltip if `text` is `None`.
+    Returns `false` if the shared tooltip of the top level window could not be created.
+*/
+pub unsafe fn set_tooltip(handle: HWND, text: Option<&str>) -> bool {
+    use crate::bind_raw_event_handler_until_destroyed;
+    use winapi::um::commctrl::{TTM_ADDTOOLW, TTM_UPDATETIPTEXTW, TTM_DELTOOLW};
+    use winapi::um::winuser::{GetAncestor, GA_ROOT, WM_DESTROY};
+
+    let root = GetAncestor(handle, GA_ROOT);
+    let root = match root.is_null() {
+        true => handle,
+        false => root
+    };
+
+    let text = match text {
+        Some(text) => text,
+        None => {
+            let removed = TOOLTIP_TEXTS.with(|texts| texts.borrow_mut().remove(&(handle as usize)));
+            if removed.is_some() {
+                if let Some(tooltip) = find_tooltip(root) {
+                    let mut tool = tool_info(handle, ptr::null_mut());
+                    wh::send_message(tooltip, TTM_DELTOOLW, 0, &mut tool as *mut TTTOOLINFOW as LPARAM);
+                }
+            }
+
+            return true;
+        }
+    };
+
+    let tooltip = match find_tooltip(root) {
+        Some(tooltip) => tooltip,
+        None => match create_tooltip(root) {
+            Some(tooltip) => tooltip,
+            None => { return false; }
+        }
+    };
+
+    // The tooltip is recreated if the previous one was destroyed, so the tools must be registered again
+    let registered = TOOLTIP_TEXTS.with(|texts| texts.borrow().contains_key(&(handle as usize)));
+    let message = match registered && tool_exists(tooltip, handle) {
+        true => TTM_UPDATETIPTEXTW,
+        false => TTM_ADDTOOLW
+    };
+
+    let mut buffer = to_utf16(text);
+    let mut tool = tool_info(handle, buffer.as_mut_ptr());
+    wh::send_message(tooltip, message, 0, &mut tool as *mut TTTOOLINFOW as LPARAM);
+
+    TOOLTIP_TEXTS.with(|texts| texts.borrow_mut().insert(handle as usize, buffer));
+
+    bind_raw_event_handler_until_destroyed(handle, SHARED_TOOLTIP_HANDLER_ID, |hwnd, msg, _w, _l| {
+        if msg == WM_DESTROY {
+            unsafe { set_tooltip(hwnd, None); }
+        }
+        None
+    });
+
+    true
+}
+
+/// Returns the text of the tooltip set with `set_tooltip` on `handle`
+pub fn tooltip(handle: HWND) -> Option<String> {
+    use super::base_helper::from_utf16;
+
+    TOOLTIP_TEXTS.with(|texts| {
+        texts.borrow().get(&(handle as usize)).map(|buffer| from_utf16(buffer))
+    })
+}
+
+unsafe fn find_tooltip(root: HWND) -> Option<HWND> {
+    use winapi::um::winuser::{GetPropW, IsWindow};
+
+    let prop_name = to_utf16(SHARED_TOOLTIP_PROP);
+    let tooltip = GetPropW(root, prop_name.as_ptr()) as HWND;
+    match tooltip.is_null() || IsWindow(tooltip) == 0 {
+        true => None,
+        false => Some(tooltip)
+    }
+}
+
+unsafe fn create_tooltip(root: HWND) -> Option<HWND> {
+    use winapi::um::winuser::{CreateWindowExW, SetPropW, WS_POPUP, WS_EX_TOPMOST, CW_USEDEFAULT};
+    use winapi::um::commctrl::{TOOLTIPS_CLASS, TTS_ALWAYSTIP, TTS_NOPREFIX, TTM_SETMAXTIPWIDTH};
+    use winapi::um::libloaderapi::GetModuleHandleW;
+    use super::high_dpi::logical_to_physical;
+
+    let class_name = to_utf16(TOOLTIPS_CLASS);
+    let tooltip = CreateWindowExW(
+        WS_EX_TOPMOST,
+        class_name.as_ptr(),
+        ptr::null(),
+        WS_POPUP | TTS_ALWAYSTIP | TTS_NOPREFIX,
+        CW_USEDEFAULT, CW_USEDEFAULT,
+        CW_USEDEFAULT, CW_USEDEFAULT,
+        root,
+        ptr::null_mut(),
+        GetModuleHandleW(ptr::null_mut()),
+        ptr::null_mut()
+    );
+
+    if tooltip.is_null() {
+        return None;
+    }
+
+    // Also enables the line breaks in the text
+    let (max_width, _) = logical_to_physical(MAX_TIP_WIDTH, 0);
+    wh::send_message(tooltip, TTM_SETMAXTIPWIDTH, 0, max_width as LPARAM);
+
+    let prop_name = to_utf16(SHARED_TOOLTIP_PROP);
+    SetPropW(root, prop_name.as_ptr(), tooltip as _);
+
+    Some(tooltip)
+}
+
+unsafe fn tool_exists(tooltip: HWND, handle: HWND) -> bool {
+    use winapi::um::commctrl::TTM_GETTOOLINFOW;
+
+    let mut tool = tool_info(handle, ptr::null_mut());
+    wh::send_message(tooltip, TTM_GETTOOLINFOW, 0, &mut tool as *mut TTTOOLINFOW as LPARAM) != 0
+}
+
+fn tool_info(handle: HWND, text: *mut u16) -> TTTOOLINFOW {
+    use winapi::um::commctrl::{TTF_IDISHWND, TTF_SUBCLASS};
+
+    TTTOOLINFOW {
+        cbSize: mem::size_of::<TTTOOLINFOW>() as UINT,
+        uFlags: TTF_IDISHWND | TTF_SUBCLASS,
+        hwnd: handle,
+        uId: handle as UINT_PTR,
+        rect: RECT { left: 0, top: 0, right: 0, bottom: 0 },
+        hinst: ptr::null_mut(),
+        lpszText: text,
+        lParam: 0,
+        lpReserved: ptr::null_mut()
+    }
+}
@@ -0,0 +1,248 @@
+/*!
+    Single instance enforcement with a named mutex.
+
+    The first instance publishes the window that receives `OnSecondInstance` in a small named file mapping.
+    The next instances read the window handle from the mapping and forward their command line with `WM_COPYDATA`.
+    `WM_COPYDATA` is used because it is the only standard message that carries data across processes without
+    any setup on the receiving side (the system copies the data in the address space of the receiver).
+*/
+use winapi::shared::minwindef::DWORD;
+use winapi::shared::windef::HWND;
+use winapi::shared::basetsd::ULONG_PTR;
+use winapi::um::winnt::HANDLE;
+use winapi::um::winuser::COPYDATASTRUCT;
+use super::base_helper::to_utf16;
+use crate::{ControlHandle, NwgError};
+use std::{mem, ptr};
+
+/// Identifies the `WM_COPYDATA` messages sent by `single_instance` ("NWGI")
+pub const SECOND_INSTANCE_COPYDATA: ULONG_PTR = 0x4E574749;
+
+/// How long a second instance waits for the first instance to listen and to accept its command line
+const FORWARD_TIMEOUT_MS: u32 = 5000;
+
+/// How often a second instance checks if the first instance is listening
+const LISTEN_POLL_MS: u32 = 50;
+
+
+/**
+    Keeps the application as the only instance holding the name passed to `single_instance`.
+    The name is released when the guard is dropped, so the guard must be kept alive for the whole lifetime of the application.
+*/
+pub struct SingleInstanceGuard {
+    mutex: HANDLE,
+    mapping: HANDLE,
+}
+
+impl SingleInstanceGuard {
+
+    /**
+        Sets the window that receives the `OnSecondInstance` event when another instance of the application starts.
+        The window is restored and brought to the foreground before the event is raised.
+
+        An instance started before `listen` is called waits up to 5 seconds for the window, so `listen` should be called
+        as soon as the window is created. If no window is published in time, the arguments of that instance are dropped.
+
+        The handle is shared with other processes, so the window should live as long as the guard.
+        Calling this method again replaces the window.
+
+        Panics if the control is not a window-like control.
+    */
+    pub fn listen(&self, window: &ControlHandle) {
+        use winapi::um::memoryapi::{MapViewOfFile, UnmapViewOfFile, FILE_MAP_WRITE};
+
+        let hwnd = window.hwnd().expect("Cannot listen for second instances with a control that is not window-like");
+        if self.mapping.is_null() {
+            return;
+        }
+
+        unsafe {
+            let view = MapViewOfFile(self.mapping, FILE_MAP_WRITE, 0, 0, mem::size_of::<u64>());
+            if !view.is_null() {
+                *(view as *mut u64) = hwnd as usize as u64;
+                UnmapViewOfFile(view);
+            }
+        }
+    }
+
+}
+
+impl Drop for SingleInstanceGuard {
+    fn drop(&mut self) {
+        use winapi::um::handleapi::CloseHandle;
+
+        unsafe {
+            if !self.mapping.is_null() {
+                CloseHandle(self.mapping);
+            }
+
+            CloseHandle(self.mutex);
+        }
+    }
+}
+
+
+/**
+    Makes sure that only one instance of the application runs at the same time.
+
+    Returns a guard if this is the first instance holding `name`. Otherwise, the command line of the current process
+    is forwarded to the first instance (see `SingleInstanceGuard::listen` and `Event::OnSecondInstance`)
+    and `Ok(None)` is returned so that the caller can exit.
+
+    Returns an error if the named mutex cannot be created (ex: invalid name or access denied). In that case,
+    it is unknown if another instance is running and the caller decides if the application should start anyway.
+
+    `name` should be unique to the application (ex: a reverse domain name or a GUID) and must not contain backslashes
+    except for the optional namespace prefix. By default, the name is local to the user session. Prefix it with `Global\`
+    to enforce a single instance for the whole machine.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn run() {
+        let _guard = match nwg::single_instance("com.example.my-app") {
+            Ok(Some(guard)) => Some(guard),
+            Ok(None) => { return; },
+            Err(_) => None,
+        };
+
+        // Build the application, then call `_guard.listen(&window.handle)`
+    }
+    ```
+*/
+pub fn single_instance(name: &str) -> Result<Option<SingleInstanceGuard>, NwgError> {
+    use winapi::um::synchapi::CreateMutexW;
+    use winapi::um::memoryapi::CreateFileMappingW;
+    use winapi::um::handleapi::{CloseHandle, INVALID_HANDLE_VALUE};
+    use winapi::um::errhandlingapi::GetLastError;
+    use winapi::um::winnt::PAGE_READWRITE;
+    use winapi::shared::winerror::ERROR_ALREADY_EXISTS;
+
+    let mutex_name = to_utf16(name);
+    let mapping_name = to_utf16(&format!("{}.nwg-window", name));
+
+    unsafe {
+        let mutex = CreateMutexW(ptr::null_mut(), 1, mutex_name.as_ptr());
+        if mutex.is_null() {
+            let msg = format!("Failed to create the single instance mutex {:?} (error {})", name, GetLastError());
+            return Err(NwgError::initialization(msg));
+        }
+
+        if GetLastError() == ERROR_ALREADY_EXISTS {
+            CloseHandle(mutex);
+            forward_command_line(&mapping_name);
+            return Ok(None);
+        }
+
+        let mapping = CreateFileMappingW(
+            INVALID_HANDLE_VALUE,
+            ptr::null_mut(),
+            PAGE_READWRITE,
+            0,
+            mem::size_of::<u64>() as DWORD,
+            mapping_name.as_ptr()
+        );
+
+        Ok(Some(SingleInstanceGuard { mutex, mapping }))
+    }
+}
+
+/// Returns the window published by the first instance, or a null handle if it is not listening (yet)
+unsafe fn published_window(mapping_name: &[u16]) -> HWND {
+    use winapi::um::memoryapi::{OpenFileMappingW, MapViewOfFile, UnmapViewOfFile, FILE_MAP_READ};
+    use winapi::um::handleapi::CloseHandle;
+    use winapi::um::winuser::IsWindow;
+
+    let mapping = OpenFileMappingW(FILE_MAP_READ, 0, mapping_name.as_ptr());
+    if mapping.is_null() {
+        return ptr::null_mut();
+    }
+
+    let view = MapViewOfFile(mapping, FILE_MAP_READ, 0, 0, mem::size_of::<u64>());
+    let hwnd = match view.is_null() {
+        true => ptr::null_mut(),
+        false => {
+            let hwnd = *(view as *const u64) as usize as HWND;
+            UnmapViewOfFile(view);
+            hwnd
+        }
+    };
+
+    CloseHandle(mapping);
+
+    match hwnd.is_null() || IsWindow(hwnd) == 0 {
+        true => ptr::null_mut(),
+        false => hwnd
+    }
+}
+
+/// Sends the arguments of the current process to the window published by the first instance.
+/// Waits for the first instance to call `listen` if it has not published a window yet.
+unsafe fn forward_command_line(mapping_name: &[u16]) {
+    use winapi::um::winuser::{GetWindowThreadProcessId, AllowSetForegroundWindow, SendMessageTimeoutW, WM_COPYDATA, SMTO_ABORTIFHUNG};
+    use winapi::um::synchapi::Sleep;
+
+    let mut waited = 0;
+    let mut hwnd = published_window(mapping_name);
+    while hwnd.is_null() && waited < FORWARD_TIMEOUT_MS {
+        Sleep(LISTEN_POLL_MS);
+        waited += LISTEN_POLL_MS;
+        hwnd = published_window(mapping_name);
+    }
+
+    if hwnd.is_null() {
+        return;
+    }
+
+    // The new process owns the foreground right (it was just started by the user). Give it to the first instance.
+    let mut process_id = 0;
+    GetWindowThreadProcessId(hwnd, &mut process_id);
+    AllowSetForegroundWindow(process_id);
+
+    let mut text: Vec<u16> = Vec::new();
+    for arg in std::env::args() {
+        text.extend(to_utf16(&arg));
+    }
+
+    let data = COPYDATASTRUCT {
+        dwData: SECOND_INSTANCE_COPYDATA,
+        cbData: (text.len() * 2) as DWORD,
+        lpData: text.as_mut_ptr() as _,
+    };
+
+    let mut result = 0;
+    SendMessageTimeoutW(hwnd, WM_COPYDATA, 0, &data as *const COPYDATASTRUCT as _, SMTO_ABORTIFHUNG, FORWARD_TIMEOUT_MS, &mut result);
+}
+
+/// Reads the arguments sent by `forward_command_line`. Returns `None` if the message was not sent by `single_instance`.
+pub unsafe fn read_forwarded_args(data: &COPYDATASTRUCT) -> Option<Vec<String>> {
+    if data.dwData != SECOND_INSTANCE_COPYDATA {
+        return None;
+    }
+
+    let len = data.cbData as usize / 2;
+    let text = match data.lpData.is_null() || len == 0 {
+        true => &[][..],
+        false => ::std::slice::from_raw_parts(data.lpData as *const u16, len)
+    };
+
+    // Every argument is null terminated
+    let args = text.split(|&c| c == 0)
+        .take(text.iter().filter(|&&c| c == 0).count())
+        .map(String::from_utf16_lossy)
+        .collect();
+
+    Some(args)
+}
+
+/// Restores the window that received the arguments and brings it to the foreground
+pub unsafe fn show_first_instance(hwnd: HWND) {
+    use winapi::um::winuser::{IsIconic, ShowWindow, SetForegroundWindow, SW_RESTORE, SW_SHOW};
+
+    match IsIconic(hwnd) != 0 {
+        true => { ShowWindow(hwnd, SW_RESTORE); },
+        false => { ShowWindow(hwnd, SW_SHOW); }
+    }
+
+    SetForegroundWindow(hwnd);
+}
@@ -0,0 +1,22 @@
+/*!
+    Queries about the system and the user settings.
+*/
+
+
+/**
+    Returns `true` if the user interface language of the user is written from right to left (ex: Arabic, Hebrew, Persian).
+    Applications can use it to mirror their windows with `ControlHandle::set_rtl` when they start.
+*/
+pub fn is_rtl_locale() -> bool {
+    use winapi::um::winnls::{GetLocaleInfoW, GetUserDefaultUILanguage};
+
+    // Not declared by winapi. "1" is right to left, "0" left to right, "2" and "3" are the vertical layouts
+    const LOCALE_IREADINGLAYOUT: u32 = 0x70;
+
+    let mut layout = [0u16; 2];
+    unsafe {
+        let lcid = GetUserDefaultUILanguage() as u32;
+        let len = GetLocaleInfoW(lcid, LOCALE_IREADINGLAYOUT, layout.as_mut_ptr(), layout.len() as i32);
+        len > 0 && layout[0] == '1' as u16
+    }
+}
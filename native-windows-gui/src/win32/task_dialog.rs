@@ -0,0 +1,328 @@
+use super::base_helper::{to_utf16, from_utf16};
+use crate::controls::ControlHandle;
+use crate::NwgError;
+use winapi::shared::windef::HWND;
+use winapi::shared::minwindef::{UINT, WPARAM, LPARAM, FARPROC};
+use winapi::shared::basetsd::LONG_PTR;
+use winapi::shared::ntdef::HRESULT;
+use std::{ptr, mem};
+
+
+bitflags! {
+    /**
+        The system buttons of a task dialog (to use with `TaskDialogParams`).
+        The buttons are always displayed in the same order, after the custom buttons.
+    */
+    pub struct TaskDialogButtons: u32 {
+        const NONE = 0;
+        const OK = 0x01;
+        const YES = 0x02;
+        const NO = 0x04;
+        const CANCEL = 0x08;
+        const RETRY = 0x10;
+        const CLOSE = 0x20;
+    }
+}
+
+/**
+    Enum of task dialog icons (to use with `TaskDialogParams`)
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TaskDialogIcon {
+    None,
+    Warning,
+    Error,
+    Information,
+    Shield,
+}
+
+/**
+    A custom button of a task dialog. `id` is returned in `TaskDialogChoice::Custom` if the user clicks on the button.
+    Use ids greater than 100 to avoid conflicts with the system buttons.
+
+    With `TaskDialogParams::command_links`, the text after the first new line (`\n`) is displayed as a note under the button.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct TaskDialogButton<'a> {
+    pub id: i32,
+    pub text: &'a str,
+}
+
+/**
+    The button that closed a task dialog. If the user closed the dialog with the X button or the Esc key,
+    `TaskDialogChoice::Cancel` is returned.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TaskDialogChoice {
+    Ok,
+    Cancel,
+    Yes,
+    No,
+    Retry,
+    Close,
+    Custom(i32),
+}
+
+/**
+    Return value of `task_dialog`.
+
+    Members:
+    * `choice`: The button that closed the dialog
+    * `verification_checked`: The state of the verification checkbox. `false` if the dialog has no verification checkbox
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TaskDialogResult {
+    pub choice: TaskDialogChoice,
+    pub verification_checked: bool,
+}
+
+/**
+    A structure that defines how a task dialog should look and behave. Use `..Default::default()` for the unused members.
+
+    Members:
+    * `title`: The title of the dialog window
+    * `main_instruction`: The large text at the top of the dialog
+    * `content`: The message of the dialog. Can contain hyperlinks (`<a href="...">text</a>`) if `on_hyperlink` is set
+    * `icon`: The dialog icon
+    * `common_buttons`: The system buttons of the dialog. If there are no buttons at all, an Ok button is displayed
+    * `buttons`: The custom buttons of the dialog
+    * `command_links`: Display the custom buttons as command links instead of push buttons
+    * `default_button`: The id of the default button. Use the winapi ids (ex: `IDOK`) for the system buttons
+    * `verification`: The text of the verification checkbox. If `None`, there is no checkbox
+    * `verification_checked`: The default state of the verification checkbox
+    * `expanded_information`: Additional text hidden behind a "See details" expando
+    * `footer`: The text at the bottom of the dialog
+    * `on_hyperlink`: Called with the `href` of a hyperlink when the user clicks on it. Hyperlinks are only enabled if this is set
+*/
+pub struct TaskDialogParams<'a> {
+    pub title: &'a str,
+    pub main_instruction: &'a str,
+    pub content: &'a str,
+    pub icon: TaskDialogIcon,
+    pub common_buttons: TaskDialogButtons,
+    pub buttons: &'a [TaskDialogButton<'a>],
+    pub command_links: bool,
+    pub default_button: Option<i32>,
+    pub verification: Option<&'a str>,
+    pub verification_checked: bool,
+    pub expanded_information: Option<&'a str>,
+    pub footer: Option<&'a str>,
+    pub on_hyperlink: Option<&'a dyn Fn(&str)>,
+}
+
+impl<'a> Default for TaskDialogParams<'a> {
+    fn default() -> TaskDialogParams<'a> {
+        TaskDialogParams {
+            title: "",
+            main_instruction: "",
+            content: "",
+            icon: TaskDialogIcon::None,
+            common_buttons: TaskDialogButtons::OK,
+            buttons: &[],
+            command_links: false,
+            default_button: None,
+            verification: None,
+            verification_checked: false,
+            expanded_information: None,
+            footer: None,
+            on_hyperlink: None,
+        }
+    }
+}
+
+type HyperlinkCallback<'a> = &'a dyn Fn(&str);
+
+type TaskDialogIndirectFn = unsafe extern "system" fn(
+    *const winapi::um::commctrl::TASKDIALOGCONFIG,
+    *mut i32,
+    *mut i32,
+    *mut i32
+) -> HRESULT;
+
+unsafe extern "system" fn task_dialog_callback(_hwnd: HWND, msg: UINT, _w: WPARAM, l: LPARAM, data: LONG_PTR) -> HRESULT {
+    use winapi::um::commctrl::TDN_HYPERLINK_CLICKED;
+    use winapi::shared::winerror::S_OK;
+
+    if msg == TDN_HYPERLINK_CLICKED && data != 0 && l != 0 {
+        let callback = &*(data as *const HyperlinkCallback);
+
+        let href_ptr = l as *const u16;
+        let mut length = 0;
+        while *href_ptr.add(length) != 0 { length += 1; }
+        let href = from_utf16(::std::slice::from_raw_parts(href_ptr, length));
+
+        callback(&href);
+    }
+
+    S_OK
+}
+
+/// `TaskDialogIndirect` only exists in comctl32 v6, so it is loaded at runtime instead of being linked.
+/// Linking it would prevent applications without visual styles from starting at all.
+/// Comctl32 is always loaded in the process because NWG links it, so the module is only looked up here.
+unsafe fn load_task_dialog_indirect() -> Option<TaskDialogIndirectFn> {
+    use winapi::um::libloaderapi::{GetModuleHandleW, GetProcAddress};
+
+    let lib_name = to_utf16("comctl32.dll");
+    let lib = GetModuleHandleW(lib_name.as_ptr());
+    if lib.is_null() {
+        return None;
+    }
+
+    let proc = GetProcAddress(lib, "TaskDialogIndirect\0".as_ptr() as _);
+    match proc.is_null() {
+        true => None,
+        false => Some(mem::transmute::<FARPROC, TaskDialogIndirectFn>(proc))
+    }
+}
+
+/// Inner function used by the task dialog functions
+fn inner_task_dialog(parent: HWND, params: &TaskDialogParams) -> Result<TaskDialogResult, NwgError> {
+    use winapi::um::commctrl::{TASKDIALOGCONFIG, TASKDIALOGCONFIG_u1, TASKDIALOG_BUTTON, TDF_ENABLE_HYPERLINKS, TDF_USE_COMMAND_LINKS,
+      TDF_VERIFICATION_FLAG_CHECKED, TDF_ALLOW_DIALOG_CANCELLATION, TDF_POSITION_RELATIVE_TO_WINDOW, TDF_EXPAND_FOOTER_AREA};
+    use winapi::um::winuser::{IDOK, IDCANCEL, IDYES, IDNO, IDRETRY, IDCLOSE};
+    use winapi::shared::winerror::S_OK;
+    use winapi::um::winnt::PCWSTR;
+
+    let task_dialog_indirect = match unsafe { load_task_dialog_indirect() } {
+        Some(f) => f,
+        None => { return Err(NwgError::initialization("TaskDialogIndirect requires comctl32 v6. Call `enable_visual_styles` first.")); }
+    };
+
+    let title = to_utf16(params.title);
+    let main_instruction = to_utf16(params.main_instruction);
+    let content = to_utf16(params.content);
+    let verification = params.verification.map(to_utf16);
+    let expanded_information = params.expanded_information.map(to_utf16);
+    let footer = params.footer.map(to_utf16);
+
+    let buttons_text: Vec<Vec<u16>> = params.buttons.iter().map(|b| to_utf16(b.text)).collect();
+    let buttons: Vec<TASKDIALOG_BUTTON> = params.buttons.iter().zip(buttons_text.iter())
+        .map(|(b, text)| TASKDIALOG_BUTTON { nButtonID: b.id, pszButtonText: text.as_ptr() })
+        .collect();
+
+    let mut flags = TDF_ALLOW_DIALOG_CANCELLATION | TDF_EXPAND_FOOTER_AREA;
+    if !parent.is_null() { flags |= TDF_POSITION_RELATIVE_TO_WINDOW; }
+    if params.command_links { flags |= TDF_USE_COMMAND_LINKS; }
+    if params.verification_checked { flags |= TDF_VERIFICATION_FLAG_CHECKED; }
+    if params.on_hyperlink.is_some() { flags |= TDF_ENABLE_HYPERLINKS; }
+
+    // The standard icons are resource ids (MAKEINTRESOURCE(-1) to MAKEINTRESOURCE(-4))
+    let icon_id: u16 = match params.icon {
+        TaskDialogIcon::None => 0,
+        TaskDialogIcon::Warning => 0xFFFF,
+        TaskDialogIcon::Error => 0xFFFE,
+        TaskDialogIcon::Information => 0xFFFD,
+        TaskDialogIcon::Shield => 0xFFFC,
+    };
+
+    let opt_ptr = |v: &Option<Vec<u16>>| v.as_ref().map(|v| v.as_ptr()).unwrap_or(ptr::null());
+
+    let mut answer = 0;
+    let mut checked = 0;
+    let result = unsafe {
+        let mut icon: TASKDIALOGCONFIG_u1 = mem::zeroed();
+        *icon.pszMainIcon_mut() = icon_id as usize as PCWSTR;
+
+        // TASKDIALOGCONFIG is packed. Fields are assigned one by one and never borrowed.
+        let mut config: TASKDIALOGCONFIG = mem::zeroed();
+        config.cbSize = mem::size_of::<TASKDIALOGCONFIG>() as UINT;
+        config.hwndParent = parent;
+        config.dwFlags = flags;
+        config.dwCommonButtons = params.common_buttons.bits() as _;
+        config.pszWindowTitle = title.as_ptr();
+        config.u1 = icon;
+        config.pszMainInstruction = main_instruction.as_ptr();
+        config.pszContent = content.as_ptr();
+        config.cButtons = buttons.len() as UINT;
+        config.pButtons = buttons.as_ptr();
+        config.nDefaultButton = params.default_button.unwrap_or(0);
+        config.pszVerificationText = opt_ptr(&verification);
+        config.pszExpandedInformation = opt_ptr(&expanded_information);
+        config.pszFooter = opt_ptr(&footer);
+
+        if let Some(callback) = params.on_hyperlink.as_ref() {
+            config.pfCallback = Some(task_dialog_callback);
+            config.lpCallbackData = callback as *const HyperlinkCallback as LONG_PTR;
+        }
+
+        task_dialog_indirect(&config, &mut answer, ptr::null_mut(), &mut checked)
+    };
+
+    if result != S_OK {
+        return Err(NwgError::initialization(format!("TaskDialogIndirect failed with the error 0x{:X}", result)));
+    }
+
+    let choice = match answer {
+        IDOK => TaskDialogChoice::Ok,
+        IDCANCEL => TaskDialogChoice::Cancel,
+        IDYES => TaskDialogChoice::Yes,
+        IDNO => TaskDialogChoice::No,
+        IDRETRY => TaskDialogChoice::Retry,
+        IDCLOSE => TaskDialogChoice::Close,
+        id => TaskDialogChoice::Custom(id)
+    };
+
+    Ok(TaskDialogResult { choice, verification_checked: checked != 0 })
+}
+
+/**
+    Create an application wide task dialog. The task dialog is the modern replacement of the message box.
+    It supports a main instruction, custom buttons, command links, a verification checkbox, an expandable text and hyperlinks.
+    It is recommended to use `modal_task_dialog` because it locks the window that creates the dialog.
+
+    Task dialogs require the version 6 of comctl32. `enable_visual_styles` must be called before creating the dialog,
+    otherwise an error is returned.
+
+    Parameters:
+    * params: A `TaskDialogParams` structure that defines how the dialog should look
+
+    ```rust
+    use native_windows_gui as nwg;
+    fn test_task_dialog() {
+        let buttons = [
+            nwg::TaskDialogButton { id: 101, text: "Save\nSave the changes to the document" },
+            nwg::TaskDialogButton { id: 102, text: "Discard\nThe changes will be lost" },
+        ];
+
+        let open_link = |href: &str| println!("Clicked on {}", href);
+
+        let p = nwg::TaskDialogParams {
+            title: "Editor",
+            main_instruction: "Save the changes?",
+            content: "The document was modified. <a href=\"help\">Learn more</a>",
+            icon: nwg::TaskDialogIcon::Warning,
+            common_buttons: nwg::TaskDialogButtons::CANCEL,
+            buttons: &buttons,
+            command_links: true,
+            verification: Some("Do not ask again"),
+            on_hyperlink: Some(&open_link),
+            ..Default::default()
+        };
+
+        let result = nwg::task_dialog(&p).unwrap();
+        if result.choice == nwg::TaskDialogChoice::Custom(101) {
+            // Save
+        }
+    }
+    ```
+*/
+pub fn task_dialog<'a>(params: &TaskDialogParams<'a>) -> Result<TaskDialogResult, NwgError> {
+    inner_task_dialog(ptr::null_mut(), params)
+}
+
+/**
+    Create a task dialog for a selected window. The window will be locked until the user close the dialog.
+    See `task_dialog`.
+
+    This functions panics if a non window control is used as parent (ex: a menu)
+
+    Parameters:
+    * parent: The reference to a window-like control
+    * params: A `TaskDialogParams` structure that defines how the dialog should look
+*/
+pub fn modal_task_dialog<'a, P: Into<ControlHandle>>(parent: P, params: &TaskDialogParams<'a>) -> Result<TaskDialogResult, NwgError> {
+    let control_handle = parent.into();
+    let hwnd = control_handle.hwnd().expect("expected window like control");
+    inner_task_dialog(hwnd, params)
+}
@@ -0,0 +1,220 @@
+/*!
+    Validators attached to controls. See `ControlHandle::set_validator`.
+
+    The validators are kept per thread, because the controls live on the UI thread. A raw event handler bound on each
+    validated control runs the validator when the control loses the focus and removes it when the control is destroyed.
+    The handler itself is freed once the control receives `WM_NCDESTROY`.
+*/
+use winapi::shared::windef::HWND;
+use winapi::shared::minwindef::{BOOL, LPARAM};
+use super::base_helper::to_utf16;
+use super::window_helper::{self as wh, VALIDATION_HANDLER_ID};
+use std::collections::HashMap;
+use std::time::Duration;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::mem;
+
+const HIGHLIGHT_DURATION: Duration = Duration::from_millis(2000);
+const HIGHLIGHT_COLOR: [u8; 3] = [220, 40, 40];
+
+pub type Validator = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+
+/**
+    How a control shows the error returned by its validator. See `ControlHandle::set_validation_display`.
+
+    * `Auto`: `Balloon` for the edit controls (`TextInput`, `TextBox`), `Highlight` for the other controls
+    * `Balloon`: An error balloon tooltip under the text (`EM_SHOWBALLOONTIP`). Only the edit controls support it,
+      so the other controls use `Highlight` instead. Requires the version 6 of comctl32 (see `enable_visual_styles`)
+    * `Highlight`: A red frame around the control for two seconds (`ControlHandle::highlight`)
+    * `Silent`: Nothing is displayed. Read the error with `ControlHandle::validation_error` to display it in a custom way
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ValidationDisplay {
+    #[default]
+    Auto,
+    Balloon,
+    Highlight,
+    Silent,
+}
+
+struct ValidationState {
+    validator: Validator,
+    display: ValidationDisplay,
+    error: Option<String>,
+}
+
+thread_local! {
+    static VALIDATORS: RefCell<HashMap<usize, ValidationState>> = RefCell::new(HashMap::new());
+}
+
+
+/// Sets the validator of `handle`, or removes it if `validator` is `None`
+pub fn set_validator(handle: HWND, validator: Option<Validator>) {
+    let validator = match validator {
+        Some(v) => v,
+        None => {
+            VALIDATORS.with(|v| v.borrow_mut().remove(&(handle as usize)));
+            return;
+        }
+    };
+
+    VALIDATORS.with(|validators| {
+        let mut validators = validators.borrow_mut();
+        let state = validators.entry(handle as usize).or_insert_with(|| ValidationState {
+            validator: validator.clone(),
+            display: ValidationDisplay::Auto,
+            error: None,
+        });
+
+        state.validator = validator;
+        state.error = None;
+    });
+
+    bind_handler(handle);
+}
+
+/// Sets how the errors of the validator of `handle` are displayed. Does nothing if the control has no validator
+pub fn set_validation_display(handle: HWND, display: ValidationDisplay) {
+    VALIDATORS.with(|validators| {
+        if let Some(state) = validators.borrow_mut().get_mut(&(handle as usize)) {
+            state.display = display;
+        }
+    });
+}
+
+/// Returns `true` if `handle` has a validator
+pub fn has_validator(handle: HWND) -> bool {
+    VALIDATORS.with(|v| v.borrow().contains_key(&(handle as usize)))
+}
+
+/// Returns the error of the last validation of `handle`
+pub fn validation_error(handle: HWND) -> Option<String> {
+    VALIDATORS.with(|v| v.borrow().get(&(handle as usize)).and_then(|state| state.error.clone()))
+}
+
+/**
+    Runs the validator of `handle` on its text and displays the error, or hides the previous error if the text is valid.
+    Returns `true` if the text is valid or if the control has no validator.
+*/
+pub unsafe fn validate(handle: HWND) -> bool {
+    // The validator is called without borrowing the map, so it can use the validation functions
+    let state = VALIDATORS.with(|v| v.borrow().get(&(handle as usize)).map(|state| (state.validator.clone(), state.display)));
+    let (validator, display) = match state {
+        Some(state) => state,
+        None => { return true; }
+    };
+
+    let text = wh::get_window_text(handle);
+    let error = validator(&text).err();
+
+    VALIDATORS.with(|validators| {
+        if let Some(state) = validators.borrow_mut().get_mut(&(handle as usize)) {
+            state.error = error.clone();
+        }
+    });
+
+    display_error(handle, display, error.as_ref().map(|e| e as &str));
+
+    error.is_none()
+}
+
+/**
+    Validates every control with a validator in `parent` (the parent itself and its children, recursively), in the creation order.
+    Every error is displayed and the first invalid control receives the focus. Returns `true` if all the controls are valid.
+*/
+pub unsafe fn validate_children(parent: HWND) -> bool {
+    use winapi::um::winuser::{EnumChildWindows, SetFocus};
+
+    unsafe extern "system" fn collect(handle: HWND, p: LPARAM) -> BOOL {
+        let handles = &mut *(p as *mut Vec<HWND>);
+        handles.push(handle);
+        1
+    }
+
+    let mut handles = vec![parent];
+    EnumChildWindows(parent, Some(collect), &mut handles as *mut Vec<HWND> as LPARAM);
+
+    let mut first_invalid = None;
+    for handle in handles {
+        if has_validator(handle) && !validate(handle) && first_invalid.is_none() {
+            first_invalid = Some(handle);
+        }
+    }
+
+    match first_invalid {
+        Some(handle) => {
+            // Moving the focus runs the validator of the control that loses it, which is fine because it was just validated
+            SetFocus(handle);
+            false
+        },
+        None => true
+    }
+}
+
+unsafe fn display_error(handle: HWND, display: ValidationDisplay, error: Option<&str>) {
+    use winapi::um::commctrl::{EDITBALLOONTIP, EM_SHOWBALLOONTIP, EM_HIDEBALLOONTIP, TTI_ERROR};
+    use super::highlight::{show_highlight, cancel_highlight};
+
+    let is_edit = is_edit_control(handle);
+    let balloon = match display {
+        ValidationDisplay::Silent => { return; },
+        ValidationDisplay::Highlight => false,
+        ValidationDisplay::Auto | ValidationDisplay::Balloon => is_edit,
+    };
+
+    let error = match error {
+        Some(error) => error,
+        None => {
+            if is_edit {
+                wh::send_message(handle, EM_HIDEBALLOONTIP, 0, 0);
+            }
+            cancel_highlight(handle);
+            return;
+        }
+    };
+
+    if balloon {
+        let title = to_utf16("");
+        let text = to_utf16(error);
+        let mut tip = EDITBALLOONTIP {
+            cbStruct: mem::size_of::<EDITBALLOONTIP>() as _,
+            pszTitle: title.as_ptr(),
+            pszText: text.as_ptr(),
+            ttiIcon: TTI_ERROR as i32,
+        };
+
+        // Fails without comctl32 v6
+        if wh::send_message(handle, EM_SHOWBALLOONTIP, 0, &mut tip as *mut EDITBALLOONTIP as LPARAM) != 0 {
+            return;
+        }
+    }
+
+    show_highlight(handle, HIGHLIGHT_DURATION, HIGHLIGHT_COLOR).ok();
+}
+
+unsafe fn is_edit_control(handle: HWND) -> bool {
+    use winapi::um::winuser::GetClassNameW;
+    use super::base_helper::from_utf16;
+
+    let mut class_name = [0u16; 16];
+    let count = GetClassNameW(handle, class_name.as_mut_ptr(), class_name.len() as _) as usize;
+    from_utf16(&class_name[..count]).eq_ignore_ascii_case("Edit")
+}
+
+fn bind_handler(handle: HWND) {
+    use crate::bind_raw_event_handler_until_destroyed;
+    use winapi::um::winuser::{WM_KILLFOCUS, WM_DESTROY};
+
+    let handler = |hwnd, msg, _w, _l| {
+        match msg {
+            WM_KILLFOCUS => unsafe { validate(hwnd); },
+            WM_DESTROY => set_validator(hwnd, None),
+            _ => {}
+        }
+        None
+    };
+
+    unsafe { bind_raw_event_handler_until_destroyed(handle, VALIDATION_HANDLER_ID, handler); }
+}
@@ -9,7 +9,8 @@ use winapi::shared::basetsd::{DWORD_PTR, UINT_PTR};
 use winapi::um::winuser::{WNDPROC, NMHDR, IDCANCEL, IDOK};
 use winapi::um::commctrl::{NMTTDISPINFOW, SUBCLASSPROC};
 use super::base_helper::{CUSTOM_ID_BEGIN, to_utf16};
-use super::window_helper::{NOTICE_MESSAGE, NWG_INIT, NWG_TRAY, NWG_TIMER_TICK, NWG_TIMER_STOP};
+use super::window_helper::{NOTICE_MESSAGE, NWG_INIT, NWG_TRAY, NWG_TIMER_TICK, NWG_TIMER_STOP, NWG_SPLITTER_MOVE, NWG_CONFIRMED_CLOSE, WM_POINTERDOWN, WM_POINTERUP, WM_POINTERUPDATE,
+    custom_message_subscribed};
 use super::high_dpi;
 use crate::controls::ControlHandle;
 use crate::{Event, EventData, NwgError};
@@ -346,6 +347,46 @@ pub fn unbind_raw_event_handler(handler: &RawEventHandler) -> Result<(), NwgErro
     }
 }
 
+/**
+    Remove the raw event handler with the id `handler_id` from a control if there is one.
+    Used by NWG for the internal hooks that can be replaced after the control creation.
+*/
+pub(crate) fn unbind_raw_event_handler_inner(handle: &ControlHandle, handler_id: UINT_PTR) -> Result<(), NwgError> {
+    if !has_raw_handler(handle, handler_id) {
+        return Ok(());
+    }
+
+    let handler = RawEventHandler {
+        handle: handle.hwnd().unwrap(),
+        subclass_proc: Some(process_raw_events),
+        handler_id
+    };
+
+    unbind_raw_event_handler(&handler)
+}
+
+/**
+    Same as `bind_raw_event_handler_inner`, but the handler is never unbound manually. Its callback is freed when the control
+    receives `WM_NCDESTROY`. Used by the handlers that are bound on a control handle without an owning struct (ex: `ControlHandle::animate_to`).
+
+    Does nothing if a handler with the same id is already bound on the control.
+*/
+pub(crate) unsafe fn bind_raw_event_handler_until_destroyed<F>(handle: HWND, handler_id: UINT_PTR, f: F)
+    where F: Fn(HWND, UINT, WPARAM, LPARAM) -> Option<LRESULT> + 'static
+{
+    let subclass_proc: SUBCLASSPROC = Some(process_raw_events_until_destroyed);
+
+    let mut tmp_value = 0;
+    if GetWindowSubclass(handle, subclass_proc, handler_id, &mut tmp_value) != 0 {
+        return;
+    }
+
+    let boxed_proc: Box<RawCallback> = Box::new(f);
+    let boxed_proc_wrapper: Box<*mut RawCallback> = Box::new(Box::into_raw(boxed_proc));
+    let proc_data: *mut *mut RawCallback = Box::into_raw(boxed_proc_wrapper);
+    SetWindowSubclass(handle, subclass_proc, handler_id, proc_data as UINT_PTR);
+}
+
 /**
     High level function that handle the creation of custom window control or built in window control
 */
@@ -480,6 +521,23 @@ pub(crate) fn create_frame_classes() -> Result<(), NwgError> {
     Ok(())
 }
 
+#[cfg(feature = "splitter")]
+/// Create the window class for the splitter control
+pub(crate) fn create_splitter_classes() -> Result<(), NwgError> {
+    use winapi::um::libloaderapi::GetModuleHandleW;
+    use winapi::um::winuser::COLOR_BTNFACE;
+    
+    unsafe {
+        let hmod = GetModuleHandleW(ptr::null_mut());
+        if hmod.is_null() { return Err(NwgError::initialization("GetModuleHandleW failed")); }
+
+        let background = (COLOR_BTNFACE + 1) as usize as HBRUSH;
+        build_sysclass(hmod, "NWG_SPLITTER", Some(blank_window_proc), Some(background), None)?;
+    }
+    
+    Ok(())
+}
+
 #[cfg(feature = "message-window")]
 /// Create a message only window. Used with the `MessageWindow` control
 pub(crate) fn create_message_window() -> Result<ControlHandle, NwgError> {
@@ -521,10 +579,24 @@ pub(crate) fn create_message_window() -> Result<ControlHandle, NwgError> {
     A blank system procedure used when creating new window class. Actual system event handling is done in the subclass procedure `process_events`.
 */
 unsafe extern "system" fn blank_window_proc(hwnd: HWND, msg: UINT, w: WPARAM, l: LPARAM) -> LRESULT {
-    use winapi::um::winuser::{WM_CREATE, WM_CLOSE, SW_HIDE};
-    use winapi::um::winuser::{DefWindowProcW, PostMessageW, ShowWindow};
+    use winapi::um::winuser::{WM_CREATE, WM_CLOSE, WM_NEXTDLGCTL, SW_HIDE};
+    use winapi::um::winuser::{DefWindowProcW, PostMessageW, ShowWindow, SetFocus, GetFocus, GetNextDlgTabItem};
 
     let handled = match msg {
+        WM_NEXTDLGCTL => {
+            // Sent by `IsDialogMessage` to move the focus, for example when the mnemonic of a label is pressed.
+            // Only the dialog window procedure handles it by default.
+            let next = match l != 0 {
+                true => w as HWND,
+                false => GetNextDlgTabItem(hwnd, GetFocus(), (w != 0) as _)
+            };
+
+            if !next.is_null() {
+                SetFocus(next);
+            }
+
+            true
+        },
         WM_CREATE => {
             PostMessageW(hwnd, NWG_INIT, 0, 0);
             true
@@ -555,11 +627,15 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
     use winapi::um::winuser::{GetClassNameW, GetMenuItemID, GetSubMenu};
     use winapi::um::winuser::{WM_CLOSE, WM_COMMAND, WM_MENUCOMMAND, WM_TIMER, WM_NOTIFY, WM_HSCROLL, WM_VSCROLL, WM_LBUTTONDOWN, WM_LBUTTONUP,
       WM_RBUTTONDOWN, WM_RBUTTONUP, WM_SIZE, WM_MOVE, WM_PAINT, WM_MOUSEMOVE, WM_CONTEXTMENU, WM_INITMENUPOPUP, WM_MENUSELECT, WM_EXITSIZEMOVE,
-      WM_ENTERSIZEMOVE, SIZE_MAXIMIZED, SIZE_MINIMIZED, WM_KEYDOWN, WM_KEYUP, WM_CHAR, WM_MOUSEWHEEL, WM_DROPFILES, GET_WHEEL_DELTA_WPARAM,
-      WM_GETMINMAXINFO, WM_ENTERMENULOOP, WM_EXITMENULOOP, WM_SYSKEYDOWN, WM_SYSKEYUP};
+      WM_ENTERSIZEMOVE, SIZE_MAXIMIZED, SIZE_MINIMIZED, WM_KEYDOWN, WM_KEYUP, WM_CHAR, WM_MOUSEWHEEL, WM_MOUSEHWHEEL, WM_DROPFILES, GET_WHEEL_DELTA_WPARAM,
+      WM_GETMINMAXINFO, WM_ENTERMENULOOP, WM_EXITMENULOOP, WM_SYSKEYDOWN, WM_SYSKEYUP, WM_DRAWITEM, DRAWITEMSTRUCT, ODT_COMBOBOX, ODT_LISTBOX, LBS_NODATA,
+      WM_DEVICECHANGE, WM_COPYDATA, COPYDATASTRUCT, WM_SIZING, WMSZ_LEFT, WMSZ_RIGHT, WMSZ_TOP, WMSZ_TOPLEFT, WMSZ_TOPRIGHT, WMSZ_BOTTOM, WMSZ_BOTTOMLEFT, WM_INPUTLANGCHANGE,
+      WM_IME_STARTCOMPOSITION, WM_IME_COMPOSITION, WM_IME_ENDCOMPOSITION, WM_HELP, HELPINFO, HELPINFO_MENUITEM,
+      WM_ACTIVATE, WA_INACTIVE, WA_CLICKACTIVE, WM_SETTINGCHANGE, WM_SYSCOMMAND, GetSystemMenu};
     use winapi::um::shellapi::{NIN_BALLOONSHOW, NIN_BALLOONHIDE, NIN_BALLOONTIMEOUT, NIN_BALLOONUSERCLICK};
     use winapi::um::winnt::WCHAR;
     use winapi::shared::minwindef::{HIWORD, LOWORD};
+    use winapi::shared::windef::RECT;
 
     let callback_ptr = data as *mut *const Callback;
     let callback: &Callback = &**callback_ptr;
@@ -581,9 +657,13 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
                 }
             }
 
-            let keycode = w as u32;
-            let data = EventData::OnKey(keycode);
+            let mut handled = false;
+            let data = EventData::OnKey(KeyData { key: w as u32, flags: l as u32, handled: &mut handled as *mut bool });
             callback(evt, data, base_handle);
+
+            if handled {
+                return 0;
+            }
         },
         WM_NOTIFY => {
             let code = {
@@ -593,6 +673,12 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
         
             match code {
                 TTN_GETDISPINFOW => handle_tooltip_callback(mem::transmute::<_, *mut NMTTDISPINFOW>(l), callback),
+                #[cfg(feature="tree-view")]
+                winapi::um::commctrl::TVN_ITEMEXPANDINGW => {
+                    if !handle_tree_expanding_callback(l as *const NMHDR, callback) {
+                        return 1;
+                    }
+                },
                 _ => handle_default_notify_callback(mem::transmute::<_, *const NMHDR>(l), callback)
             }
         },
@@ -602,8 +688,23 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
             let handle = ControlHandle::MenuItem(parent_handle, item_id);
             callback(Event::OnMenuItemSelected, NO_DATA, handle);
         },
+        WM_SYSCOMMAND if (w & 0xFFF0) < 0xF000 => {
+            // The custom items of the system menu. See `SystemMenu::append`
+            let handle = ControlHandle::MenuItem(GetSystemMenu(hwnd, 0), (w & 0xFFF0) as u32);
+            callback(Event::OnMenuItemSelected, NO_DATA, handle);
+        },
+        WM_SYSCOMMAND => {
+            let mut consumed = false;
+            let data = EventData::OnSysCommand(SysCommandData { command: (w & 0xFFF0) as u32, consumed: &mut consumed as *mut bool });
+            callback(Event::OnSysCommand, data, base_handle);
+
+            if consumed {
+                return 0;
+            }
+        },
         WM_INITMENUPOPUP => {
-            callback(Event::OnMenuOpen, NO_DATA, ControlHandle::Menu(ptr::null_mut(), w as HMENU));
+            let data = MenuOpenData { menu: w as HMENU, index: LOWORD(l as u32) as u32, system_menu: HIWORD(l as u32) != 0 };
+            callback(Event::OnMenuOpen, EventData::OnMenuOpen(data), ControlHandle::Menu(ptr::null_mut(), w as HMENU));
         },
         WM_ENTERMENULOOP => {
             callback(Event::OnMenuEnter, NO_DATA, ControlHandle::Menu(ptr::null_mut(), w as HMENU));
@@ -614,6 +715,9 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
         WM_MOUSEWHEEL => {
             callback(Event::OnMouseWheel, EventData::OnMouseWheel(GET_WHEEL_DELTA_WPARAM(w) as i32), base_handle);
         },
+        WM_MOUSEHWHEEL => {
+            callback(Event::OnMouseHWheel, EventData::OnMouseHWheel(GET_WHEEL_DELTA_WPARAM(w) as i32), base_handle);
+        },
         WM_MENUSELECT => {
             let index = LOWORD(w as u32) as u32;
             let parent = l as HMENU;
@@ -637,13 +741,23 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
             let class_name = OsString::from_wide(&class_name_raw[..count]).into_string().unwrap_or("".to_string());
 
             match &class_name as &str {
-                "Button" => callback(button_commands(message), NO_DATA, handle),
-                "Edit" => callback(edit_commands(message), NO_DATA, handle),
+                "Button" => callback(button_commands(message), button_data(child_handle, message), handle),
+                "Edit" => callback(edit_commands(message), edit_data(child_handle, message), handle),
                 "ComboBox" => callback(combo_commands(message), NO_DATA, handle),
                 "Static" => callback(static_commands(child_handle, message), NO_DATA, handle),
-                "ListBox" => callback(listbox_commands(message), NO_DATA, handle),
+                "ListBox" => callback(listbox_commands(message), listbox_data(child_handle, message), handle),
                 _ => match w as i32 {
                     IDOK | IDCANCEL => callback(no_class_name_commands(w), NO_DATA, base_handle),
+                    _ if !child_handle.is_null() => {
+                        let data = EventData::OnRawNotification(RawNotificationData {
+                            notify: false,
+                            code: message as u32,
+                            id: LOWORD(w as u32) as usize,
+                            hwnd: child_handle,
+                            header: ptr::null(),
+                        });
+                        callback(Event::OnRawNotification, data, handle);
+                    },
                     _ => {}
                 },
             }
@@ -651,7 +765,21 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
         WM_CONTEXTMENU => {
             let target_handle = w as HWND;
             let handle = ControlHandle::Hwnd(target_handle);
-            callback(Event::OnContextMenu, NO_DATA, handle);
+
+            // The position is -1, -1 if the menu was requested with the keyboard
+            let (x, y) = (LOWORD(l as u32) as i16 as i32, HIWORD(l as u32) as i16 as i32);
+            let position = match (x, y) {
+                (-1, -1) => None,
+                _ => Some((x, y))
+            };
+
+            let mut suppress = false;
+            let data = EventData::OnContextMenu(ContextMenuData { suppress: &mut suppress as *mut bool, position });
+            callback(Event::OnContextMenu, data, handle);
+
+            if suppress {
+                return 0;
+            }
         },
         NWG_TRAY => {
             let msg = LOWORD(l as u32) as u32;
@@ -692,13 +820,111 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
             let data = EventData::OnMinMaxInfo(MinMaxInfo { inner: l as _ });
             callback(Event::OnMinMaxInfo, data, base_handle)
         },
-        WM_CHAR => callback(Event::OnChar, EventData::OnChar(char::from_u32(w as u32).unwrap_or('?')), base_handle),
-        WM_EXITSIZEMOVE => callback(Event::OnResizeEnd, NO_DATA, base_handle),
-        WM_ENTERSIZEMOVE => callback(Event::OnResizeBegin, NO_DATA, base_handle),
+        WM_CHAR => {
+            let mut handled = false;
+            let ch = char::from_u32(w as u32).unwrap_or('?');
+            let data = EventData::OnChar(CharData { ch, flags: l as u32, handled: &mut handled as *mut bool });
+            callback(Event::OnChar, data, base_handle);
+
+            if handled {
+                return 0;
+            }
+        },
+        WM_DRAWITEM => {
+            let draw = l as *const DRAWITEMSTRUCT;
+            if (&*draw).CtlType == ODT_COMBOBOX {
+                let mut handled = false;
+                let data = EventData::OnComboBoxDraw(ComboBoxDrawData { inner: draw, handled: &mut handled as *mut bool });
+                callback(Event::OnComboBoxDraw, data, ControlHandle::Hwnd((&*draw).hwndItem));
+
+                if !handled {
+                    draw_combobox_item_default(draw);
+                }
+
+                return 1;
+            }
+
+            // Virtual list boxes only store the number of items
+            let item = &*draw;
+            if item.CtlType == ODT_LISTBOX && super::window_helper::get_style(item.hwndItem) & LBS_NODATA == LBS_NODATA {
+                if item.itemID != u32::MAX {
+                    let mut text = String::new();
+                    let data = EventData::OnListBoxGetText(ListBoxGetTextData { index: item.itemID as usize, text: &mut text as *mut String });
+                    callback(Event::OnListBoxGetText, data, ControlHandle::Hwnd(item.hwndItem));
+                    draw_item_text_default(draw, &text);
+                }
+
+                return 1;
+            }
+        },
+        WM_DEVICECHANGE => {
+            if let Some(data) = device_change_data(w, l) {
+                callback(Event::OnDeviceChange, data, base_handle);
+            }
+        },
+        WM_SIZING => {
+            let edge = match w as u32 {
+                WMSZ_LEFT => SizingEdge::Left,
+                WMSZ_RIGHT => SizingEdge::Right,
+                WMSZ_TOP => SizingEdge::Top,
+                WMSZ_TOPLEFT => SizingEdge::TopLeft,
+                WMSZ_TOPRIGHT => SizingEdge::TopRight,
+                WMSZ_BOTTOM => SizingEdge::Bottom,
+                WMSZ_BOTTOMLEFT => SizingEdge::BottomLeft,
+                _ => SizingEdge::BottomRight,
+            };
+
+            // The default procedure does nothing with the rect, but the message is only reported as handled if the rect was changed
+            let rect = l as *mut RECT;
+            let old = *rect;
+
+            let data = EventData::OnSizing(SizingData { inner: rect, edge });
+            callback(Event::OnSizing, data, base_handle);
+
+            let new = &*rect;
+            if (old.left, old.top, old.right, old.bottom) != (new.left, new.top, new.right, new.bottom) {
+                return 1;
+            }
+        },
+        WM_POINTERDOWN | WM_POINTERUP | WM_POINTERUPDATE => {
+            let data = EventData::OnPointer(PointerData {
+                id: LOWORD(w as u32) as u32,
+                position: ((l & 0xFFFF) as i16 as i32, ((l >> 16) & 0xFFFF) as i16 as i32),
+                flags: HIWORD(w as u32) as u32,
+            });
+
+            let evt = match msg {
+                WM_POINTERDOWN => Event::OnPointerDown,
+                WM_POINTERUP => Event::OnPointerUp,
+                _ => Event::OnPointerUpdate
+            };
+
+            callback(evt, data, base_handle);
+        },
+        WM_EXITSIZEMOVE => {
+            callback(Event::OnResizeEnd, NO_DATA, base_handle);
+            callback(Event::OnExitSizeMove, NO_DATA, base_handle);
+        },
+        WM_ENTERSIZEMOVE => {
+            callback(Event::OnEnterSizeMove, NO_DATA, base_handle);
+            callback(Event::OnResizeBegin, NO_DATA, base_handle);
+        },
         WM_TIMER => callback(Event::OnTimerTick, NO_DATA, ControlHandle::Timer(hwnd, w as u32)),
         WM_MOVE => callback(Event::OnMove, NO_DATA, base_handle),
-        WM_HSCROLL => callback(Event::OnHorizontalScroll, NO_DATA, ControlHandle::Hwnd(l as HWND)),
-        WM_VSCROLL => callback(Event::OnVerticalScroll, NO_DATA, ControlHandle::Hwnd(l as HWND)),
+        WM_HSCROLL | WM_VSCROLL => {
+            let evt = match msg == WM_HSCROLL {
+                true => Event::OnHorizontalScroll,
+                false => Event::OnVerticalScroll
+            };
+
+            // The scroll bars of a window (`WS_HSCROLL`/`WS_VSCROLL`) do not have a handle
+            let source = match l == 0 {
+                true => hwnd,
+                false => l as HWND
+            };
+
+            callback(evt, scroll_data(hwnd, msg, w, l), ControlHandle::Hwnd(source))
+        },
         WM_MOUSEMOVE => callback(Event::OnMouseMove, NO_DATA, base_handle), 
         WM_LBUTTONUP => callback(Event::OnMousePress(MousePressEvent::MousePressLeftUp), NO_DATA,  base_handle), 
         WM_LBUTTONDOWN => callback(Event::OnMousePress(MousePressEvent::MousePressLeftDown), NO_DATA, base_handle), 
@@ -706,17 +932,130 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
         WM_RBUTTONDOWN => callback(Event::OnMousePress(MousePressEvent::MousePressRightDown), NO_DATA, base_handle),
         NOTICE_MESSAGE => callback(Event::OnNotice, NO_DATA, ControlHandle::Notice(hwnd, w as u32)),
         NWG_TIMER_STOP => callback(Event::OnTimerStop, NO_DATA, ControlHandle::Timer(hwnd, w as u32)),
+        NWG_SPLITTER_MOVE => {
+            let data = EventData::OnSplitterMove { position: l as i32, delta: w as isize as i32 };
+            callback(Event::OnSplitterMove, data, base_handle)
+        },
+        super::window_helper::NWG_LAYOUT => {
+            let cause = match w {
+                0 => LayoutCause::Resize,
+                1 => LayoutCause::Dpi,
+                _ => LayoutCause::Manual
+            };
+
+            callback(Event::OnLayout, EventData::OnLayout(cause), base_handle)
+        },
+        #[cfg(feature = "drag-drop")]
+        super::window_helper::NWG_DROP_TARGET => {
+            use super::drag_drop::{DROP_TARGET_ENTER, DROP_TARGET_OVER, DROP_TARGET_LEAVE};
+
+            let info = &*(l as *const DropTargetData);
+            let data = EventData::OnDropTarget(DropTargetData {
+                data: info.data,
+                position: info.position,
+                key_state: info.key_state,
+                allowed: info.allowed,
+                effect: info.effect,
+            });
+
+            let event = match w {
+                DROP_TARGET_ENTER => Event::OnDragEnter,
+                DROP_TARGET_OVER => Event::OnDragOver,
+                DROP_TARGET_LEAVE => Event::OnDragLeave,
+                _ => Event::OnDragDrop,
+            };
+
+            callback(event, data, base_handle)
+        },
+        WM_COPYDATA => {
+            let copy_data = &*(l as *const COPYDATASTRUCT);
+
+            #[cfg(feature = "single-instance")]
+            {
+                use super::single_instance::{read_forwarded_args, show_first_instance};
+                if let Some(args) = read_forwarded_args(copy_data) {
+                    show_first_instance(hwnd);
+                    callback(Event::OnSecondInstance, EventData::OnSecondInstance(args), base_handle);
+                    return 1;
+                }
+            }
+
+            // The buffer of the sender is only valid until the message returns
+            let bytes = match copy_data.lpData.is_null() {
+                true => Vec::new(),
+                false => ::std::slice::from_raw_parts(copy_data.lpData as *const u8, copy_data.cbData as usize).to_vec()
+            };
+
+            let data = EventData::OnCopyData { id: copy_data.dwData, data: bytes };
+            callback(Event::OnCopyData, data, base_handle);
+            return 1;
+        },
+        WM_INPUTLANGCHANGE => {
+            // The low word of the keyboard layout handle is the language identifier
+            let data = EventData::OnInputLanguageChanged((l as usize & 0xFFFF) as u16);
+            callback(Event::OnInputLanguageChanged, data, base_handle);
+        },
+        WM_SETTINGCHANGE => {
+            use super::base_helper::from_wide_ptr;
+
+            let area = match l == 0 {
+                true => String::new(),
+                false => from_wide_ptr(l as *mut u16, None)
+            };
+
+            let data = EventData::OnSettingChange { area, action: w as u32 };
+            callback(Event::OnSettingChange, data, base_handle);
+        },
+        WM_IME_STARTCOMPOSITION => callback(Event::OnImeStartComposition, NO_DATA, base_handle),
+        WM_IME_COMPOSITION => {
+            let data = EventData::OnImeComposition(super::ime::composition_data(hwnd, l as u32));
+            callback(Event::OnImeComposition, data, base_handle);
+        },
+        WM_IME_ENDCOMPOSITION => callback(Event::OnImeEndComposition, NO_DATA, base_handle),
+        WM_ACTIVATE => match LOWORD(w as u32) {
+            WA_INACTIVE => callback(Event::OnWindowDeactivate, NO_DATA, base_handle),
+            state => {
+                let data = EventData::OnWindowActivate { by_click: state == WA_CLICKACTIVE, minimized: HIWORD(w as u32) != 0 };
+                callback(Event::OnWindowActivate, data, base_handle);
+            }
+        },
+        WM_HELP => {
+            let info = &*(l as *const HELPINFO);
+            let menu = info.iContextType == HELPINFO_MENUITEM as i32;
+            let data = EventData::OnHelp(HelpData {
+                menu,
+                control_id: info.iCtrlId,
+                context_id: info.dwContextId,
+                position: (info.MousePos.x, info.MousePos.y),
+            });
+
+            // `hItemHandle` is a menu handle for the menu items
+            let source = match menu || info.hItemHandle.is_null() {
+                true => base_handle,
+                false => ControlHandle::Hwnd(info.hItemHandle as HWND)
+            };
+
+            callback(Event::OnHelp, data, source);
+
+            // Stops the default processing from sending the message again to the parent
+            return 1;
+        },
         NWG_TIMER_TICK => callback(Event::OnTimerTick, NO_DATA, ControlHandle::Timer(hwnd, w as u32)),
         NWG_INIT => callback(Event::OnInit, NO_DATA, base_handle),
         WM_CLOSE => {
+            let confirmed = w == NWG_CONFIRMED_CLOSE;
             let mut should_exit = true;
-            let data = EventData::OnWindowClose(WindowCloseData { data: &mut should_exit as *mut bool });
+            let data = EventData::OnWindowClose(WindowCloseData { data: &mut should_exit as *mut bool, confirmed });
             callback(Event::OnWindowClose, data, base_handle);
 
-            if !should_exit {
+            if !should_exit && !confirmed {
                 return 0;
             }
         },
+        _ if (0xC000..=0xFFFF).contains(&msg) && custom_message_subscribed(hwnd, msg) => {
+            let data = EventData::OnCustomMessage { msg, wparam: w, lparam: l };
+            callback(Event::OnCustomMessage, data, base_handle);
+        },
         _ => {}
     }
 
@@ -740,6 +1079,22 @@ unsafe extern "system" fn process_raw_events(hwnd: HWND, msg: UINT, w: WPARAM, l
     }
 }
 
+unsafe extern "system" fn process_raw_events_until_destroyed(hwnd: HWND, msg: UINT, w: WPARAM, l: LPARAM, id: UINT_PTR, data: DWORD_PTR) -> LRESULT {
+    use winapi::um::winuser::WM_NCDESTROY;
+
+    let result = process_raw_events(hwnd, msg, w, l, id, data);
+
+    if msg == WM_NCDESTROY {
+        let callback_wrapper: Box<*mut RawCallback> = Box::from_raw(data as *mut *mut RawCallback);
+        let callback: Box<RawCallback> = Box::from_raw(*callback_wrapper);
+        mem::drop(callback);
+
+        RemoveWindowSubclass(hwnd, Some(process_raw_events_until_destroyed), id);
+    }
+
+    result
+}
+
 fn button_commands(m: u16) -> Event {
     use winapi::um::winuser::{BN_CLICKED, BN_DBLCLK};
     match m {
@@ -852,10 +1207,22 @@ fn tree_data(m: u32, notif_raw: *const NMHDR) -> EventData {
     use crate::{ExpandState, TreeItem, TreeItemAction, TreeItemState};
     use winapi::um::commctrl::{
         NMTREEVIEWW, NMTVDISPINFOW, NMTVITEMCHANGE, TVE_COLLAPSE, TVE_EXPAND, TVN_DELETEITEMW,
-        TVN_ENDLABELEDITW, TVN_ITEMCHANGEDW, TVN_ITEMEXPANDEDW, TVN_SELCHANGEDW,
+        TVN_ENDLABELEDITW, TVN_ITEMCHANGEDW, TVN_ITEMEXPANDEDW, TVN_SELCHANGEDW, NM_RCLICK,
     };
 
     match m {
+        NM_RCLICK => {
+            use winapi::um::winuser::{GetMessagePos, ScreenToClient};
+            use winapi::shared::windef::POINT;
+
+            // NM_RCLICK only sends a NMHDR. Use the position of the message that raised the notification.
+            let hwnd = unsafe { (&*notif_raw).hwndFrom };
+            let pos = unsafe { GetMessagePos() };
+            let mut pt = POINT { x: (pos & 0xFFFF) as i16 as i32, y: ((pos >> 16) & 0xFFFF) as i16 as i32 };
+            unsafe { ScreenToClient(hwnd, &mut pt); }
+
+            EventData::OnTreeViewRightClick { x: pt.x, y: pt.y }
+        },
         TVN_DELETEITEMW => {
             let data = unsafe { &*(notif_raw as *const NMTREEVIEWW) };
             let item = TreeItem { handle: data.itemOld.hItem };
@@ -968,6 +1335,84 @@ fn list_view_data(_m: u32, _notif_raw: *const NMHDR) -> EventData {
 }
 
 
+unsafe fn device_change_data(w: WPARAM, l: LPARAM) -> Option<EventData> {
+    use crate::events::DeviceChangeKind;
+    use winapi::um::dbt::{DBT_DEVICEARRIVAL, DBT_DEVICEQUERYREMOVE, DBT_DEVICEQUERYREMOVEFAILED, DBT_DEVICEREMOVEPENDING,
+      DBT_DEVICEREMOVECOMPLETE, DBT_DEVNODES_CHANGED, DBT_DEVTYP_DEVICEINTERFACE, DBT_DEVTYP_VOLUME, DEV_BROADCAST_HDR,
+      DEV_BROADCAST_DEVICEINTERFACE_W, DEV_BROADCAST_VOLUME};
+    use super::base_helper::from_utf16;
+
+    let kind = match w {
+        DBT_DEVICEARRIVAL => DeviceChangeKind::Arrival,
+        DBT_DEVICEQUERYREMOVE => DeviceChangeKind::RemoveQuery,
+        DBT_DEVICEQUERYREMOVEFAILED => DeviceChangeKind::RemoveQueryFailed,
+        DBT_DEVICEREMOVEPENDING => DeviceChangeKind::RemovePending,
+        DBT_DEVICEREMOVECOMPLETE => DeviceChangeKind::RemoveComplete,
+        DBT_DEVNODES_CHANGED => DeviceChangeKind::NodesChanged,
+        _ => { return None; }
+    };
+
+    let header = l as *const DEV_BROADCAST_HDR;
+    let path = match header.as_ref() {
+        Some(h) if h.dbch_devicetype == DBT_DEVTYP_DEVICEINTERFACE => {
+            let interface = &*(header as *const DEV_BROADCAST_DEVICEINTERFACE_W);
+            let name_ptr = interface.dbcc_name.as_ptr();
+            let mut length = 0;
+            while *name_ptr.add(length) != 0 { length += 1; }
+            Some(from_utf16(::std::slice::from_raw_parts(name_ptr, length)))
+        },
+        Some(h) if h.dbch_devicetype == DBT_DEVTYP_VOLUME => {
+            let volume = &*(header as *const DEV_BROADCAST_VOLUME);
+            (0..26u8)
+                .find(|i| volume.dbcv_unitmask & (1 << i) != 0)
+                .map(|i| format!("{}:\\", (b'A' + i) as char))
+        },
+        _ => None
+    };
+
+    Some(EventData::OnDeviceChange { kind, path })
+}
+
+unsafe fn scroll_data(hwnd: HWND, msg: UINT, w: WPARAM, l: LPARAM) -> EventData {
+    use crate::events::ScrollKind;
+    use winapi::um::winuser::{SB_LINEUP, SB_LINEDOWN, SB_PAGEUP, SB_PAGEDOWN, SB_THUMBPOSITION, SB_THUMBTRACK, SB_TOP, SB_BOTTOM, SB_ENDSCROLL,
+      SB_CTL, SB_HORZ, SB_VERT, SIF_TRACKPOS, SIF_POS, WM_HSCROLL, SCROLLINFO, GetScrollInfo};
+    use winapi::shared::minwindef::{HIWORD, LOWORD};
+
+    // The position in the message is only 16 bits. Use the scroll info when the source is a scroll bar.
+    let thumb_position = || {
+        let (handle, bar) = match (l == 0, msg == WM_HSCROLL) {
+            (false, _) => (l as HWND, SB_CTL),
+            (true, true) => (hwnd, SB_HORZ),
+            (true, false) => (hwnd, SB_VERT),
+        };
+
+        let mut si: SCROLLINFO = mem::zeroed();
+        si.cbSize = mem::size_of::<SCROLLINFO>() as u32;
+        si.fMask = SIF_TRACKPOS | SIF_POS;
+        match GetScrollInfo(handle, bar as i32, &mut si) {
+            0 => HIWORD(w as u32) as i32,
+            _ if LOWORD(w as u32) as isize == SB_THUMBTRACK => si.nTrackPos,
+            _ => si.nPos
+        }
+    };
+
+    let kind = match LOWORD(w as u32) as isize {
+        SB_LINEUP => ScrollKind::LineUp,
+        SB_LINEDOWN => ScrollKind::LineDown,
+        SB_PAGEUP => ScrollKind::PageUp,
+        SB_PAGEDOWN => ScrollKind::PageDown,
+        SB_THUMBPOSITION => ScrollKind::ThumbPosition(thumb_position()),
+        SB_THUMBTRACK => ScrollKind::ThumbTrack(thumb_position()),
+        SB_TOP => ScrollKind::Top,
+        SB_BOTTOM => ScrollKind::Bottom,
+        SB_ENDSCROLL => ScrollKind::EndScroll,
+        code => ScrollKind::Other(code as u16)
+    };
+
+    EventData::OnScroll(kind)
+}
+
 unsafe fn static_commands(handle: HWND, m: u16) -> Event {
     use winapi::um::winuser::{STN_CLICKED, STN_DBLCLK, STM_GETIMAGE, IMAGE_BITMAP, IMAGE_ICON, IMAGE_CURSOR};
     use winapi::um::winuser::SendMessageW;
@@ -992,15 +1437,67 @@ unsafe fn static_commands(handle: HWND, m: u16) -> Event {
 }
 
 unsafe fn listbox_commands(m: u16) -> Event {
-    use winapi::um::winuser::{LBN_SELCHANGE, LBN_DBLCLK};
+    use winapi::um::winuser::{LBN_SELCHANGE, LBN_DBLCLK, LBN_SETFOCUS, LBN_KILLFOCUS};
 
     match m {
         LBN_SELCHANGE => Event::OnListBoxSelect,
         LBN_DBLCLK => Event::OnListBoxDoubleClick,
+        LBN_SETFOCUS => Event::OnListBoxFocus,
+        LBN_KILLFOCUS => Event::OnListBoxFocusLost,
         _ => Event::Unknown
     }
 }
 
+unsafe fn button_data(handle: HWND, m: u16) -> EventData {
+    use winapi::um::winuser::BN_CLICKED;
+    use super::window_helper::button_click_source;
+
+    match m {
+        BN_CLICKED => {
+            let (by_keyboard, programmatic) = button_click_source(handle);
+            EventData::OnButtonClick { by_keyboard, programmatic }
+        },
+        _ => EventData::NoData
+    }
+}
+
+unsafe fn button_dropdown_data(notif_raw: *const NMHDR) -> EventData {
+    use winapi::um::commctrl::NMBCDROPDOWN;
+    use winapi::um::winuser::MapWindowPoints;
+    use winapi::shared::windef::POINT;
+
+    let notif = &*(notif_raw as *const NMBCDROPDOWN);
+
+    // `rcButton` is in the client coordinates of the button
+    let mut rect = notif.rcButton;
+    MapWindowPoints(notif.hdr.hwndFrom, ptr::null_mut(), &mut rect as *mut _ as *mut POINT, 2);
+
+    EventData::OnButtonDropdown { rect: [rect.left, rect.top, rect.right, rect.bottom] }
+}
+
+unsafe fn edit_data(handle: HWND, m: u16) -> EventData {
+    use winapi::um::winuser::EN_CHANGE;
+    use super::window_helper::edit_text_programmatic;
+
+    match m {
+        EN_CHANGE => EventData::OnTextInput { programmatic: edit_text_programmatic(handle) },
+        _ => EventData::NoData
+    }
+}
+
+unsafe fn listbox_data(handle: HWND, m: u16) -> EventData {
+    use winapi::um::winuser::LBN_SELCHANGE;
+    use super::window_helper::{list_box_previous_item, list_box_current_item};
+
+    match m {
+        LBN_SELCHANGE => EventData::OnListBoxSelect {
+            old: list_box_previous_item(handle),
+            new: list_box_current_item(handle),
+        },
+        _ => EventData::NoData
+    }
+}
+
 unsafe fn handle_tooltip_callback<'a>(notif: *mut NMTTDISPINFOW, callback: &Callback) {
     use crate::events::ToolTipTextData;
 
@@ -1010,7 +1507,29 @@ unsafe fn handle_tooltip_callback<'a>(notif: *mut NMTTDISPINFOW, callback: &Call
     callback(Event::OnTooltipText, data, handle);
 }
 
+/// Raises `OnTreeItemExpanding`. Returns `false` if the handler canceled the operation.
+#[cfg(feature="tree-view")]
+unsafe fn handle_tree_expanding_callback(notif_raw: *const NMHDR, callback: &Callback) -> bool {
+    use crate::{TreeItem, TreeItemExpandingData};
+    use winapi::um::commctrl::{NMTREEVIEWW, TVE_EXPAND};
+
+    let data = &*(notif_raw as *const NMTREEVIEWW);
+    let handle = ControlHandle::Hwnd(data.hdr.hwndFrom);
+
+    let mut allow = true;
+    let expanding = TreeItemExpandingData {
+        item: TreeItem { handle: data.itemNew.hItem },
+        expanding: data.action as usize & TVE_EXPAND == TVE_EXPAND,
+        allow: &mut allow as *mut bool,
+    };
+
+    callback(Event::OnTreeItemExpanding, EventData::OnTreeItemExpanding(expanding), handle);
+
+    allow
+}
+
 unsafe fn handle_default_notify_callback<'a>(notif_raw: *const NMHDR, callback: &Callback){
+    use crate::events::RawNotificationData;
     use winapi::um::winnt::WCHAR;
     use winapi::um::winuser::GetClassNameW;
 
@@ -1029,7 +1548,17 @@ unsafe fn handle_default_notify_callback<'a>(notif_raw: *const NMHDR, callback:
         "msctls_trackbar32" => callback(track_commands(code), NO_DATA, handle),
         winapi::um::commctrl::WC_TREEVIEW => callback(tree_commands(code), tree_data(code, notif_raw), handle),
         winapi::um::commctrl::WC_LISTVIEW => callback(list_view_commands(code), list_view_data(code, notif_raw), handle),
-        _ => {}
+        "Button" if code == winapi::um::commctrl::BCN_DROPDOWN => callback(Event::OnButtonDropdown, button_dropdown_data(notif_raw), handle),
+        _ => {
+            let data = EventData::OnRawNotification(RawNotificationData {
+                notify: true,
+                code,
+                id: notif.idFrom,
+                hwnd: notif.hwndFrom,
+                header: notif_raw,
+            });
+            callback(Event::OnRawNotification, data, handle);
+        }
     }
 }
 
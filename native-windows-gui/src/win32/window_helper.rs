@@ -1,5 +1,6 @@
 use super::base_helper::{to_utf16, from_utf16};
 use super::high_dpi;
+use crate::ControlHandle;
 use winapi::shared::windef::{HFONT, HWND, HMENU};
 use winapi::shared::minwindef::{UINT, WPARAM, LPARAM, LRESULT};
 use winapi::um::winuser::WM_USER;
@@ -14,6 +15,80 @@ pub const NWG_INIT: UINT = WM_USER + 101;
 pub const NWG_TRAY: UINT = WM_USER + 102;
 pub const NWG_TIMER_TICK: UINT = WM_USER + 103;
 pub const NWG_TIMER_STOP: UINT = WM_USER + 104;
+pub const NWG_SPLITTER_MOVE: UINT = WM_USER + 105;
+#[cfg(feature = "drag-drop")]
+pub const NWG_DROP_TARGET: UINT = WM_USER + 106;
+pub const NWG_DISPATCH: UINT = WM_USER + 107;
+pub const NWG_LAYOUT: UINT = WM_USER + 108;
+
+// Raw event handler ids reserved by NWG for the hooks that can be applied to any control
+pub const WANT_KEYS_HANDLER_ID: usize = 0x4600;
+pub const BACKGROUND_HANDLER_ID: usize = 0x4601;
+pub const FADE_HANDLER_ID: usize = 0x4602;
+#[cfg(feature = "drag-drop")]
+pub const DROP_TARGET_HANDLER_ID: usize = 0x4603;
+pub const RESIZE_MODE_HANDLER_ID: usize = 0x4604;
+pub const ANIMATE_HANDLER_ID: usize = 0x4605;
+#[cfg(feature = "listbox")]
+pub const LIST_BOX_SELECTION_HANDLER_ID: usize = 0x4606;
+pub const BUTTON_CLICK_HANDLER_ID: usize = 0x4607;
+pub const MINIMIZE_TO_TRAY_HANDLER_ID: usize = 0x4608;
+pub const DIALOG_BUTTONS_HANDLER_ID: usize = 0x4609;
+#[cfg(feature = "tooltip")]
+pub const SHARED_TOOLTIP_HANDLER_ID: usize = 0x460A;
+pub const REMEMBER_FOCUS_HANDLER_ID: usize = 0x460B;
+pub const VALIDATION_HANDLER_ID: usize = 0x460C;
+pub const IME_CONTEXT_HANDLER_ID: usize = 0x460D;
+#[cfg(feature = "listbox")]
+pub const LIST_BOX_INLINE_EDIT_HANDLER_ID: usize = 0x460E;
+
+/// `wParam` of the `WM_CLOSE` posted by `Window::request_close`. Marks a close that was already confirmed by the application
+pub const NWG_CONFIRMED_CLOSE: WPARAM = 0x4E5743;
+
+/// Window property holding the selection of a list box before the last user input (index + 1, 0 for no selection)
+pub const LIST_BOX_SELECTION_PROP: &str = "NWG_LIST_BOX_SELECTION";
+
+/// Window properties holding the input that last activated a button (`CLICK_SOURCE_*`) and the time of the message of that input
+pub const BUTTON_CLICK_SOURCE_PROP: &str = "NWG_CLICK_SOURCE";
+pub const BUTTON_CLICK_TIME_PROP: &str = "NWG_CLICK_TIME";
+
+/// Window property set on an edit control while NWG changes its text. See `set_edit_text`
+pub const PROGRAMMATIC_TEXT_PROP: &str = "NWG_PROGRAMMATIC_TEXT";
+
+/// Window property holding the tooltip shared by the controls of a top level window. See `ControlHandle::set_tooltip`
+#[cfg(feature = "tooltip")]
+pub const SHARED_TOOLTIP_PROP: &str = "NWG_SHARED_TOOLTIP";
+
+/// Window property holding the input context of a control while its IME is disabled. See `TextInput::set_ime_enabled`
+pub const IME_CONTEXT_PROP: &str = "NWG_IME_CONTEXT";
+
+/// Prefix of the window properties set on a window for each registered message it subscribed to. See `Window::subscribe_message`
+pub const CUSTOM_MESSAGE_PROP: &str = "NWG_CUSTOM_MESSAGE_";
+
+pub const CLICK_SOURCE_MOUSE: usize = 1;
+pub const CLICK_SOURCE_KEYBOARD: usize = 2;
+pub const CLICK_SOURCE_PROGRAMMATIC: usize = 3;
+
+/// Set with `CLICK_SOURCE_PROGRAMMATIC` until the simulated mouse click of `BM_CLICK` is received
+pub const CLICK_SOURCE_PENDING: usize = 0x10;
+
+// DWM window attributes added in Windows 10 and 11. Not declared by winapi
+pub const DWMWA_USE_IMMERSIVE_DARK_MODE_OLD: u32 = 19;
+pub const DWMWA_USE_IMMERSIVE_DARK_MODE: u32 = 20;
+pub const DWMWA_WINDOW_CORNER_PREFERENCE: u32 = 33;
+pub const DWMWA_BORDER_COLOR: u32 = 34;
+pub const DWMWA_CAPTION_COLOR: u32 = 35;
+pub const DWMWA_TEXT_COLOR: u32 = 36;
+pub const DWMWA_SYSTEMBACKDROP_TYPE: u32 = 38;
+pub const DWMWA_COLOR_DEFAULT: u32 = 0xFFFFFFFF;
+
+// Display affinity added in Windows 10 (build 19041). Not declared by winapi
+pub const WDA_EXCLUDEFROMCAPTURE: u32 = 0x11;
+
+// Pointer input messages (Windows 8). Not declared by winapi
+pub const WM_POINTERUPDATE: UINT = 0x0245;
+pub const WM_POINTERDOWN: UINT = 0x0246;
+pub const WM_POINTERUP: UINT = 0x0247;
 
 
 /// Returns the class info of a hwnd handle
@@ -175,6 +250,71 @@ pub fn get_window_font(handle: HWND) -> HFONT {
     }
 }
 
+/// Measure the text of a window using its font. Multiline text is supported. Returns the size in physical pixels.
+pub unsafe fn measure_window_text(handle: HWND) -> (u32, u32) {
+    measure_text(handle, &get_window_text(handle))
+}
+
+/// Measure `text` using the font of a window. Multiline text is supported. Returns the size in physical pixels.
+pub unsafe fn measure_text(handle: HWND, text: &str) -> (u32, u32) {
+    use winapi::um::winuser::{GetDC, ReleaseDC, DrawTextW, DT_CALCRECT, DT_LEFT, DT_NOPREFIX};
+    use winapi::um::wingdi::SelectObject;
+    use winapi::shared::windef::{RECT, HGDIOBJ};
+
+    let mut text = to_utf16(text);
+    if text.len() <= 1 {
+        // Empty text still needs the height of a line
+        text = to_utf16(" ");
+    }
+
+    let font = get_window_font(handle);
+    let dc = GetDC(handle);
+    let old = SelectObject(dc, font as HGDIOBJ);
+
+    let mut r: RECT = mem::zeroed();
+    DrawTextW(dc, text.as_ptr(), -1, &mut r, DT_CALCRECT | DT_LEFT | DT_NOPREFIX);
+
+    SelectObject(dc, old);
+    ReleaseDC(handle, dc);
+
+    ((r.right - r.left) as u32, (r.bottom - r.top) as u32)
+}
+
+/// Size that fits the text of a window, in logical pixels. `extra` is added to the measured text in physical pixels, `padding` in logical pixels on each side.
+pub unsafe fn window_text_fit_size(handle: HWND, extra: (u32, u32), padding: u32) -> (u32, u32) {
+    let (w, h) = measure_window_text(handle);
+    let (w, h) = high_dpi::physical_to_logical((w + extra.0) as i32, (h + extra.1) as i32);
+    (w as u32 + padding * 2, h as u32 + padding * 2)
+}
+
+/// Resize a window so that its text fits. `extra` is added to the measured text in physical pixels, `padding` in logical pixels on each side.
+pub unsafe fn fit_window_to_text(handle: HWND, extra: (u32, u32), padding: u32) {
+    let (w, h) = window_text_fit_size(handle, extra, padding);
+    set_window_size(handle, w, h, false);
+}
+
+/// Space taken by the borders of a push button around its text, in physical pixels
+pub unsafe fn push_button_text_extra() -> (u32, u32) {
+    use winapi::um::winuser::{GetSystemMetrics, SM_CXEDGE, SM_CYEDGE};
+    ((GetSystemMetrics(SM_CXEDGE) * 4) as u32, (GetSystemMetrics(SM_CYEDGE) * 4) as u32)
+}
+
+/// Space taken by the glyph of a check box or a radio button next to its text, in physical pixels
+pub unsafe fn check_text_extra(handle: HWND) -> (u32, u32) {
+    use winapi::um::winuser::{GetSystemMetrics, SM_CXMENUCHECK, SM_CYMENUCHECK, SM_CXEDGE};
+
+    let glyph_width = GetSystemMetrics(SM_CXMENUCHECK) + GetSystemMetrics(SM_CXEDGE) * 2;
+    let glyph_height = GetSystemMetrics(SM_CYMENUCHECK) as u32;
+    let (_, text_height) = measure_window_text(handle);
+
+    (glyph_width as u32, glyph_height.saturating_sub(text_height))
+}
+
+/// Resize a check box or a radio button so that its glyph and its text fit.
+pub unsafe fn fit_check_window_to_text(handle: HWND, padding: u32) {
+    fit_window_to_text(handle, check_text_extra(handle), padding);
+}
+
 pub fn maximize_window(handle: HWND) {
     use winapi::um::winuser::{ShowWindow, SW_MAXIMIZE};
     unsafe {
@@ -232,12 +372,48 @@ pub fn get_style(handle: HWND) -> UINT {
     get_window_long(handle, GWL_STYLE) as UINT
 }
 
+pub fn get_ex_style(handle: HWND) -> UINT {
+    use ::winapi::um::winuser::GWL_EXSTYLE;
+    get_window_long(handle, GWL_EXSTYLE) as UINT
+}
+
+/// Add and remove bits from the style (`GWL_STYLE`) or the extended style (`GWL_EXSTYLE`) of a window
+/// and tell the system that the frame changed. Returns the previous style.
+pub unsafe fn modify_window_style(handle: HWND, index: c_int, add: u32, remove: u32) -> u32 {
+    use winapi::um::winuser::{SetWindowPos, SWP_FRAMECHANGED, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER, SWP_NOACTIVATE, SWP_NOOWNERZORDER};
+
+    let old = get_window_long(handle, index) as u32;
+    let new = (old & !remove) | add;
+    if new != old {
+        set_window_long(handle, index, new as usize);
+
+        let flags = SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE | SWP_NOOWNERZORDER;
+        SetWindowPos(handle, ptr::null_mut(), 0, 0, 0, 0, flags);
+    }
+
+    old
+}
+
+/// Sets a DWM attribute of a top level window. Returns `false` if the attribute is not supported by the system.
+pub unsafe fn set_dwm_attribute<T>(handle: HWND, attribute: u32, value: &T) -> bool {
+    use winapi::um::dwmapi::DwmSetWindowAttribute;
+    use winapi::shared::winerror::S_OK;
+
+    let value_ptr = value as *const T;
+    DwmSetWindowAttribute(handle, attribute, value_ptr as _, mem::size_of::<T>() as u32) == S_OK
+}
+
 #[cfg(any(feature = "list-view", feature = "progress-bar"))]
 pub fn set_style(handle: HWND, style: u32) {
     use ::winapi::um::winuser::GWL_STYLE;
     set_window_long(handle, GWL_STYLE, style as usize);
 }
 
+/// Posts the `OnLayout` event of a layout to its parent. `wParam` is the cause
+pub fn post_layout_event(parent: HWND, cause: crate::LayoutCause) {
+    post_message(parent, NWG_LAYOUT, cause as WPARAM, 0);
+}
+
 pub fn send_message(hwnd: HWND, msg: UINT, w: WPARAM, l: LPARAM) -> LRESULT {
     unsafe { ::winapi::um::winuser::SendMessageW(hwnd, msg, w, l) }
 }
@@ -276,6 +452,27 @@ pub unsafe fn set_window_text<'a>(handle: HWND, text: &'a str) {
     SetWindowTextW(handle, text.as_ptr());
 }
 
+/**
+    Sets the text of an edit control with `PROGRAMMATIC_TEXT_PROP` set, so that the `EN_CHANGE` sent while
+    the text is replaced raises `OnTextInput` with `programmatic` set to `true`.
+*/
+pub unsafe fn set_edit_text(handle: HWND, text: &str) {
+    use winapi::um::winuser::{SetPropW, RemovePropW};
+
+    let prop_name = to_utf16(PROGRAMMATIC_TEXT_PROP);
+    SetPropW(handle, prop_name.as_ptr(), 1 as _);
+    set_window_text(handle, text);
+    RemovePropW(handle, prop_name.as_ptr());
+}
+
+/// Returns `true` if the text of the edit control is being changed by `set_edit_text`
+pub unsafe fn edit_text_programmatic(handle: HWND) -> bool {
+    use winapi::um::winuser::GetPropW;
+
+    let prop_name = to_utf16(PROGRAMMATIC_TEXT_PROP);
+    !GetPropW(handle, prop_name.as_ptr()).is_null()
+}
+
 pub unsafe fn set_window_position(handle: HWND, x: i32, y: i32) {
     use winapi::um::winuser::SetWindowPos;
     use winapi::um::winuser::{SWP_NOZORDER, SWP_NOSIZE, SWP_NOACTIVATE, SWP_NOOWNERZORDER};
@@ -316,6 +513,343 @@ pub unsafe fn get_window_position(handle: HWND) -> (i32, i32) {
     high_dpi::physical_to_logical(x, y)
 }
 
+/**
+    Returns the identifier of the window message registered under `name` (`RegisterWindowMessageW`), or 0 if it could not be registered.
+    Every call with the same name returns the same identifier, in every process, until the user logs off.
+    The identifiers are between `0xC000` and `0xFFFF`.
+
+    Windows receive the registered messages as `OnCustomMessage` after `Window::subscribe_message`.
+    Use `broadcast_message` to send one to every top level window.
+
+    The identifier is shared by all the processes of the session: a window of another application that registered the same name
+    receives the message too, and can send it to the windows of this application. This can be used for communication between processes,
+    but it can also be a source of unexpected messages. Use a name unique to the application (ex: with a GUID) to avoid collisions.
+*/
+pub fn register_message(name: &str) -> u32 {
+    use winapi::um::winuser::RegisterWindowMessageW;
+
+    let name = to_utf16(name);
+    unsafe { RegisterWindowMessageW(name.as_ptr()) }
+}
+
+/**
+    Sends the registered message `msg` to every top level window of the session, including the ones of other processes.
+    The windows of the application that subscribed to `msg` with `Window::subscribe_message` raise `OnCustomMessage`.
+
+    The windows of the current thread process the message before this function returns. The other windows process it asynchronously.
+    `wparam` and `lparam` must be values, not pointers: pointers are not valid in the other processes and in the other threads
+    once the function returned.
+
+    Returns `false` if the message could not be sent.
+*/
+pub fn broadcast_message(msg: u32, wparam: usize, lparam: isize) -> bool {
+    use winapi::um::winuser::{SendNotifyMessageW, HWND_BROADCAST};
+    unsafe { SendNotifyMessageW(HWND_BROADCAST, msg, wparam, lparam) != 0 }
+}
+
+/// Name of the window property that marks a window as subscribed to `msg`
+pub fn custom_message_prop(msg: u32) -> Vec<u16> {
+    to_utf16(&format!("{}{:04X}", CUSTOM_MESSAGE_PROP, msg))
+}
+
+/// Returns `true` if `handle` subscribed to the registered message `msg`. See `Window::subscribe_message`
+pub unsafe fn custom_message_subscribed(handle: HWND, msg: u32) -> bool {
+    use winapi::um::winuser::GetPropW;
+
+    let prop_name = custom_message_prop(msg);
+    !GetPropW(handle, prop_name.as_ptr()).is_null()
+}
+
+/**
+    Converts a point in screen coordinates into the client coordinates of a control.
+
+    Screen coordinates are in physical pixels (same as `GlobalCursor::position` or `Menu::popup`) and
+    client coordinates are in logical pixels (same as the position of the control children).
+    Only a single point is converted.
+
+    Panics if `control` is not a window-like control.
+*/
+pub fn screen_to_client<C: Into<ControlHandle>>(control: C, point: (i32, i32)) -> (i32, i32) {
+    use winapi::um::winuser::ScreenToClient;
+    use winapi::shared::windef::POINT;
+
+    let control = control.into();
+    let handle = control.hwnd().expect("screen_to_client can only be used with window-like controls");
+
+    let mut p = POINT { x: point.0, y: point.1 };
+    unsafe {
+        ScreenToClient(handle, &mut p);
+        high_dpi::physical_to_logical(p.x, p.y)
+    }
+}
+
+/**
+    Converts a point in the client coordinates of a control into screen coordinates.
+
+    Client coordinates are in logical pixels (same as the position of the control children) and 
+    screen coordinates are in physical pixels (same as `GlobalCursor::position` or `Menu::popup`).
+    Only a single point is converted.
+
+    Panics if `control` is not a window-like control.
+*/
+pub fn client_to_screen<C: Into<ControlHandle>>(control: C, point: (i32, i32)) -> (i32, i32) {
+    use winapi::um::winuser::ClientToScreen;
+    use winapi::shared::windef::POINT;
+
+    let control = control.into();
+    let handle = control.hwnd().expect("client_to_screen can only be used with window-like controls");
+
+    unsafe {
+        let (x, y) = high_dpi::logical_to_physical(point.0, point.1);
+        let mut p = POINT { x, y };
+        ClientToScreen(handle, &mut p);
+        (p.x, p.y)
+    }
+}
+
+/**
+    Returns the topmost window under a point in screen coordinates (physical pixels, same as `GlobalCursor::position`).
+
+    Hidden windows are skipped. Disabled children are returned, unlike the raw `WindowFromPoint`, so that
+    the function can be used to target controls that do not receive the mouse input.
+
+    Returns `None` if there is no window at that point or if the window belongs to another process.
+    The returned handle is always a `ControlHandle::Hwnd`, even if the window was created by a control
+    that uses another kind of handle (ex: a `Notice`).
+*/
+pub fn window_from_point(x: i32, y: i32) -> Option<ControlHandle> {
+    use winapi::um::winuser::{WindowFromPoint, ChildWindowFromPointEx, ScreenToClient, GetWindowThreadProcessId, CWP_SKIPINVISIBLE, CWP_SKIPTRANSPARENT};
+    use winapi::um::processthreadsapi::GetCurrentProcessId;
+    use winapi::shared::windef::POINT;
+
+    unsafe {
+        let mut handle = WindowFromPoint(POINT { x, y });
+        if handle.is_null() {
+            return None;
+        }
+
+        let mut process_id = 0;
+        GetWindowThreadProcessId(handle, &mut process_id);
+        if process_id != GetCurrentProcessId() {
+            return None;
+        }
+
+        // `WindowFromPoint` stops at the parent of disabled children
+        loop {
+            let mut p = POINT { x, y };
+            ScreenToClient(handle, &mut p);
+
+            let child = ChildWindowFromPointEx(handle, p, CWP_SKIPINVISIBLE | CWP_SKIPTRANSPARENT);
+            if child.is_null() || child == handle {
+                break;
+            }
+
+            handle = child;
+        }
+
+        Some(ControlHandle::Hwnd(handle))
+    }
+}
+
+/**
+    Forces the focus rectangles and the keyboard accelerator underlines to always show (`true`) or to be hidden (`false`)
+    in the top level window of `control` and in all its children.
+
+    By default, Windows hides them until the user uses the keyboard (ex: pressing Tab or Alt) and shows them from then on.
+    This sends `WM_CHANGEUISTATE` to the top level window, which updates the UI state of all the children with `WM_UPDATEUISTATE`.
+    The UI state is made of two flags:
+
+    * `UISF_HIDEFOCUS`: The focus rectangles are hidden
+    * `UISF_HIDEACCEL`: The keyboard accelerators (the underlined letters of the labels and of the menus) are hidden
+
+    Both flags are cleared when `visible` is `true` and set when `visible` is `false`. Once cleared, the flags stay cleared for the
+    lifetime of the window. Hidden flags can still be cleared by the system when the user uses the keyboard.
+
+    Panics if the control is not a window-like control.
+*/
+pub fn set_focus_visuals(control: &ControlHandle, visible: bool) {
+    use winapi::um::winuser::{GetAncestor, GA_ROOT, WM_CHANGEUISTATE, UIS_SET, UIS_CLEAR, UISF_HIDEFOCUS, UISF_HIDEACCEL};
+    use winapi::shared::minwindef::MAKELONG;
+
+    let handle = control.hwnd().expect("Cannot set the focus visuals of a control that is not window-like");
+    let action = match visible {
+        true => UIS_CLEAR,
+        false => UIS_SET
+    };
+
+    unsafe {
+        let root = GetAncestor(handle, GA_ROOT);
+        let root = if root.is_null() { handle } else { root };
+        send_message(root, WM_CHANGEUISTATE, MAKELONG(action, UISF_HIDEFOCUS | UISF_HIDEACCEL) as WPARAM, 0);
+    }
+}
+
+/**
+    Returns `true` if the focus rectangles of the control are visible (the `UISF_HIDEFOCUS` flag is cleared). See `set_focus_visuals`.
+
+    Panics if the control is not a window-like control.
+*/
+pub fn focus_visuals(control: &ControlHandle) -> bool {
+    use winapi::um::winuser::{WM_QUERYUISTATE, UISF_HIDEFOCUS};
+
+    let handle = control.hwnd().expect("Cannot get the focus visuals of a control that is not window-like");
+    send_message(handle, WM_QUERYUISTATE, 0, 0) as u32 & (UISF_HIDEFOCUS as u32) == 0
+}
+
+/**
+    Enables or disables a control from its handle, without the typed wrapper. Useful to disable a list of heterogeneous controls.
+
+    Window-like handles are enabled with `EnableWindow`. Menu and menu item handles are enabled like `Menu::set_enabled`
+    and `MenuItem::set_enabled` (popup menus cannot be disabled). Other handles (timers, notices, system trays) are ignored.
+*/
+pub fn set_enabled(control: &ControlHandle, enabled: bool) {
+    match *control {
+        ControlHandle::Hwnd(hwnd) => unsafe { set_window_enabled(hwnd, enabled) },
+        #[cfg(feature = "menu")]
+        ControlHandle::Menu(parent, menu) => unsafe { super::menu::enable_menu(parent, menu, enabled) },
+        #[cfg(feature = "menu")]
+        ControlHandle::MenuItem(parent, id) => unsafe { super::menu::enable_menuitem(parent, None, Some(id), enabled) },
+        _ => {}
+    }
+}
+
+/**
+    Returns `true` if the user can interact with the control. See `set_enabled`.
+    Popup menus are always enabled. Returns `false` for the handles that cannot be enabled.
+*/
+pub fn is_enabled(control: &ControlHandle) -> bool {
+    match *control {
+        ControlHandle::Hwnd(hwnd) => unsafe { get_window_enabled(hwnd) },
+        #[cfg(feature = "menu")]
+        ControlHandle::Menu(parent, menu) => unsafe { super::menu::is_menu_enabled(parent, menu) },
+        #[cfg(feature = "menu")]
+        ControlHandle::MenuItem(parent, id) => unsafe { super::menu::is_menuitem_enabled(parent, None, Some(id)) },
+        ControlHandle::PopMenu(_, _) => true,
+        _ => false
+    }
+}
+
+/**
+    Shows or hides a control from its handle, without the typed wrapper.
+
+    Only window-like handles can be hidden. Other handles (menus, timers, notices, system trays) are ignored.
+    Use `TrayNotification::set_visibility` for the system tray icons.
+*/
+pub fn set_visible(control: &ControlHandle, visible: bool) {
+    if let ControlHandle::Hwnd(hwnd) = *control {
+        unsafe { set_window_visibility(hwnd, visible) }
+    }
+}
+
+/**
+    Returns `true` if the control is visible. See `set_visible`.
+    Returns `false` for the handles that are not window-like.
+*/
+pub fn is_visible(control: &ControlHandle) -> bool {
+    match *control {
+        ControlHandle::Hwnd(hwnd) => unsafe { get_window_visibility(hwnd) },
+        _ => false
+    }
+}
+
+/**
+    Returns the control that has the keyboard focus, without the typed wrapper. Useful in generic code (ex: validation or navigation).
+
+    The focus is per thread (`GetFocus`): this returns the control focused in the windows of the calling thread, even if another
+    application is active. Returns `None` if nothing has the focus in this thread or if the focused window belongs to another process.
+    The edit field of an editable combobox is returned as the combobox. The returned handle is always a `ControlHandle::Hwnd`.
+*/
+pub fn focused_control() -> Option<ControlHandle> {
+    use winapi::um::winuser::{GetFocus, GetParent, GetClassNameW, GetWindowThreadProcessId};
+    use winapi::um::processthreadsapi::GetCurrentProcessId;
+
+    unsafe {
+        let handle = GetFocus();
+        if handle.is_null() {
+            return None;
+        }
+
+        let mut process_id = 0;
+        GetWindowThreadProcessId(handle, &mut process_id);
+        if process_id != GetCurrentProcessId() {
+            return None;
+        }
+
+        let parent = GetParent(handle);
+        if !parent.is_null() {
+            let mut class_name = [0u16; 16];
+            let count = GetClassNameW(parent, class_name.as_mut_ptr(), class_name.len() as _) as usize;
+            if from_utf16(&class_name[..count]).eq_ignore_ascii_case("ComboBox") {
+                return Some(ControlHandle::Hwnd(parent));
+            }
+        }
+
+        Some(ControlHandle::Hwnd(handle))
+    }
+}
+
+/**
+    Gives the keyboard focus to a control from its handle, without the typed wrapper. See `focused_control`.
+
+    Like the `set_focus` method of the controls, this only works for the windows of the calling thread.
+    Handles that are not window-like (ex: menus, timers) are ignored.
+*/
+pub fn set_control_focus(control: &ControlHandle) {
+    if let ControlHandle::Hwnd(hwnd) = *control {
+        unsafe { set_focus(hwnd); }
+    }
+}
+
+/// Returns the selected item of a single selection list box, or the item with the focus rectangle of a multi selection list box
+pub unsafe fn list_box_current_item(handle: HWND) -> Option<usize> {
+    use winapi::um::winuser::{LB_GETCURSEL, LB_GETCARETINDEX, LB_ERR, LBS_MULTIPLESEL, LBS_EXTENDEDSEL};
+
+    let msg = match get_style(handle) & (LBS_MULTIPLESEL | LBS_EXTENDEDSEL) {
+        0 => LB_GETCURSEL,
+        _ => LB_GETCARETINDEX
+    };
+
+    match send_message(handle, msg, 0, 0) {
+        LB_ERR => None,
+        index => Some(index as usize)
+    }
+}
+
+/// Returns the selection saved in `LIST_BOX_SELECTION_PROP` before the last user input. See `ListBox::hook_selection`
+pub unsafe fn list_box_previous_item(handle: HWND) -> Option<usize> {
+    use winapi::um::winuser::GetPropW;
+
+    let prop_name = to_utf16(LIST_BOX_SELECTION_PROP);
+    match GetPropW(handle, prop_name.as_ptr()) as usize {
+        0 => None,
+        index => Some(index - 1)
+    }
+}
+
+/**
+    Returns how a button was activated as `(by_keyboard, programmatic)`. See `Button::hook_click_source`.
+
+    The source saved by the hook is only used if it was saved while processing the current message. Otherwise (ex: the buttons not
+    created by `Button`, or the default button activated by Enter through the dialog manager), the click is reported as a
+    keyboard activation if Enter or Space is down.
+*/
+pub unsafe fn button_click_source(handle: HWND) -> (bool, bool) {
+    use winapi::um::winuser::{GetPropW, GetMessageTime, GetKeyState, VK_RETURN, VK_SPACE};
+
+    let source_prop = to_utf16(BUTTON_CLICK_SOURCE_PROP);
+    let time_prop = to_utf16(BUTTON_CLICK_TIME_PROP);
+
+    let source = GetPropW(handle, source_prop.as_ptr()) as usize & !CLICK_SOURCE_PENDING;
+    let time = GetPropW(handle, time_prop.as_ptr()) as usize as u32;
+
+    if source != 0 && time == GetMessageTime() as u32 {
+        return (source == CLICK_SOURCE_KEYBOARD, source == CLICK_SOURCE_PROGRAMMATIC);
+    }
+
+    let key_down = |key: i32| GetKeyState(key) as u16 & 0x8000 != 0;
+    (key_down(VK_RETURN) || key_down(VK_SPACE), false)
+}
+
 pub unsafe fn set_window_size(handle: HWND, w: u32, h: u32, fix: bool) {
     use winapi::um::winuser::{SetWindowPos, AdjustWindowRectEx, GetWindowLongW};
     use winapi::um::winuser::{SWP_NOZORDER, SWP_NOMOVE, SWP_NOACTIVATE, SWP_NOCOPYBITS, GWL_STYLE, GWL_EXSTYLE, SWP_NOOWNERZORDER};
@@ -397,6 +931,67 @@ pub unsafe fn set_window_enabled(handle: HWND, enabled: bool) {
     UpdateWindow(handle);
 }
 
+/// Add the `WS_EX_LAYERED` extended style to a window if it's missing
+pub unsafe fn ensure_layered(handle: HWND) {
+    use winapi::um::winuser::{GWL_EXSTYLE, WS_EX_LAYERED};
+
+    let ex_style = get_window_long(handle, GWL_EXSTYLE) as usize;
+    if ex_style & (WS_EX_LAYERED as usize) == 0 {
+        set_window_long(handle, GWL_EXSTYLE, ex_style | (WS_EX_LAYERED as usize));
+    }
+}
+
+/// Return the alpha value of a layered window. Return 255 if the window is not layered or does not use alpha
+pub unsafe fn get_window_alpha(handle: HWND) -> u8 {
+    use winapi::um::winuser::{GetLayeredWindowAttributes, LWA_ALPHA};
+
+    let (mut key, mut alpha, mut flags) = (0, 255, 0);
+    if GetLayeredWindowAttributes(handle, &mut key, &mut alpha, &mut flags) == 0 || flags & LWA_ALPHA == 0 {
+        255
+    } else {
+        alpha
+    }
+}
+
+/// Set the alpha value of a window. The window is made layered if it's not already the case. The color key of the window is kept.
+pub unsafe fn set_window_alpha(handle: HWND, alpha: u8) {
+    use winapi::um::winuser::{SetLayeredWindowAttributes, GetLayeredWindowAttributes, LWA_ALPHA, LWA_COLORKEY};
+
+    ensure_layered(handle);
+
+    let (mut key, mut old_alpha, mut flags) = (0, 255, 0);
+    GetLayeredWindowAttributes(handle, &mut key, &mut old_alpha, &mut flags);
+
+    SetLayeredWindowAttributes(handle, key, alpha, LWA_ALPHA | (flags & LWA_COLORKEY));
+}
+
+/// Return the transparency color key of a layered window. Return None if the window is not layered or does not use a color key
+pub unsafe fn get_window_color_key(handle: HWND) -> Option<[u8; 3]> {
+    use winapi::um::winuser::{GetLayeredWindowAttributes, LWA_COLORKEY};
+    use winapi::um::wingdi::{GetRValue, GetGValue, GetBValue};
+
+    let (mut key, mut alpha, mut flags) = (0, 255, 0);
+    if GetLayeredWindowAttributes(handle, &mut key, &mut alpha, &mut flags) == 0 || flags & LWA_COLORKEY == 0 {
+        None
+    } else {
+        Some([GetRValue(key), GetGValue(key), GetBValue(key)])
+    }
+}
+
+/// Set or remove the transparency color key of a window. The window is made layered if it's not already the case. The alpha of the window is kept.
+pub unsafe fn set_window_color_key(handle: HWND, color: Option<[u8; 3]>) {
+    use winapi::um::winuser::{SetLayeredWindowAttributes, LWA_ALPHA, LWA_COLORKEY};
+    use winapi::um::wingdi::RGB;
+
+    let alpha = get_window_alpha(handle);
+    ensure_layered(handle);
+
+    match color {
+        Some([r, g, b]) => SetLayeredWindowAttributes(handle, RGB(r, g, b), alpha, LWA_ALPHA | LWA_COLORKEY),
+        None => SetLayeredWindowAttributes(handle, 0, alpha, LWA_ALPHA)
+    };
+}
+
 #[cfg(feature = "tabs")]
 pub unsafe fn get_window_class_name(handle: HWND) -> String {
     use std::ffi::OsString;